@@ -2,7 +2,8 @@ use voicemeeter::VoicemeeterRemote;
 
 fn main() {
     run();
-    VoicemeeterRemote::new().expect_err("we've logged out so shouldn't login again");
+    // all remotes have been dropped, so this logs in again
+    drop(VoicemeeterRemote::new().unwrap());
 }
 fn run() {
     tracing_subscriber::FmtSubscriber::builder()
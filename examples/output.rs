@@ -81,7 +81,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // containing another slice with `data.nbs` samples.
                 // Each device has a number of channels
                 // (e.g left, right, center, etc. typically 8 channels)
-                for device in remote.program.devices() {
+                for device in remote.program().devices() {
                     let (buffer_in, buffer_out): (&[&[f32]], &mut [&mut [f32]]) = match (
                         data.buffer.read.device(device),
                         data.buffer.write.device_mut(device),
@@ -101,7 +101,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Instead of the above, the equivalent with convenience functions would be
                 let (read, mut write) = data.buffer.get_buffers();
-                write.copy_device_from(&read, remote.program.devices());
+                write.copy_device_from(&read, remote.program().devices());
             }
             _ => (),
         }
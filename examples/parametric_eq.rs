@@ -66,7 +66,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                 write.copy_device_from(
                     &read,
                     remote
-                        .program
+                        .program()
                         .devices()
                         .iter()
                         .filter(|d| d != &&Device::OutputA1),
@@ -14,13 +14,13 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                 voicemeeter::CallbackCommand::BufferMain(data) => {
                     // do something with the data, this just simply relays it without any processing
                     let (read, mut write) = data.buffer.get_buffers();
-                    write.copy_device_from(&read, remote.program.devices());
+                    write.copy_device_from(&read, remote.program().devices());
                 }
                 // Process audio outputs before master section.
                 voicemeeter::CallbackCommand::BufferOut(data) => {
                     // do something with the data, this just simply relays it without any processing
                     let (read, mut write) = data.buffer.get_buffers();
-                    write.copy_device_from(&read, remote.program.devices());
+                    write.copy_device_from(&read, remote.program().devices());
                 }
                 _ => {}
             }
@@ -0,0 +1,404 @@
+//! Backends for the Voicemeeter Remote API.
+//!
+//! A [`VoicemeeterBackend`] is what [`VoicemeeterRemote`](crate::VoicemeeterRemote) talks to.
+//! The default backend is the Voicemeeter remote DLL, loaded as a [`VoicemeeterRemoteRaw`].
+//!
+//! With the `simulator` feature, `simulator::SimulatedVoicemeeter` provides an in-process
+//! backend that behaves like a running Voicemeeter application, useful for testing.
+use std::{
+    ffi::{c_void, CStr},
+    os::raw::c_char,
+    ptr,
+};
+
+use crate::bindings::{VoicemeeterRemoteRaw, T_VBVMR_VBAUDIOCALLBACK};
+
+#[cfg(all(feature = "interface", any(test, feature = "simulator")))]
+pub mod simulator;
+
+/// A backend implementing the `VBVMR_*` functions of the Voicemeeter Remote API.
+///
+/// Every method mirrors a function in `VoicemeeterRemote.h` and returns the same status code as
+/// the DLL would, so that all error handling is shared between backends.
+pub trait VoicemeeterBackend: Send + Sync {
+    /// `VBVMR_Login`
+    fn login(&self) -> i32;
+    /// `VBVMR_Logout`
+    fn logout(&self) -> i32;
+    /// `VBVMR_RunVoicemeeter`
+    fn run_voicemeeter(&self, r#type: i32) -> i32;
+    /// `VBVMR_GetVoicemeeterType`
+    fn get_voicemeeter_type(&self, r#type: &mut i32) -> i32;
+    /// `VBVMR_GetVoicemeeterVersion`
+    fn get_voicemeeter_version(&self, version: &mut i32) -> i32;
+    /// `VBVMR_IsParametersDirty`
+    fn is_parameters_dirty(&self) -> i32;
+    /// `VBVMR_GetParameterFloat`
+    fn get_parameter_float(&self, param: &CStr, value: &mut f32) -> i32;
+    /// `VBVMR_GetParameterStringA`
+    fn get_parameter_string_a(&self, param: &CStr, value: &mut [c_char; 512]) -> i32;
+    /// `VBVMR_GetParameterStringW`
+    fn get_parameter_string_w(&self, param: &CStr, value: &mut [u16; 512]) -> i32;
+    /// `VBVMR_GetLevel`
+    fn get_level(&self, level_type: i32, channel: i32, value: &mut f32) -> i32;
+    /// `VBVMR_GetMidiMessage`
+    fn get_midi_message(&self, buffer: &mut [u8]) -> i32;
+    /// `VBVMR_SetParameterFloat`
+    fn set_parameter_float(&self, param: &CStr, value: f32) -> i32;
+    /// `VBVMR_SetParameterStringA`
+    fn set_parameter_string_a(&self, param: &CStr, value: &CStr) -> i32;
+    /// `VBVMR_SetParameterStringW`
+    ///
+    /// `value` must be nul-terminated.
+    fn set_parameter_string_w(&self, param: &CStr, value: &[u16]) -> i32;
+    /// `VBVMR_SetParameters`
+    fn set_parameters(&self, script: &CStr) -> i32;
+    /// `VBVMR_SetParametersW`
+    ///
+    /// `script` must be nul-terminated.
+    fn set_parameters_w(&self, script: &[u16]) -> i32;
+    /// `VBVMR_Output_GetDeviceNumber`
+    fn output_get_device_number(&self) -> i32;
+    /// `VBVMR_Output_GetDeviceDescA`
+    fn output_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32;
+    /// `VBVMR_Output_GetDeviceDescW`
+    fn output_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32;
+    /// `VBVMR_Input_GetDeviceNumber`
+    fn input_get_device_number(&self) -> i32;
+    /// `VBVMR_Input_GetDeviceDescA`
+    fn input_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32;
+    /// `VBVMR_Input_GetDeviceDescW`
+    fn input_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32;
+    /// `VBVMR_AudioCallbackRegister`
+    ///
+    /// # Safety
+    ///
+    /// `callback` must be safe to call with `user_data` until the callback is unregistered.
+    unsafe fn audio_callback_register(
+        &self,
+        mode: i32,
+        callback: T_VBVMR_VBAUDIOCALLBACK,
+        user_data: *mut c_void,
+        client_name: &mut [c_char; 64],
+    ) -> i32;
+    /// `VBVMR_AudioCallbackStart`
+    fn audio_callback_start(&self) -> i32;
+    /// `VBVMR_AudioCallbackStop`
+    fn audio_callback_stop(&self) -> i32;
+    /// `VBVMR_AudioCallbackUnregister`
+    fn audio_callback_unregister(&self) -> i32;
+    /// `VBVMR_MacroButton_IsDirty`
+    fn macro_button_is_dirty(&self) -> i32;
+    /// `VBVMR_MacroButton_GetStatus`
+    fn macro_button_get_status(&self, button: i32, value: &mut f32, bitmode: i32) -> i32;
+    /// `VBVMR_MacroButton_SetStatus`
+    fn macro_button_set_status(&self, button: i32, value: f32, bitmode: i32) -> i32;
+}
+
+impl<T: VoicemeeterBackend + ?Sized> VoicemeeterBackend for &T {
+    fn login(&self) -> i32 {
+        (**self).login()
+    }
+    fn logout(&self) -> i32 {
+        (**self).logout()
+    }
+    fn run_voicemeeter(&self, r#type: i32) -> i32 {
+        (**self).run_voicemeeter(r#type)
+    }
+    fn get_voicemeeter_type(&self, r#type: &mut i32) -> i32 {
+        (**self).get_voicemeeter_type(r#type)
+    }
+    fn get_voicemeeter_version(&self, version: &mut i32) -> i32 {
+        (**self).get_voicemeeter_version(version)
+    }
+    fn is_parameters_dirty(&self) -> i32 {
+        (**self).is_parameters_dirty()
+    }
+    fn get_parameter_float(&self, param: &CStr, value: &mut f32) -> i32 {
+        (**self).get_parameter_float(param, value)
+    }
+    fn get_parameter_string_a(&self, param: &CStr, value: &mut [c_char; 512]) -> i32 {
+        (**self).get_parameter_string_a(param, value)
+    }
+    fn get_parameter_string_w(&self, param: &CStr, value: &mut [u16; 512]) -> i32 {
+        (**self).get_parameter_string_w(param, value)
+    }
+    fn get_level(&self, level_type: i32, channel: i32, value: &mut f32) -> i32 {
+        (**self).get_level(level_type, channel, value)
+    }
+    fn get_midi_message(&self, buffer: &mut [u8]) -> i32 {
+        (**self).get_midi_message(buffer)
+    }
+    fn set_parameter_float(&self, param: &CStr, value: f32) -> i32 {
+        (**self).set_parameter_float(param, value)
+    }
+    fn set_parameter_string_a(&self, param: &CStr, value: &CStr) -> i32 {
+        (**self).set_parameter_string_a(param, value)
+    }
+    fn set_parameter_string_w(&self, param: &CStr, value: &[u16]) -> i32 {
+        (**self).set_parameter_string_w(param, value)
+    }
+    fn set_parameters(&self, script: &CStr) -> i32 {
+        (**self).set_parameters(script)
+    }
+    fn set_parameters_w(&self, script: &[u16]) -> i32 {
+        (**self).set_parameters_w(script)
+    }
+    fn output_get_device_number(&self) -> i32 {
+        (**self).output_get_device_number()
+    }
+    fn output_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32 {
+        (**self).output_get_device_desc_a(index, r#type, name, hardware_id)
+    }
+    fn output_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32 {
+        (**self).output_get_device_desc_w(index, r#type, name, hardware_id)
+    }
+    fn input_get_device_number(&self) -> i32 {
+        (**self).input_get_device_number()
+    }
+    fn input_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32 {
+        (**self).input_get_device_desc_a(index, r#type, name, hardware_id)
+    }
+    fn input_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32 {
+        (**self).input_get_device_desc_w(index, r#type, name, hardware_id)
+    }
+    unsafe fn audio_callback_register(
+        &self,
+        mode: i32,
+        callback: T_VBVMR_VBAUDIOCALLBACK,
+        user_data: *mut c_void,
+        client_name: &mut [c_char; 64],
+    ) -> i32 {
+        unsafe { (**self).audio_callback_register(mode, callback, user_data, client_name) }
+    }
+    fn audio_callback_start(&self) -> i32 {
+        (**self).audio_callback_start()
+    }
+    fn audio_callback_stop(&self) -> i32 {
+        (**self).audio_callback_stop()
+    }
+    fn audio_callback_unregister(&self) -> i32 {
+        (**self).audio_callback_unregister()
+    }
+    fn macro_button_is_dirty(&self) -> i32 {
+        (**self).macro_button_is_dirty()
+    }
+    fn macro_button_get_status(&self, button: i32, value: &mut f32, bitmode: i32) -> i32 {
+        (**self).macro_button_get_status(button, value, bitmode)
+    }
+    fn macro_button_set_status(&self, button: i32, value: f32, bitmode: i32) -> i32 {
+        (**self).macro_button_set_status(button, value, bitmode)
+    }
+}
+
+/// Get a pointer to the first element of `T` if option is [`Some`](Option::Some) or a null ptr if it's [`None`](Option::None)
+fn first_or_null<T, const N: usize>(option: Option<&mut [T; N]>) -> *mut T {
+    match option {
+        Some(a) => a.as_mut_ptr(),
+        None => ptr::null_mut(),
+    }
+}
+
+impl VoicemeeterBackend for VoicemeeterRemoteRaw {
+    fn login(&self) -> i32 {
+        unsafe { self.VBVMR_Login() }
+    }
+    fn logout(&self) -> i32 {
+        unsafe { self.VBVMR_Logout() }
+    }
+    fn run_voicemeeter(&self, r#type: i32) -> i32 {
+        unsafe { self.VBVMR_RunVoicemeeter(r#type) }
+    }
+    fn get_voicemeeter_type(&self, r#type: &mut i32) -> i32 {
+        unsafe { self.VBVMR_GetVoicemeeterType(r#type) }
+    }
+    fn get_voicemeeter_version(&self, version: &mut i32) -> i32 {
+        unsafe { self.VBVMR_GetVoicemeeterVersion(version) }
+    }
+    fn is_parameters_dirty(&self) -> i32 {
+        unsafe { self.VBVMR_IsParametersDirty() }
+    }
+    fn get_parameter_float(&self, param: &CStr, value: &mut f32) -> i32 {
+        unsafe { self.VBVMR_GetParameterFloat(param.as_ptr() as *mut _, value) }
+    }
+    fn get_parameter_string_a(&self, param: &CStr, value: &mut [c_char; 512]) -> i32 {
+        unsafe { self.VBVMR_GetParameterStringA(param.as_ptr() as *mut _, value.as_mut_ptr()) }
+    }
+    fn get_parameter_string_w(&self, param: &CStr, value: &mut [u16; 512]) -> i32 {
+        unsafe { self.VBVMR_GetParameterStringW(param.as_ptr() as *mut _, value.as_mut_ptr()) }
+    }
+    fn get_level(&self, level_type: i32, channel: i32, value: &mut f32) -> i32 {
+        unsafe { self.VBVMR_GetLevel(level_type, channel, value) }
+    }
+    fn get_midi_message(&self, buffer: &mut [u8]) -> i32 {
+        unsafe { self.VBVMR_GetMidiMessage(buffer.as_mut_ptr(), buffer.len() as _) }
+    }
+    fn set_parameter_float(&self, param: &CStr, value: f32) -> i32 {
+        unsafe { self.VBVMR_SetParameterFloat(param.as_ptr() as *mut _, value) }
+    }
+    fn set_parameter_string_a(&self, param: &CStr, value: &CStr) -> i32 {
+        unsafe {
+            self.VBVMR_SetParameterStringA(param.as_ptr() as *mut _, value.as_ptr() as *mut _)
+        }
+    }
+    fn set_parameter_string_w(&self, param: &CStr, value: &[u16]) -> i32 {
+        assert_eq!(value.last(), Some(&0), "value must be nul-terminated");
+        unsafe {
+            self.VBVMR_SetParameterStringW(param.as_ptr() as *mut _, value.as_ptr() as *mut _)
+        }
+    }
+    fn set_parameters(&self, script: &CStr) -> i32 {
+        unsafe { self.VBVMR_SetParameters(script.as_ptr() as *mut _) }
+    }
+    fn set_parameters_w(&self, script: &[u16]) -> i32 {
+        assert_eq!(script.last(), Some(&0), "script must be nul-terminated");
+        unsafe { self.VBVMR_SetParametersW(script.as_ptr() as *mut _) }
+    }
+    fn output_get_device_number(&self) -> i32 {
+        unsafe { self.VBVMR_Output_GetDeviceNumber() }
+    }
+    fn output_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32 {
+        unsafe {
+            self.VBVMR_Output_GetDeviceDescA(
+                index,
+                r#type.map_or(ptr::null_mut(), |t| t as *mut _),
+                first_or_null(name),
+                first_or_null(hardware_id),
+            )
+        }
+    }
+    fn output_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32 {
+        unsafe {
+            self.VBVMR_Output_GetDeviceDescW(
+                index,
+                r#type.map_or(ptr::null_mut(), |t| t as *mut _),
+                first_or_null(name),
+                first_or_null(hardware_id),
+            )
+        }
+    }
+    fn input_get_device_number(&self) -> i32 {
+        unsafe { self.VBVMR_Input_GetDeviceNumber() }
+    }
+    fn input_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32 {
+        unsafe {
+            self.VBVMR_Input_GetDeviceDescA(
+                index,
+                r#type.map_or(ptr::null_mut(), |t| t as *mut _),
+                first_or_null(name),
+                first_or_null(hardware_id),
+            )
+        }
+    }
+    fn input_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32 {
+        unsafe {
+            self.VBVMR_Input_GetDeviceDescW(
+                index,
+                r#type.map_or(ptr::null_mut(), |t| t as *mut _),
+                first_or_null(name),
+                first_or_null(hardware_id),
+            )
+        }
+    }
+    unsafe fn audio_callback_register(
+        &self,
+        mode: i32,
+        callback: T_VBVMR_VBAUDIOCALLBACK,
+        user_data: *mut c_void,
+        client_name: &mut [c_char; 64],
+    ) -> i32 {
+        unsafe {
+            self.VBVMR_AudioCallbackRegister(mode, callback, user_data, client_name.as_mut_ptr())
+        }
+    }
+    fn audio_callback_start(&self) -> i32 {
+        unsafe { self.VBVMR_AudioCallbackStart() }
+    }
+    fn audio_callback_stop(&self) -> i32 {
+        unsafe { self.VBVMR_AudioCallbackStop() }
+    }
+    fn audio_callback_unregister(&self) -> i32 {
+        unsafe { self.VBVMR_AudioCallbackUnregister() }
+    }
+    fn macro_button_is_dirty(&self) -> i32 {
+        unsafe { self.VBVMR_MacroButton_IsDirty() }
+    }
+    fn macro_button_get_status(&self, button: i32, value: &mut f32, bitmode: i32) -> i32 {
+        unsafe { self.VBVMR_MacroButton_GetStatus(button, value, bitmode) }
+    }
+    fn macro_button_set_status(&self, button: i32, value: f32, bitmode: i32) -> i32 {
+        unsafe { self.VBVMR_MacroButton_SetStatus(button, value, bitmode) }
+    }
+}
@@ -0,0 +1,1115 @@
+//! A simulated Voicemeeter application.
+//!
+//! [`SimulatedVoicemeeter`] implements [`VoicemeeterBackend`] without the remote DLL, which makes it
+//! possible to use [`VoicemeeterRemote`](crate::VoicemeeterRemote) in tests and on platforms
+//! where Voicemeeter is not available.
+//!
+//! The simulator stores all `Strip[i]`, `Bus[i]`, `Command`, `Option`, `Patch`, `Recorder`, `Fx` and `vban` parameters,
+//! rejects parameters that are not available in the simulated program and keeps track of the dirty flags.
+//! Levels are synthesized from the strip and bus parameters.
+//!
+//! Tests can act as the Voicemeeter GUI with functions like [`SimulatedVoicemeeter::gui_set_float`],
+//! and move the simulated time forward with [`SimulatedVoicemeeter::advance`].
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use voicemeeter::{
+//!     backend::simulator::SimulatedVoicemeeter, types::VoicemeeterApplication, VoicemeeterRemote,
+//! };
+//!
+//! let simulator = Arc::new(SimulatedVoicemeeter::new(
+//!     VoicemeeterApplication::VoicemeeterPotato,
+//! ));
+//! let remote = VoicemeeterRemote::with_backend(simulator.clone())?;
+//!
+//! remote.parameters().strip(0)?.gain().set(-6.0)?;
+//! assert_eq!(remote.parameters().strip(0)?.gain().get()?, -6.0);
+//!
+//! simulator.gui_set_float("Strip[0].Mute", 1.0)?;
+//! assert!(remote.is_parameters_dirty()?);
+//! assert!(remote.parameters().strip(0)?.mute().get()?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::{
+    collections::{HashMap, VecDeque},
+    ffi::{c_void, CStr},
+    os::raw::c_char,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bindings::{T_VBVMR_VBAUDIOCALLBACK, VBVMR_DEVTYPE, VBVMR_MACROBUTTON_MODE},
+    interface::parameters::command::MACRO_BUTTONS,
+    types::{LevelType, VoicemeeterApplication},
+};
+
+use super::VoicemeeterBackend;
+
+mod parameters;
+
+use parameters::{buses, resolve, strips, Group, Kind, Resolved};
+
+/// A simulated Voicemeeter application.
+///
+/// The strips and buses available are modelled after [`VoicemeeterApplication::devices`].
+pub struct SimulatedVoicemeeter {
+    state: Mutex<State>,
+}
+
+struct State {
+    program: VoicemeeterApplication,
+    version: [u8; 4],
+    logged_in: bool,
+    values: HashMap<String, Value>,
+    parameters_dirty: bool,
+    macro_buttons: HashMap<i32, MacroButton>,
+    macro_buttons_dirty: bool,
+    midi: VecDeque<Vec<u8>>,
+    elapsed: Duration,
+    input_levels: HashMap<usize, f32>,
+    input_devices: Vec<SimulatedDevice>,
+    output_devices: Vec<SimulatedDevice>,
+    callback: Option<Registration>,
+    callback_started: bool,
+    startup_delay: Duration,
+    starting_until: Option<Instant>,
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Float(f32),
+    String(String),
+}
+
+#[derive(Default, Clone, Copy)]
+struct MacroButton {
+    state: bool,
+    trigger: bool,
+}
+
+struct SimulatedDevice {
+    r#type: VBVMR_DEVTYPE,
+    name: String,
+    hardware_id: String,
+}
+
+struct Registration {
+    client_name: Vec<u8>,
+    #[allow(dead_code)]
+    mode: i32,
+    #[allow(dead_code)]
+    callback: T_VBVMR_VBAUDIOCALLBACK,
+    #[allow(dead_code)]
+    user_data: usize,
+}
+
+/// Errors from driving the simulator.
+#[derive(Debug, thiserror::Error, Clone)]
+#[non_exhaustive]
+pub enum SimulatorError {
+    /// Unknown parameter.
+    #[error("unknown parameter: {0}")]
+    UnknownParameter(String),
+    /// The parameter does not hold this kind of value.
+    #[error("parameter `{0}` does not hold this kind of value")]
+    WrongType(String),
+}
+
+impl SimulatedVoicemeeter {
+    /// Create a new running simulated Voicemeeter application.
+    ///
+    /// Use [`VoicemeeterApplication::None`] to simulate Voicemeeter not running.
+    pub fn new(program: VoicemeeterApplication) -> Self {
+        Self {
+            state: Mutex::new(State {
+                program,
+                version: default_version(program),
+                logged_in: false,
+                values: HashMap::new(),
+                parameters_dirty: false,
+                macro_buttons: HashMap::new(),
+                macro_buttons_dirty: false,
+                midi: VecDeque::new(),
+                elapsed: Duration::ZERO,
+                input_levels: HashMap::new(),
+                input_devices: vec![],
+                output_devices: vec![],
+                callback: None,
+                callback_started: false,
+                startup_delay: Duration::ZERO,
+                starting_until: None,
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The simulated application.
+    pub fn program(&self) -> VoicemeeterApplication {
+        self.state().program
+    }
+
+    /// Amount of strips in the simulated application.
+    pub fn strips(&self) -> usize {
+        strips(self.program())
+    }
+
+    /// Amount of buses in the simulated application.
+    pub fn buses(&self) -> usize {
+        buses(self.program())
+    }
+
+    /// Returns `true` if a client is logged in.
+    pub fn is_logged_in(&self) -> bool {
+        self.state().logged_in
+    }
+
+    /// Returns `true` if parameters have changed since the last poll, without clearing the flag.
+    pub fn is_dirty(&self) -> bool {
+        self.state().parameters_dirty
+    }
+
+    /// Time simulated so far, see [`advance`](Self::advance).
+    pub fn elapsed(&self) -> Duration {
+        self.state().elapsed
+    }
+
+    /// Move the simulated time forward. This changes the generated levels.
+    pub fn advance(&self, duration: Duration) {
+        self.state().elapsed += duration;
+    }
+
+    /// Read the float value of a parameter, without affecting the dirty flag.
+    pub fn float(&self, param: &str) -> Result<f32, SimulatorError> {
+        let state = self.state();
+        let resolved = state.resolve(param)?;
+        match state.value(&resolved) {
+            Value::Float(f) => Ok(f),
+            Value::String(_) => Err(SimulatorError::WrongType(param.to_owned())),
+        }
+    }
+
+    /// Read the string value of a parameter, without affecting the dirty flag.
+    pub fn string(&self, param: &str) -> Result<String, SimulatorError> {
+        let state = self.state();
+        let resolved = state.resolve(param)?;
+        match state.value(&resolved) {
+            Value::String(s) => Ok(s),
+            Value::Float(_) => Err(SimulatorError::WrongType(param.to_owned())),
+        }
+    }
+
+    /// Change a float parameter as if it was done in the Voicemeeter GUI.
+    pub fn gui_set_float(&self, param: &str, value: f32) -> Result<(), SimulatorError> {
+        let mut state = self.state();
+        let resolved = state.resolve(param)?;
+        state.set(resolved, Value::Float(value))
+    }
+
+    /// Change a string parameter as if it was done in the Voicemeeter GUI.
+    pub fn gui_set_string(&self, param: &str, value: &str) -> Result<(), SimulatorError> {
+        let mut state = self.state();
+        let resolved = state.resolve(param)?;
+        state.set(resolved, Value::String(value.to_owned()))
+    }
+
+    /// Press or release a macro button as if it was done in the Voicemeeter GUI.
+    pub fn gui_set_macro_button(&self, button: usize, pressed: bool) {
+        let mut state = self.state();
+        state.macro_buttons.entry(button as i32).or_default().state = pressed;
+        state.macro_buttons_dirty = true;
+    }
+
+    /// Queue a MIDI message, to be returned by [`get_midi_message`](crate::VoicemeeterRemote::get_midi_message)
+    pub fn push_midi_message(&self, message: &[u8]) {
+        self.state().midi.push_back(message.to_vec())
+    }
+
+    /// Set the pre fader level of an input channel, or use the generated level if `None`.
+    pub fn set_input_level(&self, channel: usize, level: Option<f32>) {
+        let mut state = self.state();
+        match level {
+            Some(level) => state.input_levels.insert(channel, level),
+            None => state.input_levels.remove(&channel),
+        };
+    }
+
+    /// Add an audio input device to the system.
+    pub fn add_input_device(&self, r#type: VBVMR_DEVTYPE, name: &str, hardware_id: &str) {
+        self.state().input_devices.push(SimulatedDevice {
+            r#type,
+            name: name.to_owned(),
+            hardware_id: hardware_id.to_owned(),
+        })
+    }
+
+    /// Add an audio output device to the system.
+    pub fn add_output_device(&self, r#type: VBVMR_DEVTYPE, name: &str, hardware_id: &str) {
+        self.state().output_devices.push(SimulatedDevice {
+            r#type,
+            name: name.to_owned(),
+            hardware_id: hardware_id.to_owned(),
+        })
+    }
+
+    /// Simulate the user closing Voicemeeter or starting another program.
+    ///
+    /// Changing the program restarts Voicemeeter, dropping the registered audio callback.
+    /// Use [`VoicemeeterApplication::None`] to simulate Voicemeeter being closed.
+    pub fn set_program(&self, program: VoicemeeterApplication) {
+        self.state().restart(program)
+    }
+
+    /// Make Voicemeeter take this long to start after [`run_voicemeeter`](crate::VoicemeeterRemote::run_voicemeeter).
+    ///
+    /// Voicemeeter reports no server while starting. The delay is in real time, not simulated time.
+    pub fn set_startup_delay(&self, delay: Duration) {
+        self.state().startup_delay = delay;
+    }
+
+    /// The client name of the registered audio callback, and whether it is started.
+    pub fn audio_callback(&self) -> Option<(String, bool)> {
+        let state = self.state();
+        state.callback.as_ref().map(|registration| {
+            (
+                String::from_utf8_lossy(&registration.client_name).into_owned(),
+                state.callback_started,
+            )
+        })
+    }
+}
+
+/// Version reported for a program, the first number of the version is the program type.
+fn default_version(program: VoicemeeterApplication) -> [u8; 4] {
+    match program {
+        VoicemeeterApplication::Voicemeeter => [1, 0, 8, 8],
+        VoicemeeterApplication::VoicemeeterBanana => [2, 0, 6, 8],
+        VoicemeeterApplication::VoicemeeterPotato | VoicemeeterApplication::PotatoX64Bits => {
+            [3, 0, 2, 8]
+        }
+        _ => [0; 4],
+    }
+}
+
+fn db_to_amplitude(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+impl State {
+    fn restart(&mut self, program: VoicemeeterApplication) {
+        if program != self.program {
+            self.callback = None;
+            self.callback_started = false;
+        }
+        self.program = program;
+        self.version = default_version(program);
+    }
+
+    fn is_running(&self) -> bool {
+        self.starting_until.map_or(true, |t| Instant::now() >= t)
+            && !matches!(
+                self.program,
+                VoicemeeterApplication::None | VoicemeeterApplication::Other
+            )
+    }
+
+    fn resolve(&self, param: &str) -> Result<Resolved, SimulatorError> {
+        resolve(self.program, param)
+            .ok_or_else(|| SimulatorError::UnknownParameter(param.to_owned()))
+    }
+
+    fn value(&self, param: &Resolved) -> Value {
+        self.values
+            .get(&param.key)
+            .cloned()
+            .unwrap_or_else(|| match param.entry.kind {
+                Kind::Float => Value::Float(param.entry.default),
+                Kind::String => Value::String(String::new()),
+            })
+    }
+
+    fn float(&self, param: &str) -> f32 {
+        match resolve(self.program, param).map(|p| self.value(&p)) {
+            Some(Value::Float(f)) => f,
+            _ => 0.0,
+        }
+    }
+
+    fn set(&mut self, param: Resolved, value: Value) -> Result<(), SimulatorError> {
+        let value = match (param.entry.kind, value) {
+            (Kind::Float, Value::Float(f)) => Value::Float(f),
+            (Kind::Float, Value::String(s)) => Value::Float(
+                s.trim()
+                    .parse()
+                    .map_err(|_| SimulatorError::WrongType(param.key.clone()))?,
+            ),
+            (Kind::String, Value::String(s)) => Value::String(s),
+            (Kind::String, Value::Float(_)) => {
+                return Err(SimulatorError::WrongType(param.key.clone()))
+            }
+        };
+        if param.group == Group::Bus
+            && param.rest.starts_with("mode.")
+            && matches!(value, Value::Float(f) if f != 0.0)
+        {
+            // only one bus mode can be active
+            let bus = param.index.unwrap_or_default();
+            self.values
+                .retain(|k, _| !k.starts_with(&format!("bus[{bus}].mode.")));
+            self.values
+                .insert(format!("bus[{bus}].mode.normal"), Value::Float(0.0));
+        }
+        if param.group == Group::Command {
+            // macro buttons can also be changed with `Command.Button[i]`
+            let button = param.rest.strip_prefix("button[");
+            if let Some((button, action)) = button.and_then(|b| b.split_once("].")) {
+                let pressed = matches!(value, Value::Float(f) if f != 0.0);
+                let b = self
+                    .macro_buttons
+                    .entry(button.parse().unwrap_or_default())
+                    .or_default();
+                match action {
+                    "trigger" => b.trigger = pressed,
+                    _ => b.state = pressed,
+                }
+                self.macro_buttons_dirty = true;
+            }
+        }
+        self.values.insert(param.key, value);
+        self.parameters_dirty = true;
+        Ok(())
+    }
+
+    /// Amount of channels available for levels, `(input, output)`.
+    fn level_channels(&self) -> (usize, usize) {
+        let program = self.program;
+        let end = |i: crate::types::ChannelIndex| i.start + i.size;
+        let devices = program.devices();
+        let input = devices.iter().filter_map(|d| d.input(&program)).map(end);
+        let output = devices.iter().filter_map(|d| d.output(&program)).map(end);
+        (input.max().unwrap_or(0), output.max().unwrap_or(0))
+    }
+
+    /// Pre fader level for an input channel.
+    fn input_level(&self, channel: usize) -> f32 {
+        if let Some(level) = self.input_levels.get(&channel) {
+            return *level;
+        }
+        let t = self.elapsed.as_secs_f32();
+        0.25 * (1.0 + (std::f32::consts::TAU * 0.5 * t + channel as f32).sin())
+    }
+
+    /// Find the strip and the channel offset in that strip for an input channel.
+    fn strip_for_channel(&self, channel: usize) -> Option<(usize, usize, usize)> {
+        let program = self.program;
+        program.devices().iter().find_map(|d| {
+            let index = d.input(&program)?;
+            let strip = d.as_strip_index(&program)?.0 as usize;
+            (index.start..index.start + index.size)
+                .contains(&channel)
+                .then_some((strip, channel - index.start, index.size))
+        })
+    }
+
+    fn strip_level(&self, level_type: LevelType, channel: usize) -> f32 {
+        let Some((strip, _, _)) = self.strip_for_channel(channel) else {
+            return 0.0;
+        };
+        let level = self.input_level(channel);
+        if level_type == LevelType::PreFaderInputLevels {
+            return level;
+        }
+        let level = level * db_to_amplitude(self.float(&format!("Strip[{strip}].Gain")));
+        if level_type == LevelType::PostMuteInputLevels
+            && self.float(&format!("Strip[{strip}].Mute")) != 0.0
+        {
+            return 0.0;
+        }
+        level
+    }
+
+    fn bus_level(&self, channel: usize) -> f32 {
+        let program = self.program;
+        let Some((bus, name, offset)) = program.devices().iter().find_map(|d| {
+            let index = d.output(&program)?;
+            let (bus, name) = d.as_bus_index(&program)?;
+            (index.start..index.start + index.size)
+                .contains(&channel)
+                .then_some((bus.0, name, channel - index.start))
+        }) else {
+            return 0.0;
+        };
+        if self.float(&format!("Bus[{bus}].Mute")) != 0.0 {
+            return 0.0;
+        }
+        let (input, _) = self.level_channels();
+        let mut level = 0.0;
+        for channel in 0..input {
+            let Some((strip, strip_offset, size)) = self.strip_for_channel(channel) else {
+                continue;
+            };
+            if offset % size == strip_offset && self.float(&format!("Strip[{strip}].{name}")) != 0.0
+            {
+                level += self.strip_level(LevelType::PostMuteInputLevels, channel);
+            }
+        }
+        level * db_to_amplitude(self.float(&format!("Bus[{bus}].Gain")))
+    }
+
+    /// Run a script, returning the line that failed.
+    fn run_script(&mut self, script: &str) -> Result<(), usize> {
+        for (line, statements) in script.lines().enumerate() {
+            for statement in split_statements(statements) {
+                self.run_statement(statement).map_err(|_| line + 1)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn run_statement(&mut self, statement: &str) -> Result<(), SimulatorError> {
+        let statement = statement.trim();
+        if statement.is_empty() {
+            return Ok(());
+        }
+        let err = || SimulatorError::UnknownParameter(statement.to_owned());
+        let (name, value) = statement.split_once('=').ok_or_else(err)?;
+        let (name, op) = match name.trim_end().as_bytes().last() {
+            Some(b'+') => (&name.trim_end()[..name.trim_end().len() - 1], 1.0),
+            Some(b'-') => (&name.trim_end()[..name.trim_end().len() - 1], -1.0),
+            _ => (name, 0.0),
+        };
+        let param = self.resolve(name)?;
+        let value = value.trim();
+        let value = if let Some(value) = value.strip_prefix('"') {
+            Value::String(value.strip_suffix('"').ok_or_else(err)?.to_owned())
+        } else if value.starts_with('(') && value.ends_with(')') {
+            Value::String(value.to_owned())
+        } else {
+            Value::Float(value.parse().map_err(|_| err())?)
+        };
+        let value = match (op, value) {
+            (op, Value::Float(f)) if op != 0.0 => match self.value(&param) {
+                Value::Float(current) => Value::Float(current + op * f),
+                Value::String(_) => return Err(err()),
+            },
+            (op, _) if op != 0.0 => return Err(err()),
+            (_, value) => value,
+        };
+        self.set(param, value)
+    }
+}
+
+/// Split a line on `;` and `,`, ignoring quoted text and tuples.
+fn split_statements(line: &str) -> impl Iterator<Item = &str> {
+    let mut quoted = false;
+    let mut tuple = false;
+    line.split(move |c| {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => tuple = true,
+            ')' if !quoted => tuple = false,
+            _ => {}
+        }
+        !quoted && !tuple && (c == ';' || c == ',')
+    })
+}
+
+/// Copy `src` into a nul-terminated `dst`, truncating if needed.
+fn copy_str<T: Copy + Default>(dst: &mut [T], src: impl IntoIterator<Item = T>) {
+    let len = dst.len() - 1;
+    let mut i = 0;
+    for (d, s) in dst[..len].iter_mut().zip(src) {
+        *d = s;
+        i += 1;
+    }
+    dst[i] = T::default();
+}
+
+fn from_utf16(value: &[u16]) -> String {
+    let len = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+    String::from_utf16_lossy(&value[..len])
+}
+
+impl SimulatedVoicemeeter {
+    fn device_desc<N: Copy + Default, const LEN: usize>(
+        &self,
+        input: bool,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [N; LEN]>,
+        hardware_id: Option<&mut [N; LEN]>,
+        encode: impl Fn(&str) -> Vec<N>,
+    ) -> i32 {
+        let state = self.state();
+        let devices = if input {
+            &state.input_devices
+        } else {
+            &state.output_devices
+        };
+        let Some(device) = usize::try_from(index).ok().and_then(|i| devices.get(i)) else {
+            return -1;
+        };
+        if let Some(r#type) = r#type {
+            *r#type = device.r#type.0;
+        }
+        if let Some(name) = name {
+            copy_str(name, encode(&device.name));
+        }
+        if let Some(hardware_id) = hardware_id {
+            copy_str(hardware_id, encode(&device.hardware_id));
+        }
+        0
+    }
+
+    fn get_value(&self, param: &CStr) -> Result<Value, i32> {
+        let state = self.state();
+        if !state.is_running() {
+            return Err(-2);
+        }
+        let param = param
+            .to_str()
+            .ok()
+            .and_then(|p| resolve(state.program, p))
+            .ok_or(-3)?;
+        Ok(state.value(&param))
+    }
+
+    fn set_value(&self, param: &CStr, value: Value) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        let Some(param) = param.to_str().ok().and_then(|p| resolve(state.program, p)) else {
+            return -3;
+        };
+        match state.set(param, value) {
+            Ok(()) => 0,
+            Err(_) => -3,
+        }
+    }
+}
+
+/// Encode like the ANSI code page of Voicemeeter, which replaces characters outside of ASCII with `?`.
+fn encode_a(s: &str) -> Vec<c_char> {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii() {
+                c as c_char
+            } else {
+                b'?' as c_char
+            }
+        })
+        .collect()
+}
+
+fn encode_w(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
+
+impl VoicemeeterBackend for SimulatedVoicemeeter {
+    fn login(&self) -> i32 {
+        let mut state = self.state();
+        if state.logged_in {
+            return -2;
+        }
+        state.logged_in = true;
+        if state.is_running() {
+            0
+        } else {
+            1
+        }
+    }
+
+    fn logout(&self) -> i32 {
+        self.state().logged_in = false;
+        0
+    }
+
+    fn run_voicemeeter(&self, r#type: i32) -> i32 {
+        let program = VoicemeeterApplication::from(r#type);
+        if matches!(program, VoicemeeterApplication::Other) {
+            return -2;
+        }
+        let mut state = self.state();
+        state.restart(program);
+        state.starting_until = Some(Instant::now() + state.startup_delay);
+        0
+    }
+
+    fn get_voicemeeter_type(&self, r#type: &mut i32) -> i32 {
+        let state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        *r#type = state.program as i32;
+        0
+    }
+
+    fn get_voicemeeter_version(&self, version: &mut i32) -> i32 {
+        let state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        *version = i32::from_be_bytes(state.version);
+        0
+    }
+
+    fn is_parameters_dirty(&self) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        std::mem::take(&mut state.parameters_dirty) as i32
+    }
+
+    fn get_parameter_float(&self, param: &CStr, value: &mut f32) -> i32 {
+        match self.get_value(param) {
+            Ok(Value::Float(f)) => {
+                *value = f;
+                0
+            }
+            Ok(Value::String(_)) => -5,
+            Err(e) => e,
+        }
+    }
+
+    fn get_parameter_string_a(&self, param: &CStr, value: &mut [c_char; 512]) -> i32 {
+        match self.get_value(param) {
+            Ok(Value::String(s)) => {
+                copy_str(value, encode_a(&s));
+                0
+            }
+            Ok(Value::Float(_)) => -5,
+            Err(e) => e,
+        }
+    }
+
+    fn get_parameter_string_w(&self, param: &CStr, value: &mut [u16; 512]) -> i32 {
+        match self.get_value(param) {
+            Ok(Value::String(s)) => {
+                copy_str(value, encode_w(&s));
+                0
+            }
+            Ok(Value::Float(_)) => -5,
+            Err(e) => e,
+        }
+    }
+
+    fn get_level(&self, level_type: i32, channel: i32, value: &mut f32) -> i32 {
+        let state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        let (input, output) = state.level_channels();
+        let Ok(channel) = usize::try_from(channel) else {
+            return -4;
+        };
+        *value = match LevelType::from(level_type) {
+            LevelType::Other => return -4,
+            LevelType::OutputLevels if channel < output => state.bus_level(channel),
+            LevelType::OutputLevels => return -4,
+            _ if channel < input => state.strip_level(LevelType::from(level_type), channel),
+            _ => return -4,
+        };
+        0
+    }
+
+    fn get_midi_message(&self, buffer: &mut [u8]) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        let Some(message) = state.midi.pop_front() else {
+            return -5;
+        };
+        let len = message.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&message[..len]);
+        len as i32
+    }
+
+    fn set_parameter_float(&self, param: &CStr, value: f32) -> i32 {
+        self.set_value(param, Value::Float(value))
+    }
+
+    fn set_parameter_string_a(&self, param: &CStr, value: &CStr) -> i32 {
+        self.set_value(param, Value::String(value.to_string_lossy().into_owned()))
+    }
+
+    fn set_parameter_string_w(&self, param: &CStr, value: &[u16]) -> i32 {
+        self.set_value(param, Value::String(from_utf16(value)))
+    }
+
+    fn set_parameters(&self, script: &CStr) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        match state.run_script(&script.to_string_lossy()) {
+            Ok(()) => 0,
+            Err(line) => line as i32,
+        }
+    }
+
+    fn set_parameters_w(&self, script: &[u16]) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        match state.run_script(&from_utf16(script)) {
+            Ok(()) => 0,
+            Err(line) => line as i32,
+        }
+    }
+
+    fn output_get_device_number(&self) -> i32 {
+        self.state().output_devices.len() as i32
+    }
+
+    fn output_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32 {
+        self.device_desc(false, index, r#type, name, hardware_id, encode_a)
+    }
+
+    fn output_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32 {
+        self.device_desc(false, index, r#type, name, hardware_id, encode_w)
+    }
+
+    fn input_get_device_number(&self) -> i32 {
+        self.state().input_devices.len() as i32
+    }
+
+    fn input_get_device_desc_a(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [c_char; 256]>,
+        hardware_id: Option<&mut [c_char; 256]>,
+    ) -> i32 {
+        self.device_desc(true, index, r#type, name, hardware_id, encode_a)
+    }
+
+    fn input_get_device_desc_w(
+        &self,
+        index: i32,
+        r#type: Option<&mut i32>,
+        name: Option<&mut [u16; 256]>,
+        hardware_id: Option<&mut [u16; 256]>,
+    ) -> i32 {
+        self.device_desc(true, index, r#type, name, hardware_id, encode_w)
+    }
+
+    unsafe fn audio_callback_register(
+        &self,
+        mode: i32,
+        callback: T_VBVMR_VBAUDIOCALLBACK,
+        user_data: *mut c_void,
+        client_name: &mut [c_char; 64],
+    ) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -1;
+        }
+        if let Some(registration) = &state.callback {
+            copy_str(
+                client_name,
+                registration.client_name.iter().map(|b| *b as c_char),
+            );
+            return 1;
+        }
+        let len = client_name.iter().position(|c| *c == 0).unwrap_or(64);
+        state.callback = Some(Registration {
+            client_name: client_name[..len].iter().map(|c| *c as u8).collect(),
+            mode,
+            callback,
+            user_data: user_data as usize,
+        });
+        0
+    }
+
+    fn audio_callback_start(&self) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -1;
+        }
+        if state.callback.is_none() {
+            return 1;
+        }
+        state.callback_started = true;
+        0
+    }
+
+    fn audio_callback_stop(&self) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -1;
+        }
+        if state.callback.is_none() {
+            return 1;
+        }
+        state.callback_started = false;
+        0
+    }
+
+    fn audio_callback_unregister(&self) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -1;
+        }
+        if state.callback.take().is_none() {
+            return 1;
+        }
+        state.callback_started = false;
+        0
+    }
+
+    fn macro_button_is_dirty(&self) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        std::mem::take(&mut state.macro_buttons_dirty) as i32
+    }
+
+    fn macro_button_get_status(&self, button: i32, value: &mut f32, bitmode: i32) -> i32 {
+        let state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        if !(0..MACRO_BUTTONS).contains(&button) {
+            return -3;
+        }
+        let b = state
+            .macro_buttons
+            .get(&button)
+            .copied()
+            .unwrap_or_default();
+        let on = match VBVMR_MACROBUTTON_MODE(bitmode) {
+            VBVMR_MACROBUTTON_MODE::DEFAULT | VBVMR_MACROBUTTON_MODE::STATEONLY => b.state,
+            VBVMR_MACROBUTTON_MODE::TRIGGER => b.trigger,
+            _ => return -5,
+        };
+        *value = if on { 1.0 } else { 0.0 };
+        0
+    }
+
+    fn macro_button_set_status(&self, button: i32, value: f32, bitmode: i32) -> i32 {
+        let mut state = self.state();
+        if !state.is_running() {
+            return -2;
+        }
+        if !(0..MACRO_BUTTONS).contains(&button) {
+            return -3;
+        }
+        let b = state.macro_buttons.entry(button).or_default();
+        match VBVMR_MACROBUTTON_MODE(bitmode) {
+            VBVMR_MACROBUTTON_MODE::DEFAULT | VBVMR_MACROBUTTON_MODE::STATEONLY => {
+                b.state = value != 0.0
+            }
+            VBVMR_MACROBUTTON_MODE::TRIGGER => b.trigger = value != 0.0,
+            _ => return -5,
+        }
+        state.macro_buttons_dirty = true;
+        0
+    }
+}
+
+/// A remote connected to a new simulated `program`, shared by the tests of the crate.
+#[cfg(test)]
+pub(crate) fn remote(
+    program: VoicemeeterApplication,
+) -> (
+    std::sync::Arc<SimulatedVoicemeeter>,
+    crate::VoicemeeterRemote,
+) {
+    let simulator = std::sync::Arc::new(SimulatedVoicemeeter::new(program));
+    let remote = crate::VoicemeeterRemote::with_backend(simulator.clone()).unwrap();
+    (simulator, remote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        interface::parameters::{
+            get_parameters::GetParameterError, set_parameters::SetParameterError,
+            set_parameters::SetParametersError, ParameterError,
+        },
+        types::{BusMode, Device},
+    };
+
+    #[test]
+    fn layouts() {
+        for (program, strips, buses) in [
+            (VoicemeeterApplication::Voicemeeter, 3, 2),
+            (VoicemeeterApplication::VoicemeeterBanana, 5, 5),
+            (VoicemeeterApplication::VoicemeeterPotato, 8, 8),
+        ] {
+            let (simulator, remote) = remote(program);
+            assert_eq!(remote.program(), program);
+            assert_eq!(simulator.strips(), strips);
+            assert_eq!(simulator.buses(), buses);
+            assert_eq!(
+                remote.get_voicemeeter_version().unwrap().0,
+                program as u8,
+                "{program}"
+            );
+            let unknown = format!("Strip[{strips}].Gain");
+            assert!(matches!(
+                remote.get_parameter_float(unknown.as_str().into()),
+                Err(GetParameterError::UnknownParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn parameters() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let strip = remote.parameters().strip(1)?;
+        strip.gain().set(-12.5)?;
+        strip.label().set("Mic")?;
+        strip.a2().set(true)?;
+        assert_eq!(strip.gain().get()?, -12.5);
+        assert_eq!(strip.label().get()?, "Mic");
+        assert!(strip.a2().get()?);
+        assert!(!strip.a1().get()?);
+        assert!(matches!(
+            remote.parameters().strip(5),
+            Err(ParameterError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            remote.set_parameter_float("Strip[0].A4".into(), 1.0),
+            Err(SetParameterError::UnknownParameter(_))
+        ));
+        assert!(matches!(
+            remote.set_parameter_float("Strip[0].Bogus".into(), 1.0),
+            Err(SetParameterError::UnknownParameter(_))
+        ));
+        assert_eq!(
+            remote
+                .parameters()
+                .vban()
+                .incoming_stream(0)?
+                .port()
+                .get()?,
+            6980
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dirty_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        assert!(!remote.is_parameters_dirty()?);
+        remote.parameters().bus(0)?.mute().set(true)?;
+        assert!(remote.is_parameters_dirty()?);
+        assert!(!remote.is_parameters_dirty()?);
+
+        simulator.gui_set_float("Bus[0].Gain", -3.0)?;
+        assert!(simulator.is_dirty());
+        assert!(remote.is_parameters_dirty()?);
+        assert_eq!(remote.parameters().bus(0)?.gain().get()?, -3.0);
+
+        remote.parameters().bus(1)?.mode().set(BusMode::Composite)?;
+        assert_eq!(
+            remote.parameters().bus(1)?.mode().get()?,
+            Some(BusMode::Composite)
+        );
+
+        simulator.gui_set_macro_button(3, true);
+        assert!(remote.is_macrobutton_dirty()?);
+        assert!(!remote.is_macrobutton_dirty()?);
+        assert!(remote.get_macrobutton_state(3)?.0);
+        Ok(())
+    }
+
+    #[test]
+    fn scripts() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        remote
+            .set_parameters("Strip[0].Gain = -10; Strip[0].Label = \"a;b\"\nStrip[0].Gain += 4")?;
+        assert_eq!(simulator.float("Strip[0].Gain")?, -6.0);
+        assert_eq!(simulator.string("Strip[0].Label")?, "a;b");
+        assert!(matches!(
+            remote.set_parameters("Bus[0].Mute = 1\nBus[9].Mute = 1"),
+            Err(SetParametersError::ScriptError(2))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn levels_and_devices() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        simulator.set_input_level(0, Some(0.5));
+        simulator.set_input_level(1, Some(0.5));
+        remote.parameters().strip(0)?.gain().set(-20.0)?;
+        let level = |level_type, device, channel| remote.get_level(level_type, device, channel);
+        assert_eq!(
+            level(LevelType::PreFaderInputLevels, Device::Strip1, 0)?,
+            Some(0.5)
+        );
+        assert_eq!(
+            level(LevelType::PostFaderInputLevels, Device::Strip1, 0)?,
+            Some(0.05)
+        );
+        assert_eq!(
+            level(LevelType::OutputLevels, Device::OutputA1, 0)?,
+            Some(0.0)
+        );
+
+        remote.parameters().strip(0)?.a1().set(true)?;
+        assert_eq!(
+            level(LevelType::OutputLevels, Device::OutputA1, 0)?,
+            Some(0.05)
+        );
+        remote.parameters().strip(0)?.mute().set(true)?;
+        assert_eq!(
+            level(LevelType::PostMuteInputLevels, Device::Strip1, 0)?,
+            Some(0.0)
+        );
+        assert_eq!(
+            level(LevelType::OutputLevels, Device::OutputA1, 0)?,
+            Some(0.0)
+        );
+
+        let generated = level(LevelType::PreFaderInputLevels, Device::Strip2, 0)?;
+        simulator.advance(Duration::from_millis(500));
+        assert_ne!(
+            level(LevelType::PreFaderInputLevels, Device::Strip2, 0)?,
+            generated
+        );
+
+        simulator.push_midi_message(&[0xB0, 0x07, 0x40]);
+        assert_eq!(remote.get_midi_message()?, [0xB0, 0x07, 0x40]);
+        assert!(remote.get_midi_message().is_err());
+
+        simulator.add_output_device(VBVMR_DEVTYPE::WDM, "Speakers", "hw-1");
+        assert_eq!(remote.get_total_output_device()?, 1);
+        assert_eq!(remote.get_output_device(0)?.name, "Speakers");
+        Ok(())
+    }
+
+    #[test]
+    fn logout_on_last_drop() {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        assert!(simulator.is_logged_in());
+        let clone = remote.clone();
+        drop(remote);
+        assert!(simulator.is_logged_in());
+        drop(clone);
+        assert!(!simulator.is_logged_in());
+    }
+}
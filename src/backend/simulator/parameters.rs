@@ -0,0 +1,432 @@
+//! Parameters known by the simulator.
+use std::{collections::HashMap, sync::OnceLock};
+
+use crate::{
+    interface::parameters::{command::MACRO_BUTTONS, patch::input_channels},
+    types::VoicemeeterApplication,
+};
+
+/// Kind of value stored in a parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Kind {
+    Float,
+    String,
+}
+
+use Kind::{Float as F, String as S};
+
+/// Top level group of a parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum Group {
+    Strip,
+    Bus,
+    Command,
+    Option,
+    Patch,
+    Recorder,
+    Fx,
+    Vban,
+}
+
+/// Indexes in the templates are written as `[x]`, where `x` is
+///
+/// * `b` - a bus index
+/// * `c` - an EQ channel
+/// * `k` - an EQ cell
+/// * `s` - a VBAN stream
+/// * `a` - an application index
+/// * `m` - a macro button
+/// * `i` - a strip index
+/// * `p` - an input channel of the physical strips
+/// * `n` - an input channel
+/// * `o` - a channel of a bus
+static STRIP: &[(&str, Kind, f32)] = &[
+    ("Mono", F, 0.0),
+    ("Mute", F, 0.0),
+    ("Solo", F, 0.0),
+    ("MC", F, 0.0),
+    ("Gain", F, 0.0),
+    ("GainLayer[b]", F, 0.0),
+    ("Pan_x", F, 0.0),
+    ("Pan_y", F, 0.0),
+    ("Color_x", F, 0.0),
+    ("Color_y", F, 0.0),
+    ("fx_x", F, 0.0),
+    ("fx_y", F, 0.0),
+    ("Audability", F, 0.0),
+    ("Comp", F, 0.0),
+    ("Gate", F, 0.0),
+    ("Denoiser", F, 0.0),
+    ("Karaoke", F, 0.0),
+    ("Limit", F, 12.0),
+    ("Label", S, 0.0),
+    ("A1", F, 0.0),
+    ("A2", F, 0.0),
+    ("A3", F, 0.0),
+    ("A4", F, 0.0),
+    ("A5", F, 0.0),
+    ("B1", F, 0.0),
+    ("B2", F, 0.0),
+    ("B3", F, 0.0),
+    ("EQGain1", F, 0.0),
+    ("EQGain2", F, 0.0),
+    ("EQGain3", F, 0.0),
+    ("EQ.on", F, 0.0),
+    ("EQ.AB", F, 0.0),
+    ("EQ.channel[c].cell[k].on", F, 0.0),
+    ("EQ.channel[c].cell[k].type", F, 0.0),
+    ("EQ.channel[c].cell[k].f", F, 1000.0),
+    ("EQ.channel[c].cell[k].gain", F, 0.0),
+    ("EQ.channel[c].cell[k].q", F, 3.0),
+    ("FadeTo", S, 0.0),
+    ("FadeBy", S, 0.0),
+    ("Reverb", F, 0.0),
+    ("Delay", F, 0.0),
+    ("Fx1", F, 0.0),
+    ("Fx2", F, 0.0),
+    ("PostReverb", F, 0.0),
+    ("PostDelay", F, 0.0),
+    ("PostFx1", F, 0.0),
+    ("PostFx2", F, 0.0),
+    ("AppGain", S, 0.0),
+    ("AppMute", S, 0.0),
+    ("App[a].Gain", F, 1.0),
+    ("App[a].Mute", F, 0.0),
+    ("device.name", S, 0.0),
+    ("device.sr", F, 0.0),
+    ("device.wdm", S, 0.0),
+    ("device.ks", S, 0.0),
+    ("device.mme", S, 0.0),
+    ("device.asio", S, 0.0),
+    ("comp.GainIn", F, 0.0),
+    ("comp.Ratio", F, 1.0),
+    ("comp.Threshold", F, -20.0),
+    ("comp.Attack", F, 10.0),
+    ("comp.Release", F, 50.0),
+    ("comp.Knee", F, 0.5),
+    ("comp.GainOut", F, 0.0),
+    ("comp.MakeUp", F, 0.0),
+    ("Gate.Threshold", F, -60.0),
+    ("Gate.Damping", F, -60.0),
+    ("Gate.BPSidechain", F, 100.0),
+    ("Gate.Attack", F, 0.0),
+    ("Gate.Hold", F, 500.0),
+    ("Gate.Release", F, 1000.0),
+];
+
+static BUS: &[(&str, Kind, f32)] = &[
+    ("Label", S, 0.0),
+    ("Mono", F, 0.0),
+    ("Mute", F, 0.0),
+    ("Gain", F, 0.0),
+    ("Sel", F, 0.0),
+    ("Monitor", F, 0.0),
+    ("EQ.on", F, 0.0),
+    ("EQ.AB", F, 0.0),
+    ("EQ.channel[c].cell[k].on", F, 0.0),
+    ("EQ.channel[c].cell[k].type", F, 0.0),
+    ("EQ.channel[c].cell[k].f", F, 1000.0),
+    ("EQ.channel[c].cell[k].gain", F, 0.0),
+    ("EQ.channel[c].cell[k].q", F, 3.0),
+    ("FadeTo", S, 0.0),
+    ("FadeBy", S, 0.0),
+    ("ReturnReverb", F, 0.0),
+    ("ReturnDelay", F, 0.0),
+    ("ReturnFx1", F, 0.0),
+    ("ReturnFx2", F, 0.0),
+    ("mode.normal", F, 1.0),
+    ("mode.Amix", F, 0.0),
+    ("mode.Bmix", F, 0.0),
+    ("mode.Repeat", F, 0.0),
+    ("mode.Composite", F, 0.0),
+    ("mode.TVMix", F, 0.0),
+    ("mode.UpMix21", F, 0.0),
+    ("mode.UpMix41", F, 0.0),
+    ("mode.UpMix61", F, 0.0),
+    ("mode.CenterOnly", F, 0.0),
+    ("mode.LFEOnly", F, 0.0),
+    ("mode.RearOnly", F, 0.0),
+    ("device.name", S, 0.0),
+    ("device.sr", F, 0.0),
+    ("device.wdm", S, 0.0),
+    ("device.ks", S, 0.0),
+    ("device.mme", S, 0.0),
+    ("device.asio", S, 0.0),
+];
+
+static COMMAND: &[(&str, Kind, f32)] = &[
+    ("Shutdown", F, 0.0),
+    ("Show", F, 0.0),
+    ("Restart", F, 0.0),
+    ("Eject", F, 0.0),
+    ("Reset", F, 0.0),
+    ("Save", S, 0.0),
+    ("Load", S, 0.0),
+    ("Lock", F, 0.0),
+    ("Button[m].State", F, 0.0),
+    ("Button[m].StateOnly", F, 0.0),
+    ("Button[m].Trigger", F, 0.0),
+    ("SaveBUSEQ[b]", S, 0.0),
+    ("LoadBUSEQ[b]", S, 0.0),
+    ("SaveStripEQ[i]", S, 0.0),
+    ("LoadStripEQ[i]", S, 0.0),
+    ("DialogShow.VBANCHAT", F, 0.0),
+];
+
+static OPTION: &[(&str, Kind, f32)] = &[
+    ("sr", F, 48000.0),
+    ("ASIOsr", F, 0.0),
+    ("delay[b]", F, 0.0),
+    ("buffer.mme", F, 1024.0),
+    ("buffer.wdm", F, 512.0),
+    ("buffer.ks", F, 512.0),
+    ("buffer.asio", F, 0.0),
+    ("mode.exclusif", F, 0.0),
+    ("mode.swift", F, 0.0),
+    ("MonitorOnSEL", F, 0.0),
+];
+
+static PATCH: &[(&str, Kind, f32)] = &[
+    ("asio[p]", F, 0.0),
+    ("OutA2[o]", F, 0.0),
+    ("OutA3[o]", F, 0.0),
+    ("OutA4[o]", F, 0.0),
+    ("OutA5[o]", F, 0.0),
+    ("composite[o]", F, 0.0),
+    ("insert[n]", F, 0.0),
+    ("PostFaderComposite", F, 0.0),
+    ("PostFxInsert", F, 0.0),
+];
+
+static RECORDER: &[(&str, Kind, f32)] = &[
+    ("stop", F, 0.0),
+    ("play", F, 0.0),
+    ("replay", F, 0.0),
+    ("ff", F, 0.0),
+    ("rew", F, 0.0),
+    ("goto", S, 0.0),
+    ("record", F, 0.0),
+    ("pause", F, 0.0),
+    ("load", S, 0.0),
+    ("samplerate", F, 48000.0),
+    ("bitResolution", F, 24.0),
+    ("Channel", F, 2.0),
+    ("kbps", F, 256.0),
+    ("FileType", F, 1.0),
+    ("gain", F, 0.0),
+    ("mode.recbus", F, 0.0),
+    ("mode.PlayOnLoad", F, 0.0),
+    ("mode.Loop", F, 0.0),
+    ("mode.MultiTrack", F, 0.0),
+];
+
+static FX: &[(&str, Kind, f32)] = &[
+    ("Reverb.On", F, 0.0),
+    ("Reverb.AB", F, 0.0),
+    ("Reverb.Size", F, 5.0),
+    ("Reverb.Damp", F, 5.0),
+    ("Reverb.Diffusion", F, 5.0),
+    ("Reverb.Bass", F, 1.0),
+    ("Reverb.LoCut", F, 100.0),
+    ("Reverb.HiCut", F, 10000.0),
+    ("Reverb.Wet", F, 0.0),
+    ("Reverb.Dry", F, -60.0),
+    ("Reverb.PreDelay", F, 0.0),
+    ("Delay.On", F, 0.0),
+    ("Delay.AB", F, 0.0),
+    ("Delay.Time", F, 500.0),
+    ("Delay.Feedback", F, 50.0),
+    ("Delay.HP", F, 100.0),
+    ("Delay.LP", F, 10000.0),
+    ("Delay.PingPong", F, 0.0),
+];
+
+static VBAN: &[(&str, Kind, f32)] = &[
+    ("Enable", F, 0.0),
+    ("instream[s].on", F, 0.0),
+    ("instream[s].name", S, 0.0),
+    ("instream[s].ip", S, 0.0),
+    ("instream[s].port", F, 6980.0),
+    ("instream[s].quality", F, 0.0),
+    ("instream[s].route", F, 0.0),
+    ("instream[s].sr", F, 48000.0),
+    ("instream[s].channel", F, 2.0),
+    ("instream[s].bit", F, 1.0),
+    ("outstream[s].on", F, 0.0),
+    ("outstream[s].name", S, 0.0),
+    ("outstream[s].ip", S, 0.0),
+    ("outstream[s].port", F, 6980.0),
+    ("outstream[s].quality", F, 0.0),
+    ("outstream[s].route", F, 0.0),
+    ("outstream[s].sr", F, 48000.0),
+    ("outstream[s].channel", F, 2.0),
+    ("outstream[s].bit", F, 1.0),
+];
+
+/// A known parameter.
+#[derive(Debug, Clone)]
+pub(super) struct Entry {
+    pub(super) kind: Kind,
+    pub(super) default: f32,
+    /// Placeholder for each index in the name.
+    bounds: Vec<u8>,
+}
+
+/// A parameter name that has been resolved against the known parameters.
+#[derive(Debug, Clone)]
+pub(super) struct Resolved {
+    /// Normalized name, used as key for storing the value.
+    pub(super) key: String,
+    pub(super) group: Group,
+    /// Strip or bus index
+    pub(super) index: Option<usize>,
+    /// The name after the group, e.g `gain` for `Strip[0].Gain`.
+    pub(super) rest: String,
+    pub(super) entry: &'static Entry,
+}
+
+/// Replace all `[...]` with `[]`, returning the contents.
+fn split_indexes(name: &str) -> (String, Vec<&str>) {
+    let mut normalized = String::with_capacity(name.len());
+    let mut indexes = vec![];
+    let mut rest = name;
+    while let Some(start) = rest.find('[') {
+        let Some(end) = rest[start..].find(']') else {
+            break;
+        };
+        normalized.push_str(&rest[..=start]);
+        normalized.push(']');
+        indexes.push(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+    normalized.push_str(rest);
+    (normalized, indexes)
+}
+
+fn known() -> &'static HashMap<(Group, String), Entry> {
+    static KNOWN: OnceLock<HashMap<(Group, String), Entry>> = OnceLock::new();
+    KNOWN.get_or_init(|| {
+        let mut map = HashMap::new();
+        for (group, params) in [
+            (Group::Strip, STRIP),
+            (Group::Bus, BUS),
+            (Group::Command, COMMAND),
+            (Group::Option, OPTION),
+            (Group::Patch, PATCH),
+            (Group::Recorder, RECORDER),
+            (Group::Fx, FX),
+            (Group::Vban, VBAN),
+        ] {
+            for (template, kind, default) in params {
+                let template = template.to_ascii_lowercase();
+                let (key, bounds) = split_indexes(&template);
+                let bounds = bounds.into_iter().map(|b| b.as_bytes()[0]).collect();
+                map.insert(
+                    (group, key),
+                    Entry {
+                        kind: *kind,
+                        default: *default,
+                        bounds,
+                    },
+                );
+            }
+        }
+        map
+    })
+}
+
+pub(super) fn strips(program: VoicemeeterApplication) -> usize {
+    program.devices().iter().filter(|d| d.is_strip()).count()
+}
+
+pub(super) fn buses(program: VoicemeeterApplication) -> usize {
+    program.devices().iter().filter(|d| d.is_bus()).count()
+}
+
+/// Amount of buses named `A` or `B` respectively.
+fn physical_virtual_buses(program: VoicemeeterApplication) -> (usize, usize) {
+    program
+        .devices()
+        .iter()
+        .filter_map(|d| d.as_bus_index(&program))
+        .fold((0, 0), |(a, b), (_, name)| {
+            if name.starts_with('A') {
+                (a + 1, b)
+            } else {
+                (a, b + 1)
+            }
+        })
+}
+
+/// Resolve a parameter name in the given program.
+///
+/// Returns `None` if the parameter is not known.
+pub(super) fn resolve(program: VoicemeeterApplication, name: &str) -> Option<Resolved> {
+    let name = name.trim().to_ascii_lowercase();
+    let (group, rest) = name.split_once('.')?;
+    let (group, index) = match group {
+        "command" => (Group::Command, None),
+        "option" => (Group::Option, None),
+        "patch" => (Group::Patch, None),
+        "recorder" => (Group::Recorder, None),
+        "fx" => (Group::Fx, None),
+        "vban" => (Group::Vban, None),
+        _ => {
+            let (group, index) = group.strip_suffix(']')?.split_once('[')?;
+            let index: usize = index.parse().ok()?;
+            match group {
+                "strip" if index < strips(program) => (Group::Strip, Some(index)),
+                "bus" if index < buses(program) => (Group::Bus, Some(index)),
+                _ => return None,
+            }
+        }
+    };
+    let (template, indexes) = split_indexes(rest);
+    let entry = known().get(&(group, template))?;
+    for (bound, index) in entry.bounds.iter().zip(&indexes) {
+        let index: usize = index.parse().ok()?;
+        let max = match bound {
+            b'b' => buses(program),
+            b'i' => strips(program),
+            b'm' => MACRO_BUTTONS as usize,
+            b'p' => input_channels(program, true),
+            b'n' => input_channels(program, false),
+            b'o' => 8,
+            b'c' => 8,
+            b'k' => 6,
+            b's' if program == VoicemeeterApplication::Voicemeeter => 4,
+            b's' => 8,
+            _ => usize::MAX,
+        };
+        if index >= max {
+            return None;
+        }
+    }
+    if group == Group::Strip {
+        let (physical, virt) = physical_virtual_buses(program);
+        let bus = |n: &str| n.parse::<usize>().ok();
+        match rest.split_at(1) {
+            ("a", n) if bus(n).is_some_and(|n| n > physical) => return None,
+            ("b", n) if bus(n).is_some_and(|n| n > virt) => return None,
+            _ => {}
+        }
+    }
+    let key = match index {
+        Some(index) => format!(
+            "{}[{index}].{rest}",
+            if group == Group::Strip {
+                "strip"
+            } else {
+                "bus"
+            }
+        ),
+        None => name.clone(),
+    };
+    Some(Resolved {
+        key,
+        group,
+        index,
+        rest: rest.to_owned(),
+        entry,
+    })
+}
@@ -24,13 +24,13 @@ impl VBVMR_DEVTYPE {
 #[repr(transparent)]
 #[doc = " @name Device Enumeration Functions\n @{"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct VBVMR_DEVTYPE(pub ::std::os::raw::c_int);
+pub struct VBVMR_DEVTYPE(pub crate::ctypes::c_int);
 #[doc = " @name VB-Audio Callback Functions\n @{"]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct tagVBVMR_AUDIOINFO {
-    pub samplerate: ::std::os::raw::c_long,
-    pub nbSamplePerFrame: ::std::os::raw::c_long,
+    pub samplerate: crate::ctypes::c_long,
+    pub nbSamplePerFrame: crate::ctypes::c_long,
 }
 #[test]
 fn bindgen_test_layout_tagVBVMR_AUDIOINFO() {
@@ -76,10 +76,10 @@ pub type VBVMR_LPT_AUDIOINFO = *mut tagVBVMR_AUDIOINFO;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct tagVBVMR_AUDIOBUFFER {
-    pub audiobuffer_sr: ::std::os::raw::c_long,
-    pub audiobuffer_nbs: ::std::os::raw::c_long,
-    pub audiobuffer_nbi: ::std::os::raw::c_long,
-    pub audiobuffer_nbo: ::std::os::raw::c_long,
+    pub audiobuffer_sr: crate::ctypes::c_long,
+    pub audiobuffer_nbs: crate::ctypes::c_long,
+    pub audiobuffer_nbi: crate::ctypes::c_long,
+    pub audiobuffer_nbo: crate::ctypes::c_long,
     pub audiobuffer_r: [*mut f32; 128usize],
     pub audiobuffer_w: [*mut f32; 128usize],
 }
@@ -164,11 +164,11 @@ pub type VBVMR_LPT_AUDIOBUFFER = *mut tagVBVMR_AUDIOBUFFER;
 #[doc = "@brief VB-AUDIO Callback is called for different task to Initialize, perform and end your process.\nVB-AUDIO Callback is part of single TIME CRITICAL Thread.\nVB-AUDIO Callback is non re-entrant (cannot be called while in process)\nVB-AUDIO Callback is supposed to be REAL TIME when called to process buffer.\n(it means that the process has to be performed as fast as possible, waiting cycles are forbidden.\ndo not use O/S synchronization object, even Critical_Section can generate waiting cycle. Do not use\nsystem functions that can generate waiting cycle like display, disk or communication functions for example).\n\n@param lpUser: User pointer given on callback registration.\n@param ncommand: reason why the callback is called.\n@param lpData: pointer on structure, pending on nCommand.\n@param nnn: additional data, unused\n\n@return :\t 0: always 0 (unused)."]
 pub type T_VBVMR_VBAUDIOCALLBACK = ::std::option::Option<
     unsafe extern "C" fn(
-        lpUser: *mut ::std::os::raw::c_void,
-        nCommand: ::std::os::raw::c_long,
-        lpData: *mut ::std::os::raw::c_void,
-        nnn: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long,
+        lpUser: *mut crate::ctypes::c_void,
+        nCommand: crate::ctypes::c_long,
+        lpData: *mut crate::ctypes::c_void,
+        nnn: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long,
 >;
 impl VBVMR_CBCOMMAND {
     pub const STARTING: VBVMR_CBCOMMAND = VBVMR_CBCOMMAND(1);
@@ -190,7 +190,7 @@ impl VBVMR_CBCOMMAND {
 }
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct VBVMR_CBCOMMAND(pub ::std::os::raw::c_int);
+pub struct VBVMR_CBCOMMAND(pub crate::ctypes::c_int);
 impl VBVMR_AUDIOCALLBACK {
     pub const INPUT: VBVMR_AUDIOCALLBACK = VBVMR_AUDIOCALLBACK(1);
 }
@@ -228,7 +228,7 @@ impl ::std::ops::BitAndAssign for VBVMR_AUDIOCALLBACK {
 }
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct VBVMR_AUDIOCALLBACK(pub ::std::os::raw::c_int);
+pub struct VBVMR_AUDIOCALLBACK(pub crate::ctypes::c_int);
 impl VBVMR_MACROBUTTON_MODE {
     pub const DEFAULT: VBVMR_MACROBUTTON_MODE = VBVMR_MACROBUTTON_MODE(0);
 }
@@ -240,140 +240,140 @@ impl VBVMR_MACROBUTTON_MODE {
 }
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct VBVMR_MACROBUTTON_MODE(pub ::std::os::raw::c_int);
-pub type T_VBVMR_Login = ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
-pub type T_VBVMR_Logout = ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+pub struct VBVMR_MACROBUTTON_MODE(pub crate::ctypes::c_int);
+pub type T_VBVMR_Login = ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
+pub type T_VBVMR_Logout = ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_RunVoicemeeter = ::std::option::Option<
-    unsafe extern "C" fn(vType: ::std::os::raw::c_long) -> ::std::os::raw::c_long,
+    unsafe extern "C" fn(vType: crate::ctypes::c_long) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_GetVoicemeeterType = ::std::option::Option<
-    unsafe extern "C" fn(pType: *mut ::std::os::raw::c_long) -> ::std::os::raw::c_long,
+    unsafe extern "C" fn(pType: *mut crate::ctypes::c_long) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_GetVoicemeeterVersion = ::std::option::Option<
-    unsafe extern "C" fn(pVersion: *mut ::std::os::raw::c_long) -> ::std::os::raw::c_long,
+    unsafe extern "C" fn(pVersion: *mut crate::ctypes::c_long) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_IsParametersDirty =
-    ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+    ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_GetParameterFloat = ::std::option::Option<
     unsafe extern "C" fn(
-        szParamName: *mut ::std::os::raw::c_char,
+        szParamName: *mut crate::ctypes::c_char,
         pValue: *mut f32,
-    ) -> ::std::os::raw::c_long,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_GetParameterStringA = ::std::option::Option<
     unsafe extern "C" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        szString: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
+        szParamName: *mut crate::ctypes::c_char,
+        szString: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_GetParameterStringW = ::std::option::Option<
     unsafe extern "C" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        wszString: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
+        szParamName: *mut crate::ctypes::c_char,
+        wszString: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_GetLevel = ::std::option::Option<
     unsafe extern "C" fn(
-        nType: ::std::os::raw::c_long,
-        nuChannel: ::std::os::raw::c_long,
+        nType: crate::ctypes::c_long,
+        nuChannel: crate::ctypes::c_long,
         pValue: *mut f32,
-    ) -> ::std::os::raw::c_long,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_GetMidiMessage = ::std::option::Option<
     unsafe extern "C" fn(
-        pMIDIBuffer: *mut ::std::os::raw::c_uchar,
-        nbByteMax: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long,
+        pMIDIBuffer: *mut crate::ctypes::c_uchar,
+        nbByteMax: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_SetParameterFloat = ::std::option::Option<
     unsafe extern "C" fn(
-        szParamName: *mut ::std::os::raw::c_char,
+        szParamName: *mut crate::ctypes::c_char,
         Value: f32,
-    ) -> ::std::os::raw::c_long,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_SetParameters = ::std::option::Option<
-    unsafe extern "C" fn(szParamScript: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_long,
+    unsafe extern "C" fn(szParamScript: *mut crate::ctypes::c_char) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_SetParametersW = ::std::option::Option<
-    unsafe extern "C" fn(szParamScript: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long,
+    unsafe extern "C" fn(szParamScript: *mut crate::ctypes::c_ushort) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_SetParameterStringA = ::std::option::Option<
     unsafe extern "C" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        szString: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
+        szParamName: *mut crate::ctypes::c_char,
+        szString: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_SetParameterStringW = ::std::option::Option<
     unsafe extern "C" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        wszString: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
+        szParamName: *mut crate::ctypes::c_char,
+        wszString: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_Output_GetDeviceNumber =
-    ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+    ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_Output_GetDeviceDescA = ::std::option::Option<
     unsafe extern "C" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        szDeviceName: *mut ::std::os::raw::c_char,
-        szHardwareId: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        szDeviceName: *mut crate::ctypes::c_char,
+        szHardwareId: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_Output_GetDeviceDescW = ::std::option::Option<
     unsafe extern "C" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        wszDeviceName: *mut ::std::os::raw::c_ushort,
-        wszHardwareId: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        wszDeviceName: *mut crate::ctypes::c_ushort,
+        wszHardwareId: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_Input_GetDeviceNumber =
-    ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+    ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_Input_GetDeviceDescA = ::std::option::Option<
     unsafe extern "C" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        szDeviceName: *mut ::std::os::raw::c_char,
-        szHardwareId: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        szDeviceName: *mut crate::ctypes::c_char,
+        szHardwareId: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_Input_GetDeviceDescW = ::std::option::Option<
     unsafe extern "C" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        wszDeviceName: *mut ::std::os::raw::c_ushort,
-        wszHardwareId: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        wszDeviceName: *mut crate::ctypes::c_ushort,
+        wszHardwareId: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_AudioCallbackRegister = ::std::option::Option<
     unsafe extern "C" fn(
-        mode: ::std::os::raw::c_long,
+        mode: crate::ctypes::c_long,
         pCallback: T_VBVMR_VBAUDIOCALLBACK,
-        lpUser: *mut ::std::os::raw::c_void,
-        szClientName: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
+        lpUser: *mut crate::ctypes::c_void,
+        szClientName: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_AudioCallbackStart =
-    ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+    ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_AudioCallbackStop =
-    ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+    ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_AudioCallbackUnregister =
-    ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+    ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_MacroButton_IsDirty =
-    ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_long>;
+    ::std::option::Option<unsafe extern "C" fn() -> crate::ctypes::c_long>;
 pub type T_VBVMR_MacroButton_GetStatus = ::std::option::Option<
     unsafe extern "C" fn(
-        nuLogicalButton: ::std::os::raw::c_long,
+        nuLogicalButton: crate::ctypes::c_long,
         pValue: *mut f32,
-        bitmode: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long,
+        bitmode: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long,
 >;
 pub type T_VBVMR_MacroButton_SetStatus = ::std::option::Option<
     unsafe extern "C" fn(
-        nuLogicalButton: ::std::os::raw::c_long,
+        nuLogicalButton: crate::ctypes::c_long,
         fValue: f32,
-        bitmode: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long,
+        bitmode: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long,
 >;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -723,28 +723,28 @@ pub type LPT_VBVMR_INTERFACE = *mut tagVBVMR_INTERFACE;
 #[repr(C, packed)]
 #[derive(Debug, Copy, Clone)]
 pub struct tagVBAN_VMRT_PACKET {
-    pub voicemeeterType: ::std::os::raw::c_uchar,
-    pub reserved: ::std::os::raw::c_uchar,
-    pub buffersize: ::std::os::raw::c_ushort,
-    pub voicemeeterVersion: ::std::os::raw::c_ulong,
-    pub optionBits: ::std::os::raw::c_ulong,
-    pub samplerate: ::std::os::raw::c_ulong,
-    pub inputLeveldB100: [::std::os::raw::c_short; 34usize],
-    pub outputLeveldB100: [::std::os::raw::c_short; 64usize],
-    pub TransportBit: ::std::os::raw::c_ulong,
-    pub stripState: [::std::os::raw::c_ulong; 8usize],
-    pub busState: [::std::os::raw::c_ulong; 8usize],
-    pub stripGaindB100Layer1: [::std::os::raw::c_short; 8usize],
-    pub stripGaindB100Layer2: [::std::os::raw::c_short; 8usize],
-    pub stripGaindB100Layer3: [::std::os::raw::c_short; 8usize],
-    pub stripGaindB100Layer4: [::std::os::raw::c_short; 8usize],
-    pub stripGaindB100Layer5: [::std::os::raw::c_short; 8usize],
-    pub stripGaindB100Layer6: [::std::os::raw::c_short; 8usize],
-    pub stripGaindB100Layer7: [::std::os::raw::c_short; 8usize],
-    pub stripGaindB100Layer8: [::std::os::raw::c_short; 8usize],
-    pub busGaindB100: [::std::os::raw::c_short; 8usize],
-    pub stripLabelUTF8c60: [[::std::os::raw::c_char; 60usize]; 8usize],
-    pub busLabelUTF8c60: [[::std::os::raw::c_char; 60usize]; 8usize],
+    pub voicemeeterType: crate::ctypes::c_uchar,
+    pub reserved: crate::ctypes::c_uchar,
+    pub buffersize: crate::ctypes::c_ushort,
+    pub voicemeeterVersion: crate::ctypes::c_ulong,
+    pub optionBits: crate::ctypes::c_ulong,
+    pub samplerate: crate::ctypes::c_ulong,
+    pub inputLeveldB100: [crate::ctypes::c_short; 34usize],
+    pub outputLeveldB100: [crate::ctypes::c_short; 64usize],
+    pub TransportBit: crate::ctypes::c_ulong,
+    pub stripState: [crate::ctypes::c_ulong; 8usize],
+    pub busState: [crate::ctypes::c_ulong; 8usize],
+    pub stripGaindB100Layer1: [crate::ctypes::c_short; 8usize],
+    pub stripGaindB100Layer2: [crate::ctypes::c_short; 8usize],
+    pub stripGaindB100Layer3: [crate::ctypes::c_short; 8usize],
+    pub stripGaindB100Layer4: [crate::ctypes::c_short; 8usize],
+    pub stripGaindB100Layer5: [crate::ctypes::c_short; 8usize],
+    pub stripGaindB100Layer6: [crate::ctypes::c_short; 8usize],
+    pub stripGaindB100Layer7: [crate::ctypes::c_short; 8usize],
+    pub stripGaindB100Layer8: [crate::ctypes::c_short; 8usize],
+    pub busGaindB100: [crate::ctypes::c_short; 8usize],
+    pub stripLabelUTF8c60: [[crate::ctypes::c_char; 60usize]; 8usize],
+    pub busLabelUTF8c60: [[crate::ctypes::c_char; 60usize]; 8usize],
 }
 #[test]
 fn bindgen_test_layout_tagVBAN_VMRT_PACKET() {
@@ -1103,104 +1103,104 @@ impl VMRTSTATE_MODE {
 }
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub struct VMRTSTATE_MODE(pub ::std::os::raw::c_int);
+pub struct VMRTSTATE_MODE(pub crate::ctypes::c_int);
 extern crate libloading;
 pub struct VoicemeeterRemoteRaw {
     __library: ::libloading::Library,
-    pub VBVMR_Login: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_Logout: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
+    pub VBVMR_Login: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_Logout: unsafe extern "system" fn() -> crate::ctypes::c_long,
     pub VBVMR_RunVoicemeeter:
-        unsafe extern "stdcall" fn(vType: ::std::os::raw::c_long) -> ::std::os::raw::c_long,
+        unsafe extern "system" fn(vType: crate::ctypes::c_long) -> crate::ctypes::c_long,
     pub VBVMR_GetVoicemeeterType:
-        unsafe extern "stdcall" fn(pType: *mut ::std::os::raw::c_long) -> ::std::os::raw::c_long,
+        unsafe extern "system" fn(pType: *mut crate::ctypes::c_long) -> crate::ctypes::c_long,
     pub VBVMR_GetVoicemeeterVersion:
-        unsafe extern "stdcall" fn(pVersion: *mut ::std::os::raw::c_long) -> ::std::os::raw::c_long,
-    pub VBVMR_IsParametersDirty: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_GetParameterFloat: unsafe extern "stdcall" fn(
-        szParamName: *mut ::std::os::raw::c_char,
+        unsafe extern "system" fn(pVersion: *mut crate::ctypes::c_long) -> crate::ctypes::c_long,
+    pub VBVMR_IsParametersDirty: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_GetParameterFloat: unsafe extern "system" fn(
+        szParamName: *mut crate::ctypes::c_char,
         pValue: *mut f32,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_GetParameterStringA: unsafe extern "stdcall" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        szString: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_GetParameterStringW: unsafe extern "stdcall" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        wszString: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_GetLevel: unsafe extern "stdcall" fn(
-        nType: ::std::os::raw::c_long,
-        nuChannel: ::std::os::raw::c_long,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_GetParameterStringA: unsafe extern "system" fn(
+        szParamName: *mut crate::ctypes::c_char,
+        szString: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_GetParameterStringW: unsafe extern "system" fn(
+        szParamName: *mut crate::ctypes::c_char,
+        wszString: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_GetLevel: unsafe extern "system" fn(
+        nType: crate::ctypes::c_long,
+        nuChannel: crate::ctypes::c_long,
         pValue: *mut f32,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_GetMidiMessage: unsafe extern "stdcall" fn(
-        pMIDIBuffer: *mut ::std::os::raw::c_uchar,
-        nbByteMax: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_SetParameterFloat: unsafe extern "stdcall" fn(
-        szParamName: *mut ::std::os::raw::c_char,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_GetMidiMessage: unsafe extern "system" fn(
+        pMIDIBuffer: *mut crate::ctypes::c_uchar,
+        nbByteMax: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_SetParameterFloat: unsafe extern "system" fn(
+        szParamName: *mut crate::ctypes::c_char,
         Value: f32,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_SetParameterStringA: unsafe extern "stdcall" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        szString: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_SetParameterStringW: unsafe extern "stdcall" fn(
-        szParamName: *mut ::std::os::raw::c_char,
-        wszString: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_SetParameters: unsafe extern "stdcall" fn(
-        szParamScript: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_SetParametersW: unsafe extern "stdcall" fn(
-        szParamScript: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_Output_GetDeviceNumber: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_Output_GetDeviceDescA: unsafe extern "stdcall" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        szDeviceName: *mut ::std::os::raw::c_char,
-        szHardwareId: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_Output_GetDeviceDescW: unsafe extern "stdcall" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        wszDeviceName: *mut ::std::os::raw::c_ushort,
-        wszHardwareId: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_Input_GetDeviceNumber: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_Input_GetDeviceDescA: unsafe extern "stdcall" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        szDeviceName: *mut ::std::os::raw::c_char,
-        szHardwareId: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_Input_GetDeviceDescW: unsafe extern "stdcall" fn(
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        wszDeviceName: *mut ::std::os::raw::c_ushort,
-        wszHardwareId: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_AudioCallbackRegister: unsafe extern "stdcall" fn(
-        mode: ::std::os::raw::c_long,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_SetParameterStringA: unsafe extern "system" fn(
+        szParamName: *mut crate::ctypes::c_char,
+        szString: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_SetParameterStringW: unsafe extern "system" fn(
+        szParamName: *mut crate::ctypes::c_char,
+        wszString: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_SetParameters: unsafe extern "system" fn(
+        szParamScript: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_SetParametersW: unsafe extern "system" fn(
+        szParamScript: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_Output_GetDeviceNumber: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_Output_GetDeviceDescA: unsafe extern "system" fn(
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        szDeviceName: *mut crate::ctypes::c_char,
+        szHardwareId: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_Output_GetDeviceDescW: unsafe extern "system" fn(
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        wszDeviceName: *mut crate::ctypes::c_ushort,
+        wszHardwareId: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_Input_GetDeviceNumber: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_Input_GetDeviceDescA: unsafe extern "system" fn(
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        szDeviceName: *mut crate::ctypes::c_char,
+        szHardwareId: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_Input_GetDeviceDescW: unsafe extern "system" fn(
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        wszDeviceName: *mut crate::ctypes::c_ushort,
+        wszHardwareId: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_AudioCallbackRegister: unsafe extern "system" fn(
+        mode: crate::ctypes::c_long,
         pCallback: T_VBVMR_VBAUDIOCALLBACK,
-        lpUser: *mut ::std::os::raw::c_void,
-        szClientName: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_AudioCallbackStart: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_AudioCallbackStop: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_AudioCallbackUnregister: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_MacroButton_IsDirty: unsafe extern "stdcall" fn() -> ::std::os::raw::c_long,
-    pub VBVMR_MacroButton_GetStatus: unsafe extern "stdcall" fn(
-        nuLogicalButton: ::std::os::raw::c_long,
+        lpUser: *mut crate::ctypes::c_void,
+        szClientName: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_AudioCallbackStart: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_AudioCallbackStop: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_AudioCallbackUnregister: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_MacroButton_IsDirty: unsafe extern "system" fn() -> crate::ctypes::c_long,
+    pub VBVMR_MacroButton_GetStatus: unsafe extern "system" fn(
+        nuLogicalButton: crate::ctypes::c_long,
         pValue: *mut f32,
-        bitmode: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long,
-    pub VBVMR_MacroButton_SetStatus: unsafe extern "stdcall" fn(
-        nuLogicalButton: ::std::os::raw::c_long,
+        bitmode: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long,
+    pub VBVMR_MacroButton_SetStatus: unsafe extern "system" fn(
+        nuLogicalButton: crate::ctypes::c_long,
         fValue: f32,
-        bitmode: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long,
+        bitmode: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long,
 }
 impl VoicemeeterRemoteRaw {
     pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
@@ -1322,201 +1322,201 @@ impl VoicemeeterRemoteRaw {
         })
     }
     #[doc = "@brief Open Communication Pipe With Voicemeeter (typically called on software startup).\n@return :\t 0: OK (no error).\n1: OK but Voicemeeter Application not launched.\n-1: cannot get client (unexpected)\n-2: unexpected login (logout was expected before)."]
-    pub unsafe fn VBVMR_Login(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_Login(&self) -> crate::ctypes::c_long {
         (self.VBVMR_Login)()
     }
     #[doc = "@brief Close Communication Pipe With Voicemeeter (typically called on software end).\n@return : 0 if ok."]
-    pub unsafe fn VBVMR_Logout(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_Logout(&self) -> crate::ctypes::c_long {
         (self.VBVMR_Logout)()
     }
     #[doc = "@brief Run Voicemeeter Application (get installation directory and run Voicemeeter Application).\n@param vType : Voicemeeter type  (1 = Voicemeeter, 2= Voicemeeter Banana, 3= Voicemeeter Potato, 6 = Potato x64 bits).\n@return :\t 0: Ok.\n-1: not installed (UninstallString not found in registry).\n-2: unknown vType number"]
     pub unsafe fn VBVMR_RunVoicemeeter(
         &self,
-        vType: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long {
+        vType: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_RunVoicemeeter)(vType)
     }
     #[doc = "@brief Get Voicemeeter Type\n@param pType : Pointer on 32bit long receiving the type (1 = Voicemeeter, 2= Voicemeeter Banana, 3 Potato).\n\nVOICEMEETER STRIP/BUS INDEX ASSIGNMENT\n\n| Strip 1 | Strip 2 |Virtual Input|  BUS A  |  BUS B  |\n+---------+---------+-------------+---------+---------+\n|    0    |    1    |      2      |    0    |    1    |\n\nVOICEMEETER BANANA STRIP/BUS INDEX ASSIGNMENT\n\n| Strip 1 | Strip 2 | Strip 2 |Virtual Input|Virtual AUX|BUS A1|BUS A2|BUS A3|BUS B1|BUS B2|\n+---------+---------+---------+-------------+-----------+------+------+------+------+------+\n|    0    |    1    |    2    |       3     |     4     |   0  |   1  |   2  |   3  |   4  |\n\nVOICEMEETER POTATO STRIP/BUS INDEX ASSIGNMENT\n\n| Strip 1 | Strip 2 | Strip 2 | Strip 2 | Strip 2 |Virtual Input|Virtual AUX|   VAIO3   |BUS A1|BUS A2|BUS A3|BUS A4|BUS A5|BUS B1|BUS B2|BUS B3|\n+---------+---------+---------+---------+---------+-------------+-----------+-----------+------+------+------+------+------+------+------+------+\n|    0    |    1    |    2    |    3    |    4    |      5      |     6     |     7     |   0  |   1  |   2  |   3  |   4  |   5  |   6  |   7  |\n\n\n@return :\t 0: OK (no error).\n-1: cannot get client (unexpected)\n-2: no server."]
     pub unsafe fn VBVMR_GetVoicemeeterType(
         &self,
-        pType: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long {
+        pType: *mut crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_GetVoicemeeterType)(pType)
     }
     #[doc = "@brief Get Voicemeeter Version\n@param pType : Pointer on 32bit integer receiving the version (v1.v2.v3.v4)\nv1 = (version & 0xFF000000)>>24;\nv2 = (version & 0x00FF0000)>>16;\nv3 = (version & 0x0000FF00)>>8;\nv4 = version & 0x000000FF;\n\n@return :\t 0: OK (no error).\n-1: cannot get client (unexpected)\n-2: no server."]
     pub unsafe fn VBVMR_GetVoicemeeterVersion(
         &self,
-        pVersion: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long {
+        pVersion: *mut crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_GetVoicemeeterVersion)(pVersion)
     }
     #[doc = "@brief  Check if parameters have changed.\nCall this function periodically (typically every 10 or 20ms).\n(this function must be called from one thread only)\n\n@return:\t 0: no new paramters.\n1: New parameters -> update your display.\n-1: error (unexpected)\n-2: no server."]
-    pub unsafe fn VBVMR_IsParametersDirty(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_IsParametersDirty(&self) -> crate::ctypes::c_long {
         (self.VBVMR_IsParametersDirty)()
     }
     #[doc = "@brief get parameter value.\n@param szParamName : Null Terminal ASCII String giving the name of the parameter (see parameters name table)\n@param pValue : Pointer on float (32bit float by reference) receiving the wanted value.\n@return :\t 0: OK (no error).\n-1: error\n-2: no server.\n-3: unknown parameter\n-5: structure mismatch"]
     pub unsafe fn VBVMR_GetParameterFloat(
         &self,
-        szParamName: *mut ::std::os::raw::c_char,
+        szParamName: *mut crate::ctypes::c_char,
         pValue: *mut f32,
-    ) -> ::std::os::raw::c_long {
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_GetParameterFloat)(szParamName, pValue)
     }
     #[doc = "@brief get parameter value.\n@param szParamName : Null Terminal ASCII String giving the name of the parameter (see parameters name table)\n@param pValue : Pointer on String (512 char or wchar) receiving the wanted value.\n@return :\t 0: OK (no error).\n-1: error\n-2: no server.\n-3: unknown parameter\n-5: structure mismatch"]
     pub unsafe fn VBVMR_GetParameterStringA(
         &self,
-        szParamName: *mut ::std::os::raw::c_char,
-        szString: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long {
+        szParamName: *mut crate::ctypes::c_char,
+        szString: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_GetParameterStringA)(szParamName, szString)
     }
     pub unsafe fn VBVMR_GetParameterStringW(
         &self,
-        szParamName: *mut ::std::os::raw::c_char,
-        wszString: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long {
+        szParamName: *mut crate::ctypes::c_char,
+        wszString: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_GetParameterStringW)(szParamName, wszString)
     }
     #[doc = "@brief Get Current levels.\n(this function must be called from one thread only)\n\n@param nType:\t0= pre fader input levels.\n1= post fader input levels.\n2= post Mute input levels.\n3= output levels.\n\n@param nuChannel: audio channel zero based index\nfor input 0 = in#1 left, 1= in#1 Right, etc...\nfor output 0 = busA ch1, 1 = busA ch2...\n\nVOICEMEETER CHANNEL ASSIGNMENT\n\n| Strip 1 | Strip 2 |             Virtual Input             |\n+----+----+----+----+----+----+----+----+----+----+----+----+\n| 00 | 01 | 02 | 03 | 04 | 05 | 06 | 07 | 08 | 09 | 10 | 11 |\n\n|             Output A1 / A2            |             Virtual Output            |\n+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+\n| 00 | 01 | 02 | 03 | 04 | 05 | 06 | 07 | 08 | 09 | 10 | 11 | 12 | 13 | 14 | 15 |\n\nVOICEMEETER BANANA CHANNEL ASSIGNMENT\n\n| Strip 1 | Strip 2 | Strip 3 |             Virtual Input             |            Virtual Input AUX          |\n+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+\n| 00 | 01 | 02 | 03 | 04 | 05 | 06 | 07 | 08 | 09 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 |\n\n|             Output A1                 |                Output A2              |                Output A3              |\n+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+\n| 00 | 01 | 02 | 03 | 04 | 05 | 06 | 07 | 08 | 09 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 |\n\n|            Virtual Output B1          |             Virtual Output B2         |\n+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+\n| 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 |\n\nVOICEMEETER POTATO CHANNEL ASSIGNMENT\n\n| Strip 1 | Strip 2 | Strip 3 | Strip 4 | Strip 5 |             Virtual Input             |            Virtual Input AUX          |                 VAIO3                 |\n+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+\n| 00 | 01 | 02 | 03 | 04 | 05 | 06 | 07 | 08 | 09 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 25 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 |\n\n|             Output A1                 |                Output A2              |                Output A3              |                Output A4              |                Output A5              |\n+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+\n| 00 | 01 | 02 | 03 | 04 | 05 | 06 | 07 | 08 | 09 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 |\n\n|            Virtual Output B1          |             Virtual Output B2         |             Virtual Output B3         |\n+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+----+\n| 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 | 60 | 61 | 62 | 63 |\n\n\n@return :\t 0: OK (no error).\n-1: error\n-2: no server.\n-3: no level available\n-4: out of range"]
     pub unsafe fn VBVMR_GetLevel(
         &self,
-        nType: ::std::os::raw::c_long,
-        nuChannel: ::std::os::raw::c_long,
+        nType: crate::ctypes::c_long,
+        nuChannel: crate::ctypes::c_long,
         pValue: *mut f32,
-    ) -> ::std::os::raw::c_long {
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_GetLevel)(nType, nuChannel, pValue)
     }
     #[doc = "@brief Get MIDI message from M.I.D.I. input device used by Voicemeeter M.I.D.I. mapping.\n(this function must be called from one thread only)\n\n@param pMIDIBuffer:\tpointer on MIDI Buffer. Expected message size is below 4 bytes,\nbut it's recommended to use 1024 Bytes local buffer to receive\npossible multiple M.I.D.I. event message in optimal way:\nunsigned char pBuffer[1024];\n\n\n@return :\t>0: number of bytes placed in buffer (2 or 3 byte for usual M.I.D.I. message)\n-1: error\n-2: no server.\n-5: no MIDI data\n-6: no MIDI data"]
     pub unsafe fn VBVMR_GetMidiMessage(
         &self,
-        pMIDIBuffer: *mut ::std::os::raw::c_uchar,
-        nbByteMax: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long {
+        pMIDIBuffer: *mut crate::ctypes::c_uchar,
+        nbByteMax: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_GetMidiMessage)(pMIDIBuffer, nbByteMax)
     }
     #[doc = "@brief Set a single float 32 bits parameters .\n@param szParamName : Null Terminal ASCII String giving the name of the parameter (see parameters name table)\nexample:\nStrip[1].gain\nStrip[0].mute\nBus[0].gain\nBus[0].eq.channel[0].cell[0].gain\n\n@param pValue : float 32bit containing the new value.\n@return :\t 0: OK (no error).\n-1: error\n-2: no server.\n-3: unknown parameter"]
     pub unsafe fn VBVMR_SetParameterFloat(
         &self,
-        szParamName: *mut ::std::os::raw::c_char,
+        szParamName: *mut crate::ctypes::c_char,
         Value: f32,
-    ) -> ::std::os::raw::c_long {
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_SetParameterFloat)(szParamName, Value)
     }
     #[doc = "@brief Set a single string parameters .\n@param szParamName : Null Terminal ASCII String giving the name of the parameter (see parameters name table)\nexample:\nStrip[1].name\nStrip[0].device.mme\nBus[0].device.asio\n\n@param szString : zero terminal string.\n@return :\t 0: OK (no error).\n-1: error\n-2: no server.\n-3: unknown parameter\n"]
     pub unsafe fn VBVMR_SetParameterStringA(
         &self,
-        szParamName: *mut ::std::os::raw::c_char,
-        szString: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long {
+        szParamName: *mut crate::ctypes::c_char,
+        szString: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_SetParameterStringA)(szParamName, szString)
     }
     pub unsafe fn VBVMR_SetParameterStringW(
         &self,
-        szParamName: *mut ::std::os::raw::c_char,
-        wszString: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long {
+        szParamName: *mut crate::ctypes::c_char,
+        wszString: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_SetParameterStringW)(szParamName, wszString)
     }
     #[doc = "@brief Set one or several parameters by a script ( < 48 kB ).\n@param szParamName : Null Terminal ASCII String giving the script\n(script allows to change several parameters in the same time - SYNCHRO).\nPossible Instuction separators: ',' ';' or '\\n'(CR)\nEXAMPLE:\n\"Strip[0].gain = -6.0\nStrip[0].A1 = 0\nStrip[0].B1 = 1\nStrip[1].gain = -6.0\nStrip[2].gain = 0.0\nStrip[3].name = \"Skype Caller\" \"\n\n@return :\t 0: OK (no error).\n>0: number of line causing script error.\n-1: error\n-2: no server.\n-3: unexpected error\n-4: unexpected error"]
     pub unsafe fn VBVMR_SetParameters(
         &self,
-        szParamScript: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long {
+        szParamScript: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_SetParameters)(szParamScript)
     }
     pub unsafe fn VBVMR_SetParametersW(
         &self,
-        szParamScript: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long {
+        szParamScript: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_SetParametersW)(szParamScript)
     }
     #[doc = "@brief Get number of Audio Output Device available on the system\n@return : return number of device found."]
-    pub unsafe fn VBVMR_Output_GetDeviceNumber(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_Output_GetDeviceNumber(&self) -> crate::ctypes::c_long {
         (self.VBVMR_Output_GetDeviceNumber)()
     }
     #[doc = "@brief Return pointer on Output Device Descriptor according index\n@param zindex : zero based index\n@param nType : Pointer on 32bit long receiving the type (pointer can be NULL).\n@param szName : Pointer on string (256 char min) receiving the device name (pointer can be NULL).\n@param szHardwareId : Pointer on string (256 char min) receiving the hardware ID (pointer can be NULL).\n@return :\t 0: OK (no error)."]
     pub unsafe fn VBVMR_Output_GetDeviceDescA(
         &self,
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        szDeviceName: *mut ::std::os::raw::c_char,
-        szHardwareId: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long {
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        szDeviceName: *mut crate::ctypes::c_char,
+        szHardwareId: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_Output_GetDeviceDescA)(zindex, nType, szDeviceName, szHardwareId)
     }
     pub unsafe fn VBVMR_Output_GetDeviceDescW(
         &self,
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        wszDeviceName: *mut ::std::os::raw::c_ushort,
-        wszHardwareId: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long {
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        wszDeviceName: *mut crate::ctypes::c_ushort,
+        wszHardwareId: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_Output_GetDeviceDescW)(zindex, nType, wszDeviceName, wszHardwareId)
     }
     #[doc = "@brief Get number of Audio Input Device available on the system\n@return : return number of device found."]
-    pub unsafe fn VBVMR_Input_GetDeviceNumber(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_Input_GetDeviceNumber(&self) -> crate::ctypes::c_long {
         (self.VBVMR_Input_GetDeviceNumber)()
     }
     #[doc = "@brief Return pointer on Input Device Descriptor according index\n@param zindex : zero based index\n@param nType : Pointer on 32bit long receiving the type (pointer can be NULL).\n@param szName : Pointer on string (256 char min) receiving the device name (pointer can be NULL).\n@param szHardwareId : Pointer on string (256 char min) receiving the hardware ID (pointer can be NULL).\n@return :\t 0: OK (no error)."]
     pub unsafe fn VBVMR_Input_GetDeviceDescA(
         &self,
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        szDeviceName: *mut ::std::os::raw::c_char,
-        szHardwareId: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long {
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        szDeviceName: *mut crate::ctypes::c_char,
+        szHardwareId: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_Input_GetDeviceDescA)(zindex, nType, szDeviceName, szHardwareId)
     }
     pub unsafe fn VBVMR_Input_GetDeviceDescW(
         &self,
-        zindex: ::std::os::raw::c_long,
-        nType: *mut ::std::os::raw::c_long,
-        wszDeviceName: *mut ::std::os::raw::c_ushort,
-        wszHardwareId: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_long {
+        zindex: crate::ctypes::c_long,
+        nType: *mut crate::ctypes::c_long,
+        wszDeviceName: *mut crate::ctypes::c_ushort,
+        wszHardwareId: *mut crate::ctypes::c_ushort,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_Input_GetDeviceDescW)(zindex, nType, wszDeviceName, wszHardwareId)
     }
     #[doc = "@brief register your audio callback function to receive real time audio buffer\nit's possible to register up to 3x different Audio Callback in the same application or in\n3x different applications. In the same application, this is possible because Voicemeeter\nprovides 3 kind of audio Streams:\n- AUDIO INPUT INSERT (to process all Voicemeeter inputs as insert)\n- AUDIO OUTPUT INSERT (to process all Voicemeeter BUS outputs as insert)\n- ALL AUDIO I/O (to process all Voicemeeter i/o).\nNote: a single callback can be used to receive the 3 possible audio streams.\n\n@param mode : callback type (main, input or bus output) see define below\n@param pCallback : Pointer on your callback function.\n@param lpUser : user pointer (pointer that will be passed in callback first argument).\n@param szClientName[64]: IN: Name of the application registering the Callback.\nOUT: Name of the application already registered.\n@return :\t 0: OK (no error).\n-1: error\n1: callback already registered (by another application)."]
     pub unsafe fn VBVMR_AudioCallbackRegister(
         &self,
-        mode: ::std::os::raw::c_long,
+        mode: crate::ctypes::c_long,
         pCallback: T_VBVMR_VBAUDIOCALLBACK,
-        lpUser: *mut ::std::os::raw::c_void,
-        szClientName: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_long {
+        lpUser: *mut crate::ctypes::c_void,
+        szClientName: *mut crate::ctypes::c_char,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_AudioCallbackRegister)(mode, pCallback, lpUser, szClientName)
     }
     #[doc = "@brief\tStart / Stop Audio processing\n\nhe Callback will be called with\n@return :\t 0: OK (no error).\n-1: error\n-2: no callback registred."]
-    pub unsafe fn VBVMR_AudioCallbackStart(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_AudioCallbackStart(&self) -> crate::ctypes::c_long {
         (self.VBVMR_AudioCallbackStart)()
     }
-    pub unsafe fn VBVMR_AudioCallbackStop(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_AudioCallbackStop(&self) -> crate::ctypes::c_long {
         (self.VBVMR_AudioCallbackStop)()
     }
     #[doc = "@brief unregister your callback to release voicemeeter virtual driver\n(this function will automatically call VBVMR_AudioCallbackStop() function)\n@param pCallback : Pointer on your callback function.\n@return :\t 0: OK (no error).\n-1: error\n1: callback already unregistered."]
-    pub unsafe fn VBVMR_AudioCallbackUnregister(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_AudioCallbackUnregister(&self) -> crate::ctypes::c_long {
         (self.VBVMR_AudioCallbackUnregister)()
     }
     #[doc = "@brief  Check if Macro Buttons states changed.\nCall this function periodically (typically every 50 or 500ms) to know if something happen on MacroButton states .\n(this function must be called from one thread only)\n\n@return:\t 0: no new status.\n>0: last nu logical button status changed.\n-1: error (unexpected)\n-2: no server."]
-    pub unsafe fn VBVMR_MacroButton_IsDirty(&self) -> ::std::os::raw::c_long {
+    pub unsafe fn VBVMR_MacroButton_IsDirty(&self) -> crate::ctypes::c_long {
         (self.VBVMR_MacroButton_IsDirty)()
     }
     #[doc = "@brief get current status of a given button.\n@param nuLogicalButton : button index: 0 to 79)\n@param pValue : Pointer on float (32bit float by reference) receiving the wanted value (0.0 = OFF / 1.0 = ON).\n@param bitmode: define what kind of value you want to read (see MACROBUTTON_MODE below)\n@return :\t 0: OK (no error).\n-1: error\n-2: no server.\n-3: unknown parameter\n-5: structure mismatch"]
     pub unsafe fn VBVMR_MacroButton_GetStatus(
         &self,
-        nuLogicalButton: ::std::os::raw::c_long,
+        nuLogicalButton: crate::ctypes::c_long,
         pValue: *mut f32,
-        bitmode: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long {
+        bitmode: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_MacroButton_GetStatus)(nuLogicalButton, pValue, bitmode)
     }
     #[doc = "@brief set current button value.\n@param nuLogicalButton : button index: 0 to 79)\n@param fValue : float 32 bit value giving the status (0.0 = OFF / 1.0 = ON).\n@param bitmode: define what kind of value you want to write/modify (see MACROBUTTON_MODE below)\n@return :\t 0: OK (no error).\n-1: error\n-2: no server.\n-3: unknown parameter\n-5: structure mismatch"]
     pub unsafe fn VBVMR_MacroButton_SetStatus(
         &self,
-        nuLogicalButton: ::std::os::raw::c_long,
+        nuLogicalButton: crate::ctypes::c_long,
         fValue: f32,
-        bitmode: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long {
+        bitmode: crate::ctypes::c_long,
+    ) -> crate::ctypes::c_long {
         (self.VBVMR_MacroButton_SetStatus)(nuLogicalButton, fValue, bitmode)
     }
 }
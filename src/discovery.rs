@@ -0,0 +1,345 @@
+//! Discovery of the Voicemeeter remote DLL.
+//!
+//! The DLL is searched for in these locations, in order:
+//!
+//! * The Voicemeeter uninstall key in the 32-bit and 64-bit views of the registry.
+//! * The default installation folder in Program Files.
+//! * The directory of the running executable.
+//! * The directories in `PATH`.
+//!
+//! On 64-bit targets `VoicemeeterRemote64.dll` is used, on 32-bit targets `VoicemeeterRemote.dll`.
+//!
+//! ```rust,no_run
+//! for candidate in voicemeeter::discovery::Discovery::new().candidates() {
+//!     println!("{candidate}");
+//! }
+//! ```
+use std::path::{Path, PathBuf};
+
+use crate::RegistryError;
+
+/// Name of the remote DLL for the current target.
+#[cfg(target_pointer_width = "64")]
+pub static LIBRARY_NAME: &str = "VoicemeeterRemote64.dll";
+/// Name of the remote DLL for the current target.
+#[cfg(not(target_pointer_width = "64"))]
+pub static LIBRARY_NAME: &str = "VoicemeeterRemote.dll";
+
+/// Installation folder of Voicemeeter relative to Program Files.
+static INSTALL_DIR: &str = "VB\\Voicemeeter";
+
+/// View of the registry to read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistryView {
+    /// The 32-bit view, `WOW6432Node` on 64-bit Windows.
+    Bits32,
+    /// The 64-bit view.
+    Bits64,
+}
+
+/// Access to the Voicemeeter installation information in the registry.
+pub trait Registry {
+    /// Get the `UninstallString` of the Voicemeeter uninstall key.
+    fn uninstall_string(&self, view: RegistryView) -> Result<String, RegistryError>;
+}
+
+/// The Windows registry.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRegistry;
+
+#[cfg(windows)]
+impl Registry for SystemRegistry {
+    fn uninstall_string(&self, view: RegistryView) -> Result<String, RegistryError> {
+        use winreg::enums::{HKEY_LOCAL_MACHINE, KEY_READ, KEY_WOW64_32KEY, KEY_WOW64_64KEY};
+
+        static INSTALLER_UNINST_KEY: &str = "VB:Voicemeeter {17359A74-1236-5467}";
+        static UNINSTALLER_DIR: &str = "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall";
+
+        let flags = match view {
+            RegistryView::Bits32 => KEY_READ | KEY_WOW64_32KEY,
+            RegistryView::Bits64 => KEY_READ | KEY_WOW64_64KEY,
+        };
+        winreg::RegKey::predef(HKEY_LOCAL_MACHINE)
+            .open_subkey_with_flags(UNINSTALLER_DIR, flags)
+            .map_err(RegistryError::CouldNotFindUninstallReg)?
+            .open_subkey_with_flags(INSTALLER_UNINST_KEY, flags)
+            .map_err(RegistryError::CouldNotFindVM)?
+            .get_value("UninstallString")
+            .map_err(|_| RegistryError::CouldNotFindUninstallString)
+    }
+}
+
+#[cfg(not(windows))]
+impl Registry for SystemRegistry {
+    fn uninstall_string(&self, _: RegistryView) -> Result<String, RegistryError> {
+        Err(RegistryError::Unsupported)
+    }
+}
+
+/// Where a [`Candidate`] was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Source {
+    /// Next to the uninstaller in the registry.
+    Registry(RegistryView),
+    /// In the default installation folder.
+    ProgramFiles,
+    /// In the directory of the running executable.
+    ExeDir,
+    /// In a directory in `PATH`.
+    Path,
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Registry(RegistryView::Bits32) => f.write_str("registry (32-bit)"),
+            Source::Registry(RegistryView::Bits64) => f.write_str("registry (64-bit)"),
+            Source::ProgramFiles => f.write_str("program files"),
+            Source::ExeDir => f.write_str("executable directory"),
+            Source::Path => f.write_str("PATH"),
+        }
+    }
+}
+
+/// A possible location of the remote DLL.
+#[derive(Debug)]
+pub struct Candidate {
+    /// Where the candidate came from.
+    pub source: Source,
+    /// The path of the DLL, or why no DLL could be found.
+    pub result: Result<PathBuf, DiscoveryError>,
+}
+
+impl Candidate {
+    /// The path of the DLL, if it exists.
+    pub fn path(&self) -> Option<&Path> {
+        self.result.as_deref().ok()
+    }
+}
+
+impl std::fmt::Display for Candidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.result {
+            Ok(path) => write!(f, "{}: found {}", self.source, path.display()),
+            Err(e) => write!(f, "{}: {e}", self.source),
+        }
+    }
+}
+
+/// Reason a [`Candidate`] did not yield a DLL.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum DiscoveryError {
+    /// The registry could not be read.
+    #[error(transparent)]
+    RegistryError(#[from] RegistryError),
+    /// The DLL does not exist at this path.
+    #[error("{} does not exist", .0.display())]
+    NotFound(PathBuf),
+}
+
+/// Search for the remote DLL.
+#[derive(Debug, Clone)]
+pub struct Discovery<R = SystemRegistry> {
+    registry: R,
+    library_name: String,
+    program_files: Vec<PathBuf>,
+    exe_dir: Option<PathBuf>,
+    path: Vec<PathBuf>,
+}
+
+impl Discovery<SystemRegistry> {
+    /// Search using the system registry and environment.
+    pub fn new() -> Self {
+        Self::with_registry(SystemRegistry)
+    }
+}
+
+impl Default for Discovery<SystemRegistry> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Registry> Discovery<R> {
+    /// Search using a custom [`Registry`] and the system environment.
+    pub fn with_registry(registry: R) -> Self {
+        let mut program_files: Vec<PathBuf> = ["ProgramFiles(x86)", "ProgramFiles", "ProgramW6432"]
+            .into_iter()
+            .filter_map(std::env::var_os)
+            .map(PathBuf::from)
+            .collect();
+        if program_files.is_empty() && cfg!(windows) {
+            program_files = vec!["C:\\Program Files (x86)".into(), "C:\\Program Files".into()];
+        }
+        Self {
+            registry,
+            library_name: LIBRARY_NAME.to_owned(),
+            program_files,
+            exe_dir: std::env::current_exe()
+                .ok()
+                .and_then(|p| p.parent().map(Path::to_owned)),
+            path: std::env::var_os("PATH")
+                .map(|p| std::env::split_paths(&p).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Set the file name of the DLL to search for, defaults to [`LIBRARY_NAME`].
+    pub fn library_name(mut self, name: impl Into<String>) -> Self {
+        self.library_name = name.into();
+        self
+    }
+
+    /// Set the Program Files folders to search in.
+    pub fn program_files(mut self, dirs: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.program_files = dirs.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the directory of the running executable.
+    pub fn exe_dir(mut self, dir: Option<impl Into<PathBuf>>) -> Self {
+        self.exe_dir = dir.map(Into::into);
+        self
+    }
+
+    /// Set the directories in `PATH`.
+    pub fn path(mut self, dirs: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.path = dirs.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn registry_candidate(&self, view: RegistryView) -> Result<PathBuf, DiscoveryError> {
+        let uninstall = self.registry.uninstall_string(view)?;
+        // the uninstall string may be quoted
+        let uninstall = uninstall.trim().trim_matches('"');
+        let dir = windows_parent(uninstall)
+            .ok_or_else(|| RegistryError::UninstallStringInvalid(uninstall.to_owned()))?;
+        Ok(dir.join(&self.library_name))
+    }
+
+    /// Get all candidates for the DLL.
+    ///
+    /// Candidates where the DLL exists come first, in search order.
+    pub fn candidates(&self) -> Vec<Candidate> {
+        let mut candidates: Vec<Candidate> = vec![];
+        let mut push = |source, result: Result<PathBuf, DiscoveryError>| {
+            let result = result.and_then(|path| {
+                if path.is_file() {
+                    Ok(path)
+                } else {
+                    Err(DiscoveryError::NotFound(path))
+                }
+            });
+            // the same location can be found through multiple sources
+            if let Ok(path) | Err(DiscoveryError::NotFound(path)) = &result {
+                if candidates.iter().any(|c| match &c.result {
+                    Ok(p) | Err(DiscoveryError::NotFound(p)) => p == path,
+                    _ => false,
+                }) {
+                    return;
+                }
+            }
+            candidates.push(Candidate { source, result });
+        };
+        for view in [RegistryView::Bits32, RegistryView::Bits64] {
+            push(Source::Registry(view), self.registry_candidate(view));
+        }
+        for dir in &self.program_files {
+            push(
+                Source::ProgramFiles,
+                Ok(dir.join(INSTALL_DIR).join(&self.library_name)),
+            );
+        }
+        if let Some(dir) = &self.exe_dir {
+            push(Source::ExeDir, Ok(dir.join(&self.library_name)));
+        }
+        for dir in &self.path {
+            push(Source::Path, Ok(dir.join(&self.library_name)));
+        }
+        // stable, so search order is kept
+        candidates.sort_by_key(|c| c.result.is_err());
+        candidates
+    }
+
+    /// Get the path of the first DLL found.
+    pub fn find(&self) -> Option<PathBuf> {
+        self.candidates().into_iter().find_map(|c| c.result.ok())
+    }
+}
+
+/// Get the parent of a windows path, also on other platforms.
+fn windows_parent(path: &str) -> Option<PathBuf> {
+    let (parent, _) = path.rsplit_once(['\\', '/'])?;
+    (!parent.is_empty()).then(|| PathBuf::from(parent))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    struct FakeRegistry(HashMap<RegistryView, String>);
+
+    impl Registry for FakeRegistry {
+        fn uninstall_string(&self, view: RegistryView) -> Result<String, RegistryError> {
+            self.0
+                .get(&view)
+                .cloned()
+                .ok_or(RegistryError::CouldNotFindUninstallString)
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "voicemeeter-discovery-{}-{name}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn search_order() {
+        let exe_dir = temp_dir("exe");
+        let path_dir = temp_dir("path");
+        std::fs::write(exe_dir.join(LIBRARY_NAME), []).unwrap();
+        std::fs::write(path_dir.join(LIBRARY_NAME), []).unwrap();
+
+        let registry = FakeRegistry(HashMap::from([(
+            RegistryView::Bits32,
+            format!("\"{}\"", path_dir.join("setup.exe").display()),
+        )]));
+        let candidates = Discovery::with_registry(registry)
+            .program_files(["C:\\Program Files (x86)"])
+            .exe_dir(Some(&exe_dir))
+            .path([&path_dir])
+            .candidates();
+        let sources: Vec<_> = candidates.iter().map(|c| c.source).collect();
+        assert_eq!(
+            sources,
+            [
+                Source::Registry(RegistryView::Bits32),
+                Source::ExeDir,
+                Source::Registry(RegistryView::Bits64),
+                Source::ProgramFiles,
+            ]
+        );
+        assert_eq!(candidates[0].path(), Some(&*path_dir.join(LIBRARY_NAME)));
+        assert!(matches!(
+            candidates[2].result,
+            Err(DiscoveryError::RegistryError(
+                RegistryError::CouldNotFindUninstallString
+            ))
+        ));
+        assert!(matches!(
+            candidates[3].result,
+            Err(DiscoveryError::NotFound(_))
+        ));
+
+        std::fs::remove_dir_all(exe_dir).unwrap();
+        std::fs::remove_dir_all(path_dir).unwrap();
+    }
+}
@@ -1,37 +1,96 @@
 //! The interface for Voicemeeter remote.
 //!
 //! See the methods on [`VoicemeeterRemote`] for how to use the interface
-use crate::{types::VoicemeeterApplication, LoadError};
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    thread::ThreadId,
+};
+
+use crate::{backend::VoicemeeterBackend, types::VoicemeeterApplication, LoadError};
 
 use self::{
-    communication_login_logout::LoginError, general_information::GetVoicemeeterInformationError,
+    callback::register::AudioCallbackRegistration,
+    communication_login_logout::{LoginError, VoicemeeterStatus},
+    general_information::GetVoicemeeterInformationError,
 };
 
 pub mod callback;
 pub mod communication_login_logout;
 pub mod device;
+#[cfg(feature = "async")]
+pub mod events;
 pub mod general_information;
 pub mod get_levels;
 pub mod macro_buttons;
 pub mod parameters;
+pub mod supervisor;
 
 /// Interface for voicemeeter.
-#[derive(Clone)]
 #[cfg(feature = "interface")] // for doc_cfg
 pub struct VoicemeeterRemote {
-    raw: &'static crate::bindings::VoicemeeterRemoteRaw,
-    logout_handle: Option<std::sync::Arc<bool>>,
-    /// The type of the running Voicemeeter instance.
+    backend: Arc<dyn VoicemeeterBackend>,
+    login_state: Arc<LoginState>,
+    /// `true` if this remote is counted in the login session.
+    in_session: bool,
+    /// The type of the running Voicemeeter instance, when this remote last [updated](VoicemeeterRemote::update_program) it.
+    ///
+    /// This does not see updates made by other clones of this remote, e.g. by a [supervisor](VoicemeeterRemote::supervisor).
+    #[deprecated(note = "use `VoicemeeterRemote::program` to see updates made by other clones")]
     pub program: VoicemeeterApplication,
 }
 
-pub(crate) static LOGOUT_HANDLE: std::sync::OnceLock<
-    std::sync::Mutex<Option<std::sync::Arc<bool>>>,
-> = std::sync::OnceLock::new();
+/// Login bookkeeping shared by all [`VoicemeeterRemote`]s using the same backend.
+#[derive(Default)]
+pub(crate) struct LoginState {
+    pub(crate) session: Mutex<Session>,
+    /// Status of the first login.
+    pub(crate) initial_status: OnceLock<VoicemeeterStatus>,
+    /// The last known program, used by audio callbacks.
+    program: AtomicI32,
+    /// The registered audio callback, kept to register it again after Voicemeeter restarts.
+    pub(crate) audio_callback: Mutex<Option<AudioCallbackRegistration>>,
+    /// The thread of the running [`ParameterWatcher`](parameters::watcher::ParameterWatcher), the only thread allowed to poll the dirty flag.
+    pub(crate) parameter_poller: Mutex<Option<ThreadId>>,
+    /// The thread polling for events with the `async` feature, the only thread allowed to poll the macro button dirty flag.
+    pub(crate) macro_button_poller: Mutex<Option<ThreadId>>,
+    /// The last values set for write-only parameters.
+    pub(crate) written: parameters::written::WrittenValues,
+}
+
+impl LoginState {
+    pub(crate) fn program(&self) -> VoicemeeterApplication {
+        match self.program.load(Ordering::Relaxed) {
+            255 => VoicemeeterApplication::None,
+            p => VoicemeeterApplication::from(p),
+        }
+    }
+
+    fn set_program(&self, program: VoicemeeterApplication) {
+        if self.program.swap(program as i32, Ordering::Relaxed) != program as i32 {
+            self.written.clear();
+        }
+    }
+}
+
+/// The current login session.
+///
+/// The backend is logged in while `status` is `Some`, and logged out when the last remote leaves the session.
+#[derive(Default)]
+pub(crate) struct Session {
+    pub(crate) remotes: usize,
+    pub(crate) status: Option<VoicemeeterStatus>,
+}
+
+/// Login state for the Voicemeeter remote DLL.
+pub(crate) static DLL_LOGIN_STATE: OnceLock<Arc<LoginState>> = OnceLock::new();
 
 impl std::fmt::Debug for VoicemeeterRemote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.program {
+        match self.program() {
             VoicemeeterApplication::None => write!(f, "[no voicemeeter program running]"),
             p => write!(f, "[{}]", p),
         }
@@ -44,51 +103,143 @@ impl VoicemeeterRemote {
     /// # Notes
     ///
     /// If you create [`VoicemeeterRemote`] instances, when the last instance remaining is dropped,
-    /// you will automatically be logged out of the API, meaning you cannot login again.
+    /// you will automatically be logged out of the API. Creating a new instance after that logs in again.
     ///
     /// This is done to prevent leaving the API logged in when your program is closed,
     /// causing a visual bug in the voicemeeter application.
+    ///
+    /// The remote DLL is located using the [`VOICEMEETER_REMOTE_DLL_ENV`](crate::VOICEMEETER_REMOTE_DLL_ENV) environment variable
+    /// or the registry, use [`VoicemeeterRemote::builder`] to give an explicit path.
     #[tracing::instrument]
     pub fn new() -> Result<Self, InitializationError> {
-        let raw = crate::get_voicemeeter_raw()?;
-        let mut s = VoicemeeterRemote::from_raw(raw);
-        if s.logout_handle.is_none() {
-            return Err(InitializationError::AlreadyLoggedOut);
-        }
-        match s.login() {
-            Ok(_) => {}
-            Err(LoginError::AlreadyLoggedIn(_)) => {}
-            e => {
-                e?;
-            }
-        };
-        s.update_program()?;
-        Ok(s)
+        Self::builder().build()
     }
 
-    fn from_raw(raw: &'static crate::VoicemeeterRemoteRaw) -> VoicemeeterRemote {
-        Self {
-            raw,
+    /// Creates a builder for a [`VoicemeeterRemote`] instance using the remote DLL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use voicemeeter::VoicemeeterRemote;
+    ///
+    /// let remote = VoicemeeterRemote::builder()
+    ///     .dll_path(r"D:\Voicemeeter\VoicemeeterRemote64.dll")
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> VoicemeeterRemoteBuilder {
+        VoicemeeterRemoteBuilder::default()
+    }
+
+    /// Creates a new [`VoicemeeterRemote`] instance using a custom [backend](crate::backend) that is logged in with the client.
+    ///
+    /// The same rules for logging out as in [`VoicemeeterRemote::new`] apply, but only to remotes created from clones of this remote.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "simulator")] {
+    /// use std::sync::Arc;
+    /// use voicemeeter::{
+    ///     backend::simulator::SimulatedVoicemeeter, types::VoicemeeterApplication, VoicemeeterRemote,
+    /// };
+    ///
+    /// let simulator = Arc::new(SimulatedVoicemeeter::new(
+    ///     VoicemeeterApplication::VoicemeeterBanana,
+    /// ));
+    /// let remote = VoicemeeterRemote::with_backend(simulator)?;
+    /// assert_eq!(remote.program(), VoicemeeterApplication::VoicemeeterBanana);
+    /// # }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[tracing::instrument(skip(backend))]
+    pub fn with_backend(backend: Arc<dyn VoicemeeterBackend>) -> Result<Self, InitializationError> {
+        Self::with_login_state(backend, Arc::default())
+    }
+
+    fn with_login_state(
+        backend: Arc<dyn VoicemeeterBackend>,
+        login_state: Arc<LoginState>,
+    ) -> Result<Self, InitializationError> {
+        #[allow(deprecated)]
+        let mut s = Self {
+            backend,
+            login_state,
+            in_session: false,
             program: VoicemeeterApplication::Other,
-            logout_handle: LOGOUT_HANDLE
-                .get_or_init(|| std::sync::Mutex::new(Some(std::sync::Arc::new(false))))
-                .lock()
-                .unwrap()
-                .clone(),
-        }
+        };
+        s.login()?;
+        s.update_program()?;
+        Ok(s)
     }
 
     /// Update the current program type.
     pub fn update_program(&mut self) -> Result<(), GetVoicemeeterInformationError> {
         match self.get_voicemeeter_type() {
-            Ok(t) => self.program = t,
+            Ok(t) => self.set_program(t),
             Err(GetVoicemeeterInformationError::NoServer) => {
-                self.program = VoicemeeterApplication::None
+                self.set_program(VoicemeeterApplication::None)
             }
             Err(e) => return Err(e),
         }
         Ok(())
     }
+
+    /// The type of the running Voicemeeter instance.
+    ///
+    /// This is shared by all clones of this remote, so a [supervisor](VoicemeeterRemote::supervisor) keeps it up to date.
+    /// Parameters are checked against this program.
+    pub fn program(&self) -> VoicemeeterApplication {
+        self.login_state.program()
+    }
+
+    fn set_program(&mut self, program: VoicemeeterApplication) {
+        #[allow(deprecated)]
+        {
+            self.program = program;
+        }
+        self.login_state.set_program(program);
+    }
+}
+
+/// Builder for [`VoicemeeterRemote`], created with [`VoicemeeterRemote::builder`].
+#[derive(Debug, Default, Clone)]
+pub struct VoicemeeterRemoteBuilder {
+    dll_path: Option<PathBuf>,
+}
+
+impl VoicemeeterRemoteBuilder {
+    /// Load the remote DLL from this path.
+    ///
+    /// Only this path is tried, [`build`](VoicemeeterRemoteBuilder::build) returns [`LoadError::NotFound`] if it can not be loaded.
+    /// The DLL can only be loaded once per process, if another DLL is already loaded [`LoadError::OtherLibraryLoaded`] is returned.
+    pub fn dll_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.dll_path = Some(path.into());
+        self
+    }
+
+    /// Load the remote DLL and create a [`VoicemeeterRemote`] instance that is logged in with the client.
+    #[tracing::instrument]
+    pub fn build(self) -> Result<VoicemeeterRemote, InitializationError> {
+        let raw = crate::load_voicemeeter_raw(self.dll_path.as_deref())?;
+        let login_state = DLL_LOGIN_STATE.get_or_init(Arc::default);
+        VoicemeeterRemote::with_login_state(Arc::new(raw), login_state.clone())
+    }
+}
+
+impl Clone for VoicemeeterRemote {
+    fn clone(&self) -> Self {
+        if self.in_session {
+            self.login_state.session.lock().unwrap().remotes += 1;
+        }
+        #[allow(deprecated)]
+        Self {
+            backend: self.backend.clone(),
+            login_state: self.login_state.clone(),
+            in_session: self.in_session,
+            program: self.program,
+        }
+    }
 }
 
 impl Drop for VoicemeeterRemote {
@@ -109,6 +260,8 @@ pub enum InitializationError {
     #[error("could not login")]
     LoginError(#[from] LoginError),
     /// Application has already logged out.
+    ///
+    /// No longer returned, a new login session is started instead.
     #[error("application has already logged out, so cannot login again")]
     AlreadyLoggedOut,
     /// Error when getting the Voicemeeter application type.
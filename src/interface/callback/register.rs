@@ -1,15 +1,20 @@
 //! Functions for callbacks in Voicemeeter.
 use std::{
-    ffi::{CString, NulError},
-    os::raw::c_long,
-    ptr,
+    ffi::{CStr, CString, NulError},
+    os::raw::{c_char, c_void},
+    sync::Arc,
 };
 
 use crate::{
-    bindings::VBVMR_CBCOMMAND, interface::callback::data::RawCallbackData, CallbackCommand,
-    VoicemeeterRemote,
+    bindings::{T_VBVMR_VBAUDIOCALLBACK, VBVMR_CBCOMMAND},
+    ctypes::c_long,
+    interface::{callback::data::RawCallbackData, LoginState},
+    types::VoicemeeterApplication,
+    CallbackCommand, VoicemeeterRemote,
 };
 
+use super::start_stop::AudioCallbackStartError;
+
 /***************************************************************************** */
 /* VB-AUDIO CALLBACK */
 /***************************************************************************** */
@@ -21,21 +26,43 @@ use crate::{
 /* VBVMR_AudioCallbackUnregister :to unregister / Release callback(s) */
 /***************************************************************************** */
 
+/// A registered audio callback.
+pub(crate) struct AudioCallbackRegistration {
+    mode: i32,
+    client_name: [c_char; 64],
+    callback: T_VBVMR_VBAUDIOCALLBACK,
+    user_data: *mut c_void,
+    pub(crate) started: bool,
+}
+
+// SAFETY: `user_data` is only passed back to the backend together with `callback`, never dereferenced.
+unsafe impl Send for AudioCallbackRegistration {}
+
+/// Data passed to [`call_closure`].
+struct CallbackData<F> {
+    login_state: Arc<LoginState>,
+    callback: F,
+}
+
 // Thanks to sgrif, http://blog.sagetheprogrammer.com/neat-rust-tricks-passing-rust-closures-to-c
 fn register_audio_callback<'cb, F>(
     remote: &VoicemeeterRemote,
     mode: &crate::AudioCallbackMode,
-    application: *mut std::os::raw::c_char,
+    application: &mut [c_char; 64],
     callback: F,
-) -> Result<*mut F, AudioCallbackRegisterError>
+) -> Result<*mut CallbackData<F>, AudioCallbackRegisterError>
 where
-    F: FnMut(CallbackCommand<'cb>, i32) -> c_long,
+    F: FnMut(CallbackCommand<'cb>, i32) -> i32,
 {
     // This leaks
-    let data = Box::into_raw(Box::new(callback));
+    let data = Box::into_raw(Box::new(CallbackData {
+        login_state: remote.login_state.clone(),
+        callback,
+    }));
     tracing::debug!("callback {:p}", data);
+    let client_name = *application;
     let res = unsafe {
-        remote.raw.VBVMR_AudioCallbackRegister(
+        remote.backend.audio_callback_register(
             mode.0,
             Some(call_closure::<F>),
             data as *mut _,
@@ -44,11 +71,20 @@ where
     };
     tracing::debug!("registered application");
     match res {
-        0 => Ok(data),
+        0 => {
+            *remote.login_state.audio_callback.lock().unwrap() = Some(AudioCallbackRegistration {
+                mode: mode.0,
+                client_name,
+                callback: Some(call_closure::<F>),
+                user_data: data as *mut _,
+                started: false,
+            });
+            Ok(data)
+        }
         -1 => Err(AudioCallbackRegisterError::NoServer),
-        1 => Err(AudioCallbackRegisterError::AlreadyRegistered(unsafe {
-            CString::from_raw(application)
-        })),
+        1 => Err(AudioCallbackRegisterError::AlreadyRegistered(
+            unsafe { CStr::from_ptr(application.as_ptr()) }.to_owned(),
+        )),
         s => Err(AudioCallbackRegisterError::Unexpected(s)),
     }
 }
@@ -60,27 +96,27 @@ unsafe extern "C" fn call_closure<'cb, F>(
     nnn: c_long,
 ) -> c_long
 where
-    F: FnMut(CallbackCommand<'cb>, i32) -> c_long,
+    F: FnMut(CallbackCommand<'cb>, i32) -> i32,
 {
-    let callback_ptr = user_data as *mut F;
-    let callback = unsafe { &mut *callback_ptr };
+    let data_ptr = user_data as *mut CallbackData<F>;
+    let data = unsafe { &mut *data_ptr };
+    let program = match data.login_state.program() {
+        VoicemeeterApplication::Other | VoicemeeterApplication::None => {
+            VoicemeeterApplication::PotatoX64Bits
+        }
+        program => program,
+    };
     let ptr = RawCallbackData::from_ptr(buffer);
-    callback(
-        unsafe {
-            CallbackCommand::new_unchecked(
-                crate::types::VoicemeeterApplication::PotatoX64Bits,
-                VBVMR_CBCOMMAND(command),
-                ptr,
-            )
-        },
-        nnn,
+    (data.callback)(
+        unsafe { CallbackCommand::new_unchecked(program, VBVMR_CBCOMMAND(command as _), ptr) },
+        nnn as _,
     )
 }
 
 /// Guard type for the callback. If this is dropped the callback data will be leaked and newer dropped.
 #[must_use = "This structure contains the raw pointer to the closure environment, if this is not returned you will leak memory"]
 pub struct CallbackGuard<'a, F> {
-    guard: *mut F,
+    guard: *mut CallbackData<F>,
     lt: std::marker::PhantomData<&'a ()>,
 }
 
@@ -98,7 +134,7 @@ impl VoicemeeterRemote {
     /// ```rust,no_run
     #[doc = include_str!("../../../examples/simple.rs")]
     /// ```
-    /// 
+    ///
     /// ## Complete example
     /// ```rust,no_run
     #[doc = include_str!("../../../examples/output.rs")]
@@ -112,24 +148,22 @@ impl VoicemeeterRemote {
         callback: F,
     ) -> Result<CallbackGuard<'g, F>, AudioCallbackRegisterError>
     where
-        F: FnMut(CallbackCommand<'cb>, i32) -> c_long + 'g,
+        F: FnMut(CallbackCommand<'cb>, i32) -> i32 + 'g,
     {
         let application_name = application_name.as_ref();
         tracing::Span::current().record("application_name", application_name);
         //let ctx_span = tracing::trace_span!("voicemeeter_callback");
         //ctx_span.record("application_name", &application_name).record("mode", &mode.0).follows_from(tracing::Span::current());
         assert!(application_name.len() < 64);
-        let mut application = [b'\0'; 64];
-        application[0..application_name.len()].copy_from_slice(application_name.as_bytes());
-        let ptr = ptr::addr_of!(self.program);
+        let mut application = [0 as c_char; 64];
+        for (a, b) in application.iter_mut().zip(application_name.as_bytes()) {
+            *a = *b as c_char;
+        }
+        #[allow(deprecated)]
+        let ptr = std::ptr::addr_of!(self.program);
         tracing::info!("a: {ptr:p}");
 
-        let g = register_audio_callback(
-            self,
-            &mode,
-            ptr::addr_of_mut!(application) as *mut _,
-            callback,
-        )?;
+        let g = register_audio_callback(self, &mode, &mut application, callback)?;
 
         Ok(CallbackGuard {
             guard: g,
@@ -142,9 +176,10 @@ impl VoicemeeterRemote {
         &self,
         guard: CallbackGuard<'_, F>,
     ) -> Result<(), AudioCallbackUnregisterError> {
-        let res = unsafe { self.raw.VBVMR_AudioCallbackUnregister() };
+        let res = self.backend.audio_callback_unregister();
         match res {
             0 => {
+                self.login_state.audio_callback.lock().unwrap().take();
                 let _ = unsafe { Box::from_raw(guard.guard) };
                 Ok(())
             }
@@ -156,14 +191,56 @@ impl VoicemeeterRemote {
 
     /// Unregister a callback without dropping the callback, thus leaking data. This implicitly calls [`VoicemeeterRemote::audio_callback_stop`].
     pub fn audio_callback_unregister_leak<F>(&self) -> Result<(), AudioCallbackUnregisterError> {
-        let res = unsafe { self.raw.VBVMR_AudioCallbackUnregister() };
+        let res = self.backend.audio_callback_unregister();
         match res {
-            0 => Ok(()),
+            0 => {
+                self.login_state.audio_callback.lock().unwrap().take();
+                Ok(())
+            }
             -1 => Err(AudioCallbackUnregisterError::NoServer),
             1 => Err(AudioCallbackUnregisterError::AlreadyUnregistered),
             s => Err(AudioCallbackUnregisterError::Unexpected(s)),
         }
     }
+
+    /// Register the audio callback again, and start it if it was started.
+    ///
+    /// Returns `false` if no audio callback is registered.
+    pub(crate) fn reregister_audio_callback(&self) -> Result<bool, AudioCallbackReregisterError> {
+        let mut registration = self.login_state.audio_callback.lock().unwrap();
+        let Some(registration) = registration.as_mut() else {
+            return Ok(false);
+        };
+        let mut client_name = registration.client_name;
+        let res = unsafe {
+            self.backend.audio_callback_register(
+                registration.mode,
+                registration.callback,
+                registration.user_data,
+                &mut client_name,
+            )
+        };
+        match res {
+            0 => {}
+            -1 => return Err(AudioCallbackRegisterError::NoServer.into()),
+            1 => {
+                return Err(AudioCallbackRegisterError::AlreadyRegistered(
+                    unsafe { CStr::from_ptr(client_name.as_ptr()) }.to_owned(),
+                )
+                .into())
+            }
+            s => return Err(AudioCallbackRegisterError::Unexpected(s).into()),
+        }
+        if registration.started {
+            match self.backend.audio_callback_start() {
+                0 => {}
+                -1 => return Err(AudioCallbackStartError::NoServer.into()),
+                1 => return Err(AudioCallbackStartError::NoCallbackRegistered.into()),
+                s => return Err(AudioCallbackStartError::Unexpected(s).into()),
+            }
+        }
+        Ok(true)
+    }
 }
 
 /// Errors that can occur while registering an audio callback.
@@ -185,6 +262,18 @@ pub enum AudioCallbackRegisterError {
     Unexpected(i32),
 }
 
+/// Errors that can occur while registering the audio callback again after Voicemeeter restarted.
+#[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
+pub enum AudioCallbackReregisterError {
+    /// Could not register the callback.
+    #[error("could not register the audio callback")]
+    Register(#[from] AudioCallbackRegisterError),
+    /// Could not start the callback.
+    #[error("could not start the audio callback")]
+    Start(#[from] AudioCallbackStartError),
+}
+
 /// Errors that can occur while unregistering the audio callback.
 #[derive(Debug, Clone, thiserror::Error)]
 #[non_exhaustive]
@@ -4,9 +4,12 @@ use crate::VoicemeeterRemote;
 impl VoicemeeterRemote {
     /// Audio callback start.
     pub fn audio_callback_start(&self) -> Result<(), AudioCallbackStartError> {
-        let res = unsafe { self.raw.VBVMR_AudioCallbackStart() };
+        let res = self.backend.audio_callback_start();
         match res {
-            0 => Ok(()),
+            0 => {
+                self.set_audio_callback_started(true);
+                Ok(())
+            }
             -1 => Err(AudioCallbackStartError::NoServer),
             1 => Err(AudioCallbackStartError::NoCallbackRegistered),
             s => Err(AudioCallbackStartError::Unexpected(s)),
@@ -15,14 +18,23 @@ impl VoicemeeterRemote {
 
     /// Audio callback stop.
     pub fn audio_callback_stop(&self) -> Result<(), AudioCallbackStopError> {
-        let res = unsafe { self.raw.VBVMR_AudioCallbackStop() };
+        let res = self.backend.audio_callback_stop();
         match res {
-            0 => Ok(()),
+            0 => {
+                self.set_audio_callback_started(false);
+                Ok(())
+            }
             -1 => Err(AudioCallbackStopError::NoServer),
             1 => Err(AudioCallbackStopError::NoCallbackRegistered),
             s => Err(AudioCallbackStopError::Unexpected(s)),
         }
     }
+
+    fn set_audio_callback_started(&self, started: bool) {
+        if let Some(registration) = self.login_state.audio_callback.lock().unwrap().as_mut() {
+            registration.started = started;
+        }
+    }
 }
 
 /// Errors that can occur while registering the audio callback.
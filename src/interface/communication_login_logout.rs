@@ -5,22 +5,43 @@
 //! * `login` is implicitly called when creating your first [`VoicemeeterRemote`] instance.
 //! * [`logout`](VoicemeeterRemote::logout)
 //! * [`run_voicemeeter`](VoicemeeterRemote::run_voicemeeter)
+//! * [`launch_and_wait`](VoicemeeterRemote::launch_and_wait)
+use std::time::{Duration, Instant};
+
 use crate::types::VoicemeeterApplication;
 
-use super::VoicemeeterRemote;
+use super::{
+    general_information::GetVoicemeeterInformationError,
+    parameters::get_parameters::GetParameterError, VoicemeeterRemote,
+};
 
-static HAS_LOGGED_IN: std::sync::OnceLock<VoicemeeterStatus> = std::sync::OnceLock::new();
+/// Interval between checks in [`VoicemeeterRemote::launch_and_wait`].
+const LAUNCH_POLL_INTERVAL: Duration = Duration::from_millis(50);
 
 impl VoicemeeterRemote {
     /// Get the status of the running Voicemeeter instance when we first logged in
     pub fn initial_status() -> VoicemeeterStatus {
-        HAS_LOGGED_IN.get().unwrap().clone()
+        super::DLL_LOGIN_STATE
+            .get()
+            .and_then(|s| s.initial_status.get())
+            .unwrap()
+            .clone()
     }
+
+    /// Join the login session, logging in if there is no session.
     pub(crate) fn login(&mut self) -> Result<VoicemeeterStatus, LoginError> {
-        if let Some(res) = HAS_LOGGED_IN.get() {
-            return Err(LoginError::AlreadyLoggedIn(res.clone()));
+        let mut session = self.login_state.session.lock().unwrap();
+        if self.in_session {
+            return Err(LoginError::AlreadyLoggedIn(
+                session.status.clone().expect("session should be logged in"),
+            ));
         }
-        let res = unsafe { self.raw.VBVMR_Login() };
+        if let Some(status) = session.status.clone() {
+            session.remotes += 1;
+            self.in_session = true;
+            return Ok(status);
+        }
+        let res = self.backend.login();
         let res = match res {
             0 => Ok(VoicemeeterStatus::Launched),
             1 => Ok(VoicemeeterStatus::NotLaunched),
@@ -28,55 +49,40 @@ impl VoicemeeterRemote {
             s => Err(LoginError::Unexpected(s)),
         }?;
         tracing::debug!("logged in with status {:?}", res);
-        Ok(HAS_LOGGED_IN.get_or_init(|| res).clone())
+        self.login_state.initial_status.get_or_init(|| res.clone());
+        session.status = Some(res.clone());
+        session.remotes = 1;
+        self.in_session = true;
+        Ok(res)
     }
-    /// Logout from the voicemeeter instance. This should only be called when you never need another VoiceMeeter remote again.
+
+    /// Logout from the voicemeeter instance.
     ///
     /// # Notes
     ///
-    /// [`VoicemeeterRemote::new`] will automatically login if needed.
-    pub fn logout(self) -> Result<(), LogoutError> {
-        drop(self);
-        if super::LOGOUT_HANDLE
-            .get()
-            .unwrap()
-            .lock()
-            .unwrap()
-            .is_some()
-        {
-            Err(LogoutError::OtherRemotesExists)
-        } else {
-            Ok(())
-        }
+    /// The logout only happens if this is the last [`VoicemeeterRemote`] in this program, otherwise [`LogoutError::OtherRemotesExists`] is returned.
+    /// [`VoicemeeterRemote::new`] will login again if needed.
+    pub fn logout(mut self) -> Result<(), LogoutError> {
+        self._logout()
     }
 
+    /// Leave the login session, logging out if this was the last remote in it.
     pub(crate) fn _logout(&mut self) -> Result<(), LogoutError> {
-        let _ = self.logout_handle.take();
-        // TODO: use Option::take_if ?
-        let Some(mut a) = super::LOGOUT_HANDLE.get().unwrap().lock().unwrap().take() else {
+        if !std::mem::take(&mut self.in_session) {
             return Ok(());
-        };
-        if let Some(logged_out) = std::sync::Arc::get_mut(&mut a) {
-            if *logged_out {
-                return Ok(());
-            }
-            tracing::debug!("logging out");
-            let res = unsafe { self.raw.VBVMR_Logout() };
-            match res {
-                0 => {
-                    *logged_out = true;
-                    Ok(())
-                }
-                s => Err(LogoutError::Unexpected(s)),
-            }
-        } else {
-            super::LOGOUT_HANDLE
-                .get()
-                .unwrap()
-                .lock()
-                .unwrap()
-                .replace(a);
-            Err(LogoutError::OtherRemotesExists)
+        }
+        let mut session = self.login_state.session.lock().unwrap();
+        session.remotes -= 1;
+        if session.remotes > 0 {
+            return Err(LogoutError::OtherRemotesExists);
+        }
+        // the session is over even if the logout fails, the next remote will try to login again
+        session.status = None;
+        tracing::debug!("logging out");
+        let res = self.backend.logout();
+        match res {
+            0 => Ok(()),
+            s => Err(LogoutError::Unexpected(s)),
         }
     }
 
@@ -85,7 +91,7 @@ impl VoicemeeterRemote {
         &self,
         r#type: VoicemeeterApplication,
     ) -> Result<(), RunVoicemeeterError> {
-        let res = unsafe { self.raw.VBVMR_RunVoicemeeter(r#type as i32) };
+        let res = self.backend.run_voicemeeter(r#type as i32);
         match res {
             0 => Ok(()),
             -1 => Err(RunVoicemeeterError::NotInstalled),
@@ -93,9 +99,72 @@ impl VoicemeeterRemote {
             s => Err(RunVoicemeeterError::Other(s)),
         }
     }
+
+    /// Invoke Voicemeeter like [`run_voicemeeter`](VoicemeeterRemote::run_voicemeeter) and wait until it is ready.
+    ///
+    /// Voicemeeter is ready when it reports the requested type and its parameters can be read.
+    /// The [program](VoicemeeterRemote::program) of this remote is updated when ready.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use voicemeeter::{types::VoicemeeterApplication, VoicemeeterRemote};
+    ///
+    /// let mut remote = VoicemeeterRemote::new()?;
+    /// remote.launch_and_wait(VoicemeeterApplication::VoicemeeterBanana, Duration::from_secs(10))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn launch_and_wait(
+        &mut self,
+        r#type: VoicemeeterApplication,
+        timeout: Duration,
+    ) -> Result<(), LaunchError> {
+        let deadline = Instant::now() + timeout;
+        self.run_voicemeeter(r#type)?;
+        loop {
+            let running = match self.get_voicemeeter_type() {
+                Ok(running) => running,
+                Err(GetVoicemeeterInformationError::NoServer) => VoicemeeterApplication::None,
+                Err(e) => return Err(e.into()),
+            };
+            if is_same_program(running, r#type) && self.parameters_readable()? {
+                self.set_program(running);
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(LaunchError::Timeout {
+                    program: r#type,
+                    running,
+                    timeout,
+                });
+            }
+            std::thread::sleep(LAUNCH_POLL_INTERVAL);
+        }
+    }
+
+    /// Only reads a parameter, polling the dirty flag would take changes from the user or a watcher.
+    fn parameters_readable(&self) -> Result<bool, LaunchError> {
+        match self.get_parameter_float("Strip[0].Mute".into()) {
+            Ok(_) => Ok(true),
+            Err(GetParameterError::NoServer) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Potato is reported as [`VoicemeeterApplication::VoicemeeterPotato`] even when launched as [`VoicemeeterApplication::PotatoX64Bits`].
+fn is_same_program(running: VoicemeeterApplication, requested: VoicemeeterApplication) -> bool {
+    use VoicemeeterApplication::{PotatoX64Bits, VoicemeeterPotato};
+    running == requested
+        || matches!(
+            (running, requested),
+            (VoicemeeterPotato, PotatoX64Bits) | (PotatoX64Bits, VoicemeeterPotato)
+        )
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The status of the Voicemeeter instance.
 pub enum VoicemeeterStatus {
     /// Voicemeeter is launched.
@@ -145,6 +214,31 @@ pub enum RunVoicemeeterError {
     Other(i32),
 }
 
+/// Errors that can happen when [launching](VoicemeeterRemote::launch_and_wait) voicemeeter and waiting for it.
+#[derive(Debug, thiserror::Error, Clone)]
+#[non_exhaustive]
+pub enum LaunchError {
+    /// Could not launch voicemeeter.
+    #[error("could not launch voicemeeter")]
+    RunVoicemeeterError(#[from] RunVoicemeeterError),
+    /// Voicemeeter was not ready in time.
+    #[error("{program} was not ready after {timeout:?}, running program is {running}")]
+    Timeout {
+        /// The program that was launched.
+        program: VoicemeeterApplication,
+        /// The program running when the timeout elapsed.
+        running: VoicemeeterApplication,
+        /// The timeout.
+        timeout: Duration,
+    },
+    /// Could not get the type of the running program.
+    #[error("could not get voicemeeter type")]
+    InformationError(#[from] GetVoicemeeterInformationError),
+    /// Could not read a parameter.
+    #[error("could not read parameters")]
+    GetParameterError(#[from] GetParameterError),
+}
+
 impl LoginError {
     /// Returns `true` if the login error is [`LoginFailed`].
     ///
@@ -160,3 +254,106 @@ impl LoginError {
         matches!(self, Self::Unexpected(..))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        backend::simulator::{remote, SimulatedVoicemeeter},
+        interface::LoginState,
+        types::VoicemeeterApplication,
+        VoicemeeterRemote,
+    };
+
+    use super::*;
+
+    fn simulator() -> Arc<SimulatedVoicemeeter> {
+        Arc::new(SimulatedVoicemeeter::new(
+            VoicemeeterApplication::VoicemeeterBanana,
+        ))
+    }
+
+    #[test]
+    fn relogin() -> Result<(), Box<dyn std::error::Error>> {
+        let simulator = simulator();
+        let state = Arc::<LoginState>::default();
+        let remote = VoicemeeterRemote::with_login_state(simulator.clone(), state.clone())?;
+        let clone = remote.clone();
+        assert!(matches!(
+            remote.logout(),
+            Err(LogoutError::OtherRemotesExists)
+        ));
+        assert!(simulator.is_logged_in());
+        clone.logout()?;
+        assert!(!simulator.is_logged_in());
+
+        let remote = VoicemeeterRemote::with_login_state(simulator.clone(), state.clone())?;
+        assert!(simulator.is_logged_in());
+        let other = VoicemeeterRemote::with_login_state(simulator.clone(), state.clone())?;
+        drop(remote);
+        assert!(simulator.is_logged_in());
+        drop(other);
+        assert!(!simulator.is_logged_in());
+        assert_eq!(
+            state.initial_status.get(),
+            Some(&VoicemeeterStatus::Launched)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn launch_and_wait() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, mut remote) = remote(VoicemeeterApplication::None);
+        simulator.set_startup_delay(Duration::from_secs(5));
+        assert!(matches!(
+            remote.launch_and_wait(
+                VoicemeeterApplication::VoicemeeterBanana,
+                Duration::from_millis(100)
+            ),
+            Err(LaunchError::Timeout {
+                running: VoicemeeterApplication::None,
+                ..
+            })
+        ));
+
+        simulator.set_startup_delay(Duration::from_millis(100));
+        remote.launch_and_wait(
+            VoicemeeterApplication::PotatoX64Bits,
+            Duration::from_secs(5),
+        )?;
+        assert_eq!(remote.program(), VoicemeeterApplication::PotatoX64Bits);
+        remote.parameters().strip(7)?.mute().get()?;
+
+        // waiting leaves the dirty flag alone
+        simulator.gui_set_float("Strip[0].Gain", -3.0)?;
+        remote.launch_and_wait(
+            VoicemeeterApplication::PotatoX64Bits,
+            Duration::from_secs(5),
+        )?;
+        assert!(remote.is_parameters_dirty()?);
+        Ok(())
+    }
+
+    #[test]
+    fn no_double_login() {
+        let simulator = simulator();
+        let state = Arc::<LoginState>::default();
+        std::thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    for _ in 0..100 {
+                        // the simulator fails a login while logged in
+                        let remote =
+                            VoicemeeterRemote::with_login_state(simulator.clone(), state.clone())
+                                .unwrap();
+                        assert!(simulator.is_logged_in());
+                        drop(remote.clone());
+                    }
+                });
+            }
+        });
+        assert!(!simulator.is_logged_in());
+        assert_eq!(state.session.lock().unwrap().remotes, 0);
+    }
+}
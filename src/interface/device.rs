@@ -15,7 +15,7 @@ use super::VoicemeeterRemote;
 impl VoicemeeterRemote {
     /// Get the number of Audio Input Devices available on the system.
     pub fn get_total_input_device(&self) -> Result<i32, GetTotalDeviceError> {
-        let res = unsafe { self.raw.VBVMR_Input_GetDeviceNumber() };
+        let res = self.backend.input_get_device_number();
         if res < 0 {
             Err(GetTotalDeviceError(res))
         } else {
@@ -24,7 +24,7 @@ impl VoicemeeterRemote {
     }
     /// Get the number of Audio Output Devices available on the system.
     pub fn get_total_output_device(&self) -> Result<i32, GetTotalDeviceError> {
-        let res = unsafe { self.raw.VBVMR_Output_GetDeviceNumber() };
+        let res = self.backend.output_get_device_number();
         if res < 0 {
             Err(GetTotalDeviceError(res))
         } else {
@@ -40,14 +40,12 @@ impl VoicemeeterRemote {
         let index = index.into().0;
         let mut name = [0 as c_char; 256];
         let mut hardware_id = [0 as c_char; 256];
-        unsafe {
-            self._get_input_device(
-                index,
-                Some(&mut r#type),
-                Some(&mut name),
-                Some(&mut hardware_id),
-            )?;
-        }
+        self._get_input_device(
+            index,
+            Some(&mut r#type),
+            Some(&mut name),
+            Some(&mut hardware_id),
+        )?;
         Ok(InputDevice {
             r#type: DeviceType::from(r#type),
             name: unsafe { CStr::from_ptr(name.as_ptr()) }
@@ -59,21 +57,16 @@ impl VoicemeeterRemote {
         })
     }
 
-    pub(crate) unsafe fn _get_input_device(
+    pub(crate) fn _get_input_device(
         &self,
         index: i32,
         r#type: Option<&mut i32>,
         name: Option<&mut [c_char; 256]>,
         hardware_id: Option<&mut [c_char; 256]>,
     ) -> Result<(), GetDeviceError> {
-        let type_p = crate::opt_or_null(r#type);
-        let name_p = crate::opt_or_null(name.map(|a| &mut a[0]));
-        let hardware_id_p = crate::opt_or_null(hardware_id.map(|a| &mut a[0]));
-
-        let res = unsafe {
-            self.raw
-                .VBVMR_Input_GetDeviceDescA(index, type_p, name_p, hardware_id_p)
-        };
+        let res = self
+            .backend
+            .input_get_device_desc_a(index, r#type, name, hardware_id);
         //cleanup
         match res {
             0 => Ok(()),
@@ -90,14 +83,12 @@ impl VoicemeeterRemote {
         let index = index.into().0;
         let mut name = [0 as c_char; 256];
         let mut hardware_id = [0 as c_char; 256];
-        unsafe {
-            self._get_output_device(
-                index,
-                Some(&mut r#type),
-                Some(&mut name),
-                Some(&mut hardware_id),
-            )?;
-        }
+        self._get_output_device(
+            index,
+            Some(&mut r#type),
+            Some(&mut name),
+            Some(&mut hardware_id),
+        )?;
         Ok(OutputDevice {
             r#type: DeviceType::from(r#type),
             name: unsafe { CStr::from_ptr(name.as_ptr()) }
@@ -109,20 +100,16 @@ impl VoicemeeterRemote {
         })
     }
 
-    pub(crate) unsafe fn _get_output_device(
+    pub(crate) fn _get_output_device(
         &self,
         index: i32,
         r#type: Option<&mut i32>,
         name: Option<&mut [c_char; 256]>,
         hardware_id: Option<&mut [c_char; 256]>,
     ) -> Result<(), GetDeviceError> {
-        let type_p = crate::opt_or_null(r#type);
-        let name_p = crate::opt_or_null(name.map(|a| &mut a[0]));
-        let hardware_id_p = crate::opt_or_null(hardware_id.map(|a| &mut a[0]));
-        let res = unsafe {
-            self.raw
-                .VBVMR_Output_GetDeviceDescA(index, type_p, name_p, hardware_id_p)
-        };
+        let res = self
+            .backend
+            .output_get_device_desc_a(index, r#type, name, hardware_id);
         //cleanup
         match res {
             0 => Ok(()),
@@ -133,8 +120,10 @@ impl VoicemeeterRemote {
 
 /// A Audio Input Device.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InputDevice {
     /// The type of the device.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     pub r#type: DeviceType,
     /// Device name
     pub name: String,
@@ -143,6 +132,8 @@ pub struct InputDevice {
 }
 
 /// Represents the type of an audio device.
+///
+/// With the `serde` feature, this is represented as `"MME"`, `"WDM"`, `"KS"` or `"ASIO"`, and other types as their number.
 #[repr(i32)]
 #[derive(Debug)]
 pub enum DeviceType {
@@ -171,10 +162,64 @@ impl From<i32> for DeviceType {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for DeviceType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            DeviceType::Mme => serializer.serialize_str("MME"),
+            DeviceType::Wdm => serializer.serialize_str("WDM"),
+            DeviceType::Ks => serializer.serialize_str("KS"),
+            DeviceType::Asio => serializer.serialize_str("ASIO"),
+            DeviceType::Other(t) => serializer.serialize_i32(t.0),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DeviceType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = DeviceType;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a device type name or number")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<DeviceType, E> {
+                match v {
+                    "MME" => Ok(DeviceType::Mme),
+                    "WDM" => Ok(DeviceType::Wdm),
+                    "KS" => Ok(DeviceType::Ks),
+                    "ASIO" => Ok(DeviceType::Asio),
+                    _ => Err(E::unknown_variant(v, &["MME", "WDM", "KS", "ASIO"])),
+                }
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<DeviceType, E> {
+                i32::try_from(v)
+                    .map(DeviceType::from)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<DeviceType, E> {
+                i32::try_from(v)
+                    .map(DeviceType::from)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
 /// A Audio Output Device.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OutputDevice {
     /// The type of the device.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     pub r#type: DeviceType,
     /// Device name
     pub name: String,
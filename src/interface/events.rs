@@ -0,0 +1,533 @@
+//! Async event streams, available with the `async` feature.
+//!
+//! [`Events`] polls Voicemeeter on one dedicated thread and fans out what it sees to [`Stream`]s,
+//! so the functions that must only be called from one thread are never called concurrently.
+//!
+//! * [`parameters`](Events::parameters) yields a [`ParameterChange`] for every watched parameter that changed.
+//! * [`macro_buttons`](Events::macro_buttons) yields a [`MacroButtonChange`] for every macro button that was pressed or released.
+//! * [`levels`](Events::levels) yields a [`LevelFrame`] with the levels of all channels on every poll.
+//!   Frames are dropped while the stream is not polled, so it never falls behind.
+//! * [`midi`](Events::midi) yields every MIDI message received.
+//!
+//! While [`Events`] is alive, its thread is the only one allowed to call [`is_parameters_dirty`](VoicemeeterRemote::is_parameters_dirty)
+//! and [`is_macrobutton_dirty`](VoicemeeterRemote::is_macrobutton_dirty).
+//! Streams end when the [`Events`] they were created from is dropped.
+//! Every stream buffers up to [`EVENT_BUFFER`] events, newer events are dropped while the buffer is full.
+//!
+//! # Functions
+//!
+//! * [`events`](VoicemeeterRemote::events)
+//! * [`events_every`](VoicemeeterRemote::events_every)
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use voicemeeter::VoicemeeterRemote;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let remote = VoicemeeterRemote::new()?;
+//! let events = remote.events()?;
+//! events.watch_strip(0)?;
+//!
+//! let mut parameters = events.parameters();
+//! let mut buttons = events.macro_buttons()?;
+//! loop {
+//!     futures::select! {
+//!         change = parameters.select_next_some() => {
+//!             println!("{}: {} -> {}", change.name, change.old, change.new)
+//!         }
+//!         change = buttons.select_next_some() => {
+//!             println!("{}: {}", change.button, change.pressed)
+//!         }
+//!     }
+//! }
+//! # }
+//! ```
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use futures_channel::mpsc::{self, Receiver, Sender};
+pub use futures_core::{FusedStream, Stream};
+
+use crate::types::{Device, LevelType, LogicalButton, ParameterName, ParameterNameRef};
+
+use super::{
+    get_levels::GetMidiMessageError,
+    macro_buttons::{GetMacroButtonStatusError, IsMacroButtonDirtyError},
+    parameters::{
+        command::MACRO_BUTTONS,
+        get_parameters::IsParametersDirtyError,
+        watcher::{WatchError, Watched, DEFAULT_WATCH_INTERVAL},
+        BusIndex, ParameterChange, ParameterError, ParameterValue, StripIndex,
+    },
+    VoicemeeterRemote,
+};
+
+/// Events buffered by a stream that is not polled, newer events are dropped.
+pub const EVENT_BUFFER: usize = 1024;
+
+impl VoicemeeterRemote {
+    /// Start polling for [`Events`], every [`DEFAULT_WATCH_INTERVAL`].
+    ///
+    /// Can not be used together with a [`ParameterWatcher`](super::parameters::ParameterWatcher).
+    pub fn events(&self) -> Result<Events, WatchError> {
+        self.events_every(DEFAULT_WATCH_INTERVAL)
+    }
+
+    /// Start polling for [`Events`], every `interval`.
+    ///
+    /// Can not be used together with a [`ParameterWatcher`](super::parameters::ParameterWatcher).
+    pub fn events_every(&self, interval: Duration) -> Result<Events, WatchError> {
+        let mut parameter_poller = self.login_state.parameter_poller.lock().unwrap();
+        let mut macro_button_poller = self.login_state.macro_button_poller.lock().unwrap();
+        if parameter_poller.is_some() || macro_button_poller.is_some() {
+            return Err(WatchError::AlreadyWatching);
+        }
+        let shared = Arc::new(Shared::default());
+        let thread = std::thread::Builder::new()
+            .name("voicemeeter-events".to_owned())
+            .spawn({
+                let remote = self.clone();
+                let shared = shared.clone();
+                move || poll(remote, shared, interval)
+            })
+            .map_err(WatchError::Spawn)?;
+        *parameter_poller = Some(thread.thread().id());
+        *macro_button_poller = Some(thread.thread().id());
+        Ok(Events {
+            remote: self.clone(),
+            shared,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// A macro button that was pressed or released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MacroButtonChange {
+    /// The button.
+    pub button: LogicalButton,
+    /// `true` if the button is now pressed.
+    pub pressed: bool,
+}
+
+/// The levels of all channels at one poll.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelFrame {
+    /// The type of the levels.
+    pub level_type: LevelType,
+    /// The levels of every channel, per device.
+    pub devices: Vec<(Device, Vec<f32>)>,
+}
+
+impl LevelFrame {
+    /// The levels of the channels of a device, if the device has levels of this type.
+    pub fn device(&self, device: Device) -> Option<&[f32]> {
+        self.devices
+            .iter()
+            .find(|(d, _)| *d == device)
+            .map(|(_, levels)| &levels[..])
+    }
+}
+
+/// A [`Stream`] of events from [`Events`].
+///
+/// The stream ends when the [`Events`] it was created from is dropped.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct EventStream<T> {
+    receiver: Receiver<T>,
+}
+
+impl<T> EventStream<T> {
+    /// A stream buffering `buffer` events, and one more for the sender.
+    fn new(buffer: usize) -> (Sender<T>, Self) {
+        let (sender, receiver) = mpsc::channel(buffer);
+        (sender, Self { receiver })
+    }
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.receiver.size_hint()
+    }
+}
+
+impl<T> FusedStream for EventStream<T> {
+    fn is_terminated(&self) -> bool {
+        self.receiver.is_terminated()
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    stop: AtomicBool,
+    watched: Watched,
+    /// Last known state of the macro buttons, read when the first subscriber is added.
+    macro_buttons: Mutex<Option<Vec<bool>>>,
+    parameter_subscribers: Mutex<Vec<Sender<ParameterChange>>>,
+    macro_button_subscribers: Mutex<Vec<Sender<MacroButtonChange>>>,
+    level_subscribers: Mutex<Vec<(LevelType, Sender<LevelFrame>)>>,
+    midi_subscribers: Mutex<Vec<Sender<Vec<u8>>>>,
+}
+
+/// Polls Voicemeeter on a background thread and sends events to [`EventStream`]s, see the [module documentation](self).
+///
+/// The thread is stopped when this is dropped.
+pub struct Events {
+    remote: VoicemeeterRemote,
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for Events {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Events")
+            .field("remote", &self.remote)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Events {
+    /// Watch a parameter, changes are sent to the [`parameters`](Events::parameters) streams.
+    ///
+    /// The parameter is read on the next poll, changes after that are reported.
+    /// Parameters that can not be read are not watched.
+    pub fn watch(&self, name: &ParameterNameRef) {
+        self.shared.watched.watch(name)
+    }
+
+    /// Watch the common parameters of a strip, like gain, mute, label and the bus assignments.
+    pub fn watch_strip(&self, index: impl StripIndex) -> Result<(), ParameterError> {
+        self.shared.watched.watch_strip(&self.remote, index)
+    }
+
+    /// Watch the common parameters of a bus, like gain, mute, label and the bus mode.
+    pub fn watch_bus(&self, index: impl BusIndex) -> Result<(), ParameterError> {
+        self.shared.watched.watch_bus(&self.remote, index)
+    }
+
+    /// Stop watching a parameter.
+    pub fn unwatch(&self, name: &ParameterNameRef) {
+        self.shared.watched.unwatch(name)
+    }
+
+    /// The last known value of a watched parameter.
+    pub fn value(&self, name: &ParameterNameRef) -> Option<ParameterValue> {
+        self.shared.watched.value(name)
+    }
+
+    /// The last known values of all watched parameters.
+    pub fn values(&self) -> HashMap<ParameterName, ParameterValue> {
+        self.shared.watched.values()
+    }
+
+    /// Stream changes of the [watched](Events::watch) parameters.
+    pub fn parameters(&self) -> EventStream<ParameterChange> {
+        let (sender, stream) = EventStream::new(EVENT_BUFFER - 1);
+        self.shared
+            .parameter_subscribers
+            .lock()
+            .unwrap()
+            .push(sender);
+        stream
+    }
+
+    /// Stream presses and releases of macro buttons.
+    ///
+    /// Reads the state of all macro buttons if this is the first stream, changes after that are reported.
+    pub fn macro_buttons(
+        &self,
+    ) -> Result<EventStream<MacroButtonChange>, GetMacroButtonStatusError> {
+        let mut buttons = self.shared.macro_buttons.lock().unwrap();
+        if buttons.is_none() {
+            *buttons = Some(read_macro_buttons(&self.remote)?);
+        }
+        let (sender, stream) = EventStream::new(EVENT_BUFFER - 1);
+        self.shared
+            .macro_button_subscribers
+            .lock()
+            .unwrap()
+            .push(sender);
+        Ok(stream)
+    }
+
+    /// Stream the levels of all channels, one frame per poll.
+    ///
+    /// Only one frame is buffered, frames are dropped while the stream is not polled.
+    pub fn levels(&self, level_type: LevelType) -> EventStream<LevelFrame> {
+        let (sender, stream) = EventStream::new(0);
+        self.shared
+            .level_subscribers
+            .lock()
+            .unwrap()
+            .push((level_type, sender));
+        stream
+    }
+
+    /// Stream received MIDI messages.
+    ///
+    /// MIDI messages are only read while there is a stream.
+    pub fn midi(&self) -> EventStream<Vec<u8>> {
+        let (sender, stream) = EventStream::new(EVENT_BUFFER - 1);
+        self.shared.midi_subscribers.lock().unwrap().push(sender);
+        stream
+    }
+}
+
+impl Drop for Events {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        let login_state = &self.remote.login_state;
+        login_state.parameter_poller.lock().unwrap().take();
+        login_state.macro_button_poller.lock().unwrap().take();
+    }
+}
+
+/// Send an event to all subscribers, forgetting the ones whose stream was dropped.
+fn broadcast<T: Clone>(subscribers: &Mutex<Vec<Sender<T>>>, event: T) {
+    subscribers
+        .lock()
+        .unwrap()
+        .retain_mut(|s| send(s, event.clone()));
+}
+
+/// Send an event, dropping it if the stream is full. Returns `false` if the stream was dropped.
+fn send<T>(sender: &mut Sender<T>, event: T) -> bool {
+    match sender.try_send(event) {
+        Ok(()) => true,
+        Err(e) if e.is_full() => {
+            tracing::warn!("event stream is full, dropping an event");
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+fn poll(remote: VoicemeeterRemote, shared: Arc<Shared>, interval: Duration) {
+    while !shared.stop.load(Ordering::Relaxed) {
+        // the first dirty poll after a set makes voicemeeter process it, so poll before reading
+        let dirty = match remote.is_parameters_dirty() {
+            Ok(dirty) => dirty,
+            Err(IsParametersDirtyError::NoServer) => {
+                std::thread::sleep(interval);
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!("could not check if parameters are dirty: {e}");
+                false
+            }
+        };
+        let mut changes = vec![];
+        shared.watched.update(&remote, dirty, |change| {
+            changes.push(change);
+            true
+        });
+        for change in changes {
+            broadcast(&shared.parameter_subscribers, change);
+        }
+
+        match remote.is_macrobutton_dirty() {
+            Ok(true) => poll_macro_buttons(&remote, &shared),
+            Ok(false) | Err(IsMacroButtonDirtyError::NoServer) => {}
+            Err(e) => tracing::warn!("could not check if macro buttons are dirty: {e}"),
+        }
+
+        poll_levels(&remote, &shared);
+        poll_midi(&remote, &shared);
+        std::thread::sleep(interval);
+    }
+}
+
+fn read_macro_buttons(remote: &VoicemeeterRemote) -> Result<Vec<bool>, GetMacroButtonStatusError> {
+    (0..MACRO_BUTTONS)
+        .map(|button| remote.get_macrobutton_state(button).map(|s| s.0))
+        .collect()
+}
+
+fn poll_macro_buttons(remote: &VoicemeeterRemote, shared: &Shared) {
+    let mut changes = vec![];
+    {
+        let mut buttons = shared.macro_buttons.lock().unwrap();
+        // nobody is listening yet
+        let Some(buttons) = buttons.as_mut() else {
+            return;
+        };
+        let new = match read_macro_buttons(remote) {
+            Ok(new) => new,
+            Err(e) => {
+                tracing::warn!("could not read macro buttons: {e}");
+                return;
+            }
+        };
+        for (button, (old, new)) in buttons.iter().zip(&new).enumerate() {
+            if old != new {
+                changes.push(MacroButtonChange {
+                    button: button.into(),
+                    pressed: *new,
+                });
+            }
+        }
+        *buttons = new;
+    }
+    for change in changes {
+        broadcast(&shared.macro_button_subscribers, change);
+    }
+}
+
+fn poll_levels(remote: &VoicemeeterRemote, shared: &Shared) {
+    let mut subscribers = shared.level_subscribers.lock().unwrap();
+    subscribers.retain(|(_, s)| !s.is_closed());
+    let mut frames: Vec<LevelFrame> = vec![];
+    for (level_type, sender) in subscribers.iter_mut() {
+        let frame = match frames.iter().find(|f| f.level_type == *level_type) {
+            Some(frame) => frame,
+            None => match read_levels(remote, *level_type) {
+                Some(frame) => {
+                    frames.push(frame);
+                    frames.last().unwrap()
+                }
+                None => continue,
+            },
+        };
+        // a full stream still has an unread frame, drop this one
+        let _ = sender.try_send(frame.clone());
+    }
+}
+
+fn read_levels(remote: &VoicemeeterRemote, level_type: LevelType) -> Option<LevelFrame> {
+    let mut devices = vec![];
+    for device in remote.program().devices() {
+        let mut levels = vec![];
+        for channel in 0.. {
+            match remote.get_level(level_type, *device, channel) {
+                Ok(Some(level)) => levels.push(level),
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::trace!("could not read levels: {e}");
+                    return None;
+                }
+            }
+        }
+        if !levels.is_empty() {
+            devices.push((*device, levels));
+        }
+    }
+    Some(LevelFrame {
+        level_type,
+        devices,
+    })
+}
+
+fn poll_midi(remote: &VoicemeeterRemote, shared: &Shared) {
+    let mut subscribers = shared.midi_subscribers.lock().unwrap();
+    subscribers.retain(|s| !s.is_closed());
+    if subscribers.is_empty() {
+        return;
+    }
+    loop {
+        match remote.get_midi_message() {
+            Ok(message) if !message.is_empty() => {
+                subscribers.retain_mut(|s| send(s, message.clone()))
+            }
+            Ok(_) | Err(GetMidiMessageError::NoMidiData(_)) => return,
+            Err(e) => {
+                tracing::trace!("could not read midi message: {e}");
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, FutureExt, StreamExt};
+
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn streams() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let events = remote.events_every(Duration::from_millis(1))?;
+        assert!(matches!(
+            remote.watch_parameters(),
+            Err(WatchError::AlreadyWatching)
+        ));
+        assert!(matches!(
+            remote.is_macrobutton_dirty(),
+            Err(IsMacroButtonDirtyError::PolledByWatcher)
+        ));
+
+        let mut parameters = events.parameters();
+        let mut buttons = events.macro_buttons()?;
+        let mut midi = events.midi();
+        simulator.set_input_level(0, Some(0.5));
+        let mut levels = events.levels(LevelType::PreFaderInputLevels);
+        events.watch("Strip[0].Mute".into());
+        while events.value("Strip[0].Mute".into()).is_none() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        simulator.gui_set_float("Strip[0].Mute", 1.0)?;
+        let change = block_on(parameters.next()).unwrap();
+        assert_eq!(change.name.as_str(), "Strip[0].Mute");
+        assert_eq!(change.new.as_bool(), Some(true));
+
+        simulator.gui_set_macro_button(3, true);
+        assert_eq!(
+            block_on(buttons.next()),
+            Some(MacroButtonChange {
+                button: 3.into(),
+                pressed: true
+            })
+        );
+
+        simulator.push_midi_message(&[0x90, 60, 127]);
+        assert_eq!(block_on(midi.next()), Some(vec![0x90, 60, 127]));
+
+        let frame = block_on(levels.next()).unwrap();
+        assert_eq!(frame.level_type, LevelType::PreFaderInputLevels);
+        assert_eq!(frame.device(Device::Strip1).map(|l| l[0]), Some(0.5));
+        assert_eq!(frame.device(Device::OutputA1), None);
+
+        drop(events);
+        assert_eq!(block_on(parameters.next()), None);
+        remote.is_macrobutton_dirty()?;
+        remote.is_parameters_dirty()?;
+        Ok(())
+    }
+
+    #[test]
+    fn full_stream() {
+        let (mut sender, mut stream) = EventStream::new(1);
+        assert!((1..=3).all(|i| send(&mut sender, i)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(2)));
+        assert_eq!(stream.next().now_or_never(), None);
+        drop(stream);
+        assert!(!send(&mut sender, 4));
+    }
+}
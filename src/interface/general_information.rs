@@ -14,7 +14,7 @@ impl VoicemeeterRemote {
         &self,
     ) -> Result<VoicemeeterApplication, GetVoicemeeterInformationError> {
         let mut t = 0i32;
-        let res = unsafe { self.raw.VBVMR_GetVoicemeeterType(&mut t) };
+        let res = self.backend.get_voicemeeter_type(&mut t);
         match res {
             0 => Ok(VoicemeeterApplication::from(t)),
             -1 => Err(GetVoicemeeterInformationError::CannotGetClient),
@@ -27,7 +27,7 @@ impl VoicemeeterRemote {
         &self,
     ) -> Result<VoicemeeterVersion, GetVoicemeeterInformationError> {
         let mut t = 0i32;
-        let res = unsafe { self.raw.VBVMR_GetVoicemeeterVersion(&mut t) };
+        let res = self.backend.get_voicemeeter_version(&mut t);
         match res {
             0 => {
                 let a: [u8; 4] = t.to_be_bytes();
@@ -41,7 +41,7 @@ impl VoicemeeterRemote {
 }
 
 /// Version of the Voicemeeter instance.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct VoicemeeterVersion(pub u8, pub u8, pub u8, pub u8);
 
 impl std::fmt::Display for VoicemeeterVersion {
@@ -50,6 +50,40 @@ impl std::fmt::Display for VoicemeeterVersion {
     }
 }
 
+/// Serialized as `"a.b.c.d"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for VoicemeeterVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VoicemeeterVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let invalid = || {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&s),
+                &"a version like \"3.1.1.4\"",
+            )
+        };
+        let mut parts = s.split('.').map(|p| p.parse::<u8>());
+        match (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) {
+            (Some(Ok(a)), Some(Ok(b)), Some(Ok(c)), Some(Ok(d)), None) => {
+                Ok(VoicemeeterVersion(a, b, c, d))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
 /// Errors that can happen when querying information from Voicemeeter.
 #[derive(Debug, thiserror::Error, Clone)]
 #[non_exhaustive]
@@ -5,8 +5,6 @@
 //! * [`get_level`](VoicemeeterRemote::get_level)
 //! * [`get_midi_message`](VoicemeeterRemote::get_midi_message)
 //! * [`get_midi_message_buff`](VoicemeeterRemote::get_midi_message_buff)
-use std::ptr;
-
 pub use crate::types::{Device, LevelType};
 
 use super::VoicemeeterRemote;
@@ -20,14 +18,15 @@ impl VoicemeeterRemote {
         device: Device,
         channel: usize,
     ) -> Result<Option<f32>, GetLevelError> {
-        let mut f = std::f32::NAN;
-        let dev_num =
-            if let Some(dev_num) = device.as_level_device_num(&self.program, level_type, channel) {
-                dev_num as i32
-            } else {
-                return Ok(None);
-            };
-        let res = unsafe { self.raw.VBVMR_GetLevel(level_type as i32, dev_num, &mut f) };
+        let mut f = f32::NAN;
+        let dev_num = if let Some(dev_num) =
+            device.as_level_device_num(&self.program(), level_type, channel)
+        {
+            dev_num as i32
+        } else {
+            return Ok(None);
+        };
+        let res = self.backend.get_level(level_type as i32, dev_num, &mut f);
         match res {
             0 => Ok(Some(f)),
             -1 => Err(GetLevelError::CannotGetClient),
@@ -51,10 +50,7 @@ impl VoicemeeterRemote {
     /// Get a midi message with a set buffer.
     #[inline]
     pub fn get_midi_message_buff(&self, buffer: &mut [u8]) -> Result<usize, GetMidiMessageError> {
-        let res = unsafe {
-            self.raw
-                .VBVMR_GetMidiMessage(ptr::addr_of_mut!(buffer[0]), buffer.len() as _)
-        };
+        let res = self.backend.get_midi_message(buffer);
         match res {
             res if res >= 0 => Ok(res as usize),
             -1 => Err(GetMidiMessageError::CannotGetClient),
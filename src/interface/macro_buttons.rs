@@ -42,8 +42,18 @@ impl VoicemeeterRemote {
     /// # Security
     ///
     /// This method must only be called from one thread.
+    /// While macro buttons are polled for an event stream, calls from other threads return [`IsMacroButtonDirtyError::PolledByWatcher`].
     pub fn is_macrobutton_dirty(&self) -> Result<bool, IsMacroButtonDirtyError> {
-        let res = unsafe { self.raw.VBVMR_MacroButton_IsDirty() };
+        if self
+            .login_state
+            .macro_button_poller
+            .lock()
+            .unwrap()
+            .is_some_and(|id| id != std::thread::current().id())
+        {
+            return Err(IsMacroButtonDirtyError::PolledByWatcher);
+        }
+        let res = self.backend.macro_button_is_dirty();
         match res {
             0 => Ok(false),
             s if s > 0 => Ok(true),
@@ -60,7 +70,7 @@ impl VoicemeeterRemote {
     ) -> Result<MacroButtonStatus, GetMacroButtonStatusError> {
         let mut f = 0.0f32;
         let button = button.into();
-        let res = unsafe { self.raw.VBVMR_MacroButton_GetStatus(button.0.0, &mut f, 0) };
+        let res = self.backend.macro_button_get_status(button.0.0, &mut f, 0);
         match res {
             0 => Ok(MacroButtonStatus(f == 1.)),
             -1 => Err(GetMacroButtonStatusError::CannotGetClient),
@@ -87,10 +97,9 @@ impl VoicemeeterRemote {
         } else {
             VBVMR_MACROBUTTON_MODE::DEFAULT
         };
-        let res = unsafe {
-            self.raw
-                .VBVMR_MacroButton_SetStatus(button.0.0, (state as u32) as f32, bitmode.0)
-        };
+        let res =
+            self.backend
+                .macro_button_set_status(button.0.0, (state as u32) as f32, bitmode.0);
         match res {
             0 => Ok(()),
             -1 => Err(SetMacroButtonStatusError::CannotGetClient),
@@ -108,7 +117,7 @@ impl VoicemeeterRemote {
     ) -> Result<MacroButtonStatus, GetMacroButtonStatusError> {
         let mut f = 0.0f32;
         let button = button.into();
-        let res = unsafe { self.raw.VBVMR_MacroButton_GetStatus(button.0.0, &mut f, 3) };
+        let res = self.backend.macro_button_get_status(button.0.0, &mut f, 3);
         match res {
             0 => Ok(MacroButtonStatus(f == 1.)),
             -1 => Err(GetMacroButtonStatusError::CannotGetClient),
@@ -126,10 +135,9 @@ impl VoicemeeterRemote {
         state: bool,
     ) -> Result<(), SetMacroButtonStatusError> {
         let button = button.into();
-        let res = unsafe {
-            self.raw
-                .VBVMR_MacroButton_SetStatus(button.0.0, (state as u32) as f32, 3)
-        };
+        let res = self
+            .backend
+            .macro_button_set_status(button.0.0, (state as u32) as f32, 3);
         match res {
             0 => Ok(()),
             -1 => Err(SetMacroButtonStatusError::CannotGetClient),
@@ -155,6 +163,9 @@ pub enum IsMacroButtonDirtyError {
     /// An unknown error code occured.
     #[error("unexpected error occurred: error code {0}")]
     Other(i32),
+    /// The macro buttons are polled for an event stream on another thread.
+    #[error("macro buttons are polled by a watcher on another thread")]
+    PolledByWatcher,
 }
 
 /// Errors that can happen when getting macrobutton status.
@@ -9,44 +9,91 @@
 
 // FIXME: file above as an issue upstream, is this an issue?
 use std::borrow::Cow;
-use std::fmt::Debug;
 use std::fmt::Display;
 use std::ops::{Bound, RangeBounds};
 
-use crate::types::{BusMode, Device, ParameterNameRef, VoicemeeterApplication, ZIndex};
+use crate::types::{
+    BusMode, Device, LogicalButton, ParameterNameRef, VoicemeeterApplication, ZIndex,
+};
 use crate::VoicemeeterRemote;
 
 mod errors;
 
 pub mod bus;
+pub mod command;
 pub mod eq;
 pub mod fx;
 pub mod get_parameters;
 pub mod option;
+pub mod patch;
+pub mod ramp;
 pub mod recorder;
+pub mod registry;
+pub mod scene;
+pub mod script;
 pub mod set_parameters;
+pub mod snapshot;
 pub mod strip;
+pub mod tuple;
 pub mod vban;
+pub mod watcher;
+pub(crate) mod written;
 
 pub use bus::*;
+pub use command::*;
 pub use eq::*;
 pub use errors::*;
 pub use fx::*;
 pub use option::*;
+pub use patch::*;
+pub use ramp::{Curve, RampEngine};
 pub use recorder::*;
+pub use registry::{ParameterInfo, ParameterKind};
+pub use scene::{Crossfade, Scene, SceneBook};
+pub use script::{Assignable, Script};
+pub use snapshot::{
+    MixerSnapshot, RestoreFailure, RestoreFailureReason, RestoreReport, SnapshotDiff,
+};
 pub use strip::*;
+pub use tuple::TupleElement;
 pub use vban::*;
+pub use watcher::{ParameterChange, ParameterValue, ParameterWatcher};
 
 use self::get_parameters::GetParameterError;
 use self::set_parameters::SetParameterError;
 
 pub(crate) static BUS: &str = "Bus";
-pub(crate) static FX: &str = "Recorder";
+pub(crate) static COMMAND: &str = "Command";
+pub(crate) static FX: &str = "Fx";
+pub(crate) static PATCH: &str = "Patch";
 pub(crate) static RECORDER: &str = "Recorder";
 pub(crate) static STRIP: &str = "Strip";
 pub(crate) static VOICEMEETER_OPTION: &str = "Option";
 pub(crate) static VBAN: &str = "vban";
 
+/// Programs with effects.
+const POTATO: &[VoicemeeterApplication] = &[
+    VoicemeeterApplication::VoicemeeterPotato,
+    VoicemeeterApplication::PotatoX64Bits,
+];
+
+/// Check that the program of `remote` is one of `valid` before accessing `parameter`.
+fn check_version(
+    remote: &VoicemeeterRemote,
+    valid: &'static [VoicemeeterApplication],
+    parameter: &ParameterNameRef,
+) -> Result<(), InvalidVoicemeeterVersion> {
+    if valid.contains(&remote.program()) {
+        Ok(())
+    } else {
+        Err(InvalidVoicemeeterVersion {
+            expected: valid,
+            found: remote.program(),
+            parameter: parameter.to_string(),
+        })
+    }
+}
+
 impl VoicemeeterRemote {
     /// Get access to [parameters](Parameters) of the application.
     ///
@@ -68,19 +115,23 @@ impl VoicemeeterRemote {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn parameters(&self) -> Parameters {
+    pub fn parameters(&self) -> Parameters<'_> {
         Parameters { remote: self }
     }
 }
 
 /// A float parameter
+///
+/// Values outside of the [range](FloatParameter::range) are rejected by [`set`](FloatParameter::set),
+/// unless the parameter is [clamped](FloatParameter::clamped).
 #[must_use = "set or get the value of the parameter"]
 pub struct FloatParameter<'a, const WRITE: bool = true, const READ: bool = true> {
     /// The name of the parameter, fully qualified
     pub name: Cow<'a, ParameterNameRef>,
 
     remote: &'a VoicemeeterRemote,
-    _range: Option<(Bound<f32>, Bound<f32>)>,
+    range: Option<(Bound<f32>, Bound<f32>)>,
+    clamp: bool,
 }
 
 impl<'a, const WRITE: bool, const READ: bool> FloatParameter<'a, WRITE, READ> {
@@ -91,23 +142,89 @@ impl<'a, const WRITE: bool, const READ: bool> FloatParameter<'a, WRITE, READ> {
         Self {
             name,
             remote,
-            _range: Some((range.start_bound().cloned(), range.end_bound().cloned())),
+            range: Some((range.start_bound().cloned(), range.end_bound().cloned())),
+            clamp: false,
         }
     }
     fn new_unranged(name: Cow<'a, ParameterNameRef>, remote: &'a VoicemeeterRemote) -> Self {
         Self {
             name,
             remote,
-            _range: None,
+            range: None,
+            clamp: false,
+        }
+    }
+
+    /// The range of valid values for this parameter, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::ops::Bound;
+    /// # use voicemeeter::VoicemeeterRemote;
+    /// # let remote: VoicemeeterRemote = todo!();
+    ///
+    /// let gain = remote.parameters().strip(0)?.gain();
+    /// assert_eq!(
+    ///     gain.range(),
+    ///     Some((Bound::Included(-60.0), Bound::Included(12.0)))
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn range(&self) -> Option<(Bound<f32>, Bound<f32>)> {
+        self.range
+    }
+
+    /// Clamp values to the [range](FloatParameter::range) when setting this parameter, instead of returning [`SetParameterError::OutOfRange`].
+    pub fn clamped(self) -> Self {
+        Self {
+            clamp: true,
+            ..self
         }
     }
 }
 
 impl<'a, const READ: bool> FloatParameter<'a, true, READ> {
     /// Set the value of this parameter
+    ///
+    /// Returns [`SetParameterError::OutOfRange`] if the value is outside of the [range](FloatParameter::range) of this parameter,
+    /// or is not finite.
     pub fn set(&self, val: f32) -> Result<(), SetParameterError> {
+        let val = self.checked(val)?;
         self.remote.set_parameter_float(&self.name, val)
     }
+
+    /// Clamp `val` if this parameter is clamped, and check that it is in range.
+    ///
+    /// Non-finite values are rejected before clamping, clamping would turn NaN into the minimum.
+    pub(crate) fn checked(&self, val: f32) -> Result<f32, SetParameterError> {
+        if !val.is_finite() {
+            return Err(SetParameterError::OutOfRange {
+                name: self.name.to_string(),
+                value: val,
+                range: self.range.unwrap_or((Bound::Unbounded, Bound::Unbounded)),
+            });
+        }
+        let val = match self.range {
+            Some(range) if self.clamp => clamp_f32(val, range),
+            _ => val,
+        };
+        match self.range.filter(|range| !range.contains(&val)) {
+            Some(range) => Err(SetParameterError::OutOfRange {
+                name: self.name.to_string(),
+                value: val,
+                range,
+            }),
+            None => Ok(val),
+        }
+    }
+}
+
+impl<'a> FloatParameter<'a, true, false> {
+    /// The last value set for this write-only parameter, see [`VoicemeeterRemote::last_set_value`].
+    pub fn last_set(&self) -> Option<f32> {
+        self.remote.last_set_value(&self.name)?.as_f32()
+    }
 }
 
 impl<'a, const WRITE: bool> FloatParameter<'a, WRITE, true> {
@@ -139,6 +256,16 @@ impl<'a, const READ: bool> StringParameter<'a, true, READ> {
     }
 }
 
+impl<'a> StringParameter<'a, true, false> {
+    /// The last value set for this write-only parameter, see [`VoicemeeterRemote::last_set_value`].
+    pub fn last_set(&self) -> Option<String> {
+        match self.remote.last_set_value(&self.name)? {
+            ParameterValue::String(s) => Some(s),
+            ParameterValue::Float(_) => None,
+        }
+    }
+}
+
 impl<'a, const WRITE: bool> StringParameter<'a, WRITE, true> {
     /// Get the value of this parameter
     pub fn get(&self) -> Result<String, GetParameterError> {
@@ -168,13 +295,38 @@ impl<'a, A, B, const WRITE: bool, const READ: bool> TupleParameter<'a, A, B, WRI
 
 impl<'a, A, B, const READ: bool> TupleParameter<'a, A, B, true, READ>
 where
-    A: Debug,
-    B: Debug,
+    A: TupleElement,
+    B: TupleElement,
 {
     /// Set the value of this parameter
+    ///
+    /// Returns [`SetParameterError::InvalidString`] if a string contains a quote or a line break, they can not be written in a tuple.
     pub fn set(&self, val_a: A, val_b: B) -> Result<(), SetParameterError> {
         self.remote
-            .set_parameter_string(&self.name, &format!("({:?}, {:?})", val_a, val_b))
+            .set_parameter_string(&self.name, &tuple::write(&self.name, &val_a, &val_b)?)
+    }
+}
+
+impl<'a, A, B, const WRITE: bool> TupleParameter<'a, A, B, WRITE, true>
+where
+    A: TupleElement,
+    B: TupleElement,
+{
+    /// Get the value of this parameter
+    pub fn get(&self) -> Result<(A, B), GetParameterError> {
+        tuple::parse(&self.remote.get_parameter_string(&self.name)?)
+            .ok_or_else(|| GetParameterError::StructureMismatch(self.name.to_string(), "tuple"))
+    }
+}
+
+impl<'a, A, B> TupleParameter<'a, A, B, true, false>
+where
+    A: TupleElement,
+    B: TupleElement,
+{
+    /// The last value set for this write-only parameter, see [`VoicemeeterRemote::last_set_value`].
+    pub fn last_set(&self) -> Option<(A, B)> {
+        tuple::parse(self.remote.last_set_value(&self.name)?.as_str()?)
     }
 }
 
@@ -201,6 +353,13 @@ impl<'a, const READ: bool> BoolParameter<'a, true, READ> {
     }
 }
 
+impl<'a> BoolParameter<'a, true, false> {
+    /// The last value set for this write-only parameter, see [`VoicemeeterRemote::last_set_value`].
+    pub fn last_set(&self) -> Option<bool> {
+        self.remote.last_set_value(&self.name)?.as_bool()
+    }
+}
+
 impl<'a, const WRITE: bool> BoolParameter<'a, WRITE, true> {
     /// Get the value of this parameter
     pub fn get(&self) -> Result<bool, GetParameterError> {
@@ -209,13 +368,17 @@ impl<'a, const WRITE: bool> BoolParameter<'a, WRITE, true> {
 }
 
 /// A integer parameter
+///
+/// Values outside of the [range](IntParameter::range) are rejected by [`set`](IntParameter::set),
+/// unless the parameter is [clamped](IntParameter::clamped).
 #[must_use = "set or get the value of the parameter"]
 pub struct IntParameter<'a, const WRITE: bool = true, const READ: bool = true> {
     /// The name of the parameter, fully qualified
     pub name: Cow<'a, ParameterNameRef>,
 
     remote: &'a VoicemeeterRemote,
-    _range: Option<(Bound<i32>, Bound<i32>)>,
+    range: Option<(Bound<i32>, Bound<i32>)>,
+    clamp: bool,
 }
 
 impl<'a, const WRITE: bool, const READ: bool> IntParameter<'a, WRITE, READ> {
@@ -226,23 +389,97 @@ impl<'a, const WRITE: bool, const READ: bool> IntParameter<'a, WRITE, READ> {
         Self {
             name,
             remote,
-            _range: Some((range.start_bound().cloned(), range.end_bound().cloned())),
+            range: Some((range.start_bound().cloned(), range.end_bound().cloned())),
+            clamp: false,
         }
     }
     fn new_unranged(name: Cow<'a, ParameterNameRef>, remote: &'a VoicemeeterRemote) -> Self {
         Self {
             name,
             remote,
-            _range: None,
+            range: None,
+            clamp: false,
+        }
+    }
+
+    /// The range of valid values for this parameter, if known.
+    pub fn range(&self) -> Option<(Bound<i32>, Bound<i32>)> {
+        self.range
+    }
+
+    /// Clamp values to the [range](IntParameter::range) when setting this parameter, instead of returning [`SetParameterError::OutOfRange`].
+    pub fn clamped(self) -> Self {
+        Self {
+            clamp: true,
+            ..self
         }
     }
 }
 
 impl<'a, const READ: bool> IntParameter<'a, true, READ> {
     /// Set the value of this parameter
+    ///
+    /// Returns [`SetParameterError::OutOfRange`] if the value is outside of the [range](IntParameter::range) of this parameter.
     pub fn set(&self, val: i32) -> Result<(), SetParameterError> {
+        let val = self.checked(val)?;
         self.remote.set_parameter_float(&self.name, val as f32)
     }
+
+    /// Clamp `val` if this parameter is clamped, and check that it is in range.
+    pub(crate) fn checked(&self, val: i32) -> Result<i32, SetParameterError> {
+        let val = match self.range {
+            Some(range) if self.clamp => clamp_i32(val, range),
+            _ => val,
+        };
+        match self.range.filter(|range| !range.contains(&val)) {
+            Some(range) => Err(SetParameterError::OutOfRange {
+                name: self.name.to_string(),
+                value: val as f32,
+                range: (bound_to_f32(range.0), bound_to_f32(range.1)),
+            }),
+            None => Ok(val),
+        }
+    }
+}
+
+/// Clamp a value to a range, an excluded float bound can not be reached and is left to fail the range check.
+fn clamp_f32(val: f32, (start, end): (Bound<f32>, Bound<f32>)) -> f32 {
+    let val = match start {
+        Bound::Included(min) => val.max(min),
+        _ => val,
+    };
+    match end {
+        Bound::Included(max) => val.min(max),
+        _ => val,
+    }
+}
+
+fn bound_to_f32(bound: Bound<i32>) -> Bound<f32> {
+    match bound {
+        Bound::Included(b) => Bound::Included(b as f32),
+        Bound::Excluded(b) => Bound::Excluded(b as f32),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+fn clamp_i32(val: i32, (start, end): (Bound<i32>, Bound<i32>)) -> i32 {
+    let val = match start {
+        Bound::Included(min) => val.max(min),
+        Bound::Excluded(min) => val.max(min.saturating_add(1)),
+        Bound::Unbounded => val,
+    };
+    match end {
+        Bound::Included(max) => val.min(max),
+        Bound::Excluded(max) => val.min(max.saturating_sub(1)),
+        Bound::Unbounded => val,
+    }
+}
+
+impl<'a> IntParameter<'a, true, false> {
+    /// The last value set for this write-only parameter, see [`VoicemeeterRemote::last_set_value`].
+    pub fn last_set(&self) -> Option<i32> {
+        Some(self.remote.last_set_value(&self.name)?.as_f32()? as i32)
+    }
 }
 
 impl<'a, const WRITE: bool> IntParameter<'a, WRITE, true> {
@@ -355,7 +592,6 @@ pub struct Parameters<'a> {
     remote: &'a VoicemeeterRemote,
 }
 
-// TODO: `patch`
 impl<'a> Parameters<'a> {
     /// Parameters of a [strip](Strip).
     ///
@@ -372,8 +608,8 @@ impl<'a> Parameters<'a> {
     /// Voicemeeter Banana | total: `5` | total: `3` _(starting on strip #0)_ | total: `2` _(starting on strip #3)_
     /// Voicemeeter Potato | total: `8` | total: `5` _(starting on strip #0)_ | total: `3` _(starting on strip #5)_
     pub fn strip(&self, index: impl StripIndex) -> Result<Strip<'a>, ParameterError> {
-        let index = index.into_strip_index(&self.remote.program)?;
-        Ok(match (self.remote.program, index.0) {
+        let index = index.into_strip_index(&self.remote.program())?;
+        Ok(match (self.remote.program(), index.0) {
             (VoicemeeterApplication::Voicemeeter, 0..=2) => Strip::new(self.remote, index),
             (VoicemeeterApplication::VoicemeeterBanana, 0..=4) => Strip::new(self.remote, index),
             (VoicemeeterApplication::VoicemeeterPotato, 0..=7)
@@ -382,7 +618,7 @@ impl<'a> Parameters<'a> {
                 return Err(Into::into(OutOfRangeError {
                     name: STRIP.to_owned(),
                     index,
-                    program: self.remote.program,
+                    program: self.remote.program(),
                 }));
             }
         })
@@ -402,9 +638,9 @@ impl<'a> Parameters<'a> {
     /// Voicemeeter Banana | total: `5` | total: `3` _(starting on bus #0)_ | total: `2` _(starting on bus #3)_
     /// Voicemeeter Potato | total: `8` | total: `5` _(starting on bus #0)_ | total: `3` _(starting on bus #5)_
     pub fn bus(&self, index: impl BusIndex) -> Result<Bus<'a>, ParameterError> {
-        let index = index.into_bus_index(&self.remote.program)?;
-        Ok(match (self.remote.program, index.0) {
-            (VoicemeeterApplication::Voicemeeter, 0..=1) => Bus::new(self.remote, index),
+        let index = index.into_bus_index(&self.remote.program())?;
+        Ok(match (self.remote.program(), index.0) {
+            (VoicemeeterApplication::Voicemeeter, 0..=2) => Bus::new(self.remote, index),
             (VoicemeeterApplication::VoicemeeterBanana, 0..=4) => Bus::new(self.remote, index),
             (VoicemeeterApplication::VoicemeeterPotato, 0..=7)
             | (VoicemeeterApplication::PotatoX64Bits, 0..=7) => Bus::new(self.remote, index),
@@ -412,7 +648,7 @@ impl<'a> Parameters<'a> {
                 return Err(Into::into(OutOfRangeError {
                     name: BUS.to_owned(),
                     index,
-                    program: self.remote.program,
+                    program: self.remote.program(),
                 }));
             }
         })
@@ -423,6 +659,16 @@ impl<'a> Parameters<'a> {
         VoicemeeterOption::new(self.remote)
     }
 
+    /// Commands for Voicemeeter
+    pub fn command(&self) -> VoicemeeterCommand<'a> {
+        VoicemeeterCommand::new(self.remote)
+    }
+
+    /// Patching of inputs and outputs
+    pub fn patch(&self) -> VoicemeeterPatch<'a> {
+        VoicemeeterPatch::new(self.remote)
+    }
+
     /// Voicemeeter recorder with playback
     pub fn recorder(&self) -> Result<VoicemeeterRecorder<'a>, ParameterError> {
         const VALID: &[VoicemeeterApplication] = &[
@@ -430,10 +676,10 @@ impl<'a> Parameters<'a> {
             VoicemeeterApplication::VoicemeeterPotato,
             VoicemeeterApplication::PotatoX64Bits,
         ];
-        if !VALID.contains(&self.remote.program) {
+        if !VALID.contains(&self.remote.program()) {
             Err(ParameterError::Version(InvalidVoicemeeterVersion {
                 expected: VALID,
-                found: self.remote.program,
+                found: self.remote.program(),
                 parameter: RECORDER.to_owned(),
             }))
         } else {
@@ -447,10 +693,10 @@ impl<'a> Parameters<'a> {
             VoicemeeterApplication::VoicemeeterPotato,
             VoicemeeterApplication::PotatoX64Bits,
         ];
-        if !VALID.contains(&self.remote.program) {
+        if !VALID.contains(&self.remote.program()) {
             Err(ParameterError::Version(InvalidVoicemeeterVersion {
                 expected: VALID,
-                found: self.remote.program,
+                found: self.remote.program(),
                 parameter: FX.to_owned(),
             }))
         } else {
@@ -463,3 +709,139 @@ impl<'a> Parameters<'a> {
         VoicemeeterVban::new(self.remote)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::simulator::remote;
+
+    use super::{set_parameters::SetParametersError, *};
+
+    #[test]
+    fn ranges() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        let strip = remote.parameters().strip(0)?;
+
+        assert_eq!(
+            strip.comp().range(),
+            Some((Bound::Included(0.0), Bound::Included(10.0)))
+        );
+        assert_eq!(strip.pan_y().range(), None);
+
+        strip.gain().set(-6.0)?;
+        assert!(matches!(
+            strip.gain().set(20.0),
+            Err(SetParameterError::OutOfRange { value, .. }) if value == 20.0
+        ));
+        assert!(strip.gain().set(f32::NAN).is_err());
+        assert_eq!(strip.gain().get()?, -6.0);
+        let err = strip.limit().set(13).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value 13 is out of range -40..=12 for parameter `Strip[0].Limit`"
+        );
+
+        strip.gain().clamped().set(20.0)?;
+        assert_eq!(strip.gain().get()?, 12.0);
+        strip.limit().clamped().set(-100)?;
+        assert_eq!(strip.limit().get()?, -40);
+        strip.pan_y().clamped().set(0.25)?;
+        Ok(())
+    }
+
+    #[test]
+    fn non_finite() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        let strip = remote.parameters().strip(0)?;
+
+        strip.gain().set(-6.0)?;
+        strip.pan_y().set(0.25)?;
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            for gain in [strip.gain(), strip.gain().clamped()] {
+                assert!(matches!(
+                    gain.set(value),
+                    Err(SetParameterError::OutOfRange { value: v, range, .. })
+                        if v.to_bits() == value.to_bits() && range == (Bound::Included(-60.0), Bound::Included(12.0))
+                ));
+            }
+            assert!(matches!(
+                strip.pan_y().clamped().set(value),
+                Err(SetParameterError::OutOfRange {
+                    range: (Bound::Unbounded, Bound::Unbounded),
+                    ..
+                })
+            ));
+        }
+        assert_eq!(strip.gain().get()?, -6.0);
+        assert_eq!(strip.pan_y().get()?, 0.25);
+        Ok(())
+    }
+
+    #[test]
+    fn unicode_strings() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let strip = remote.parameters().strip(0)?;
+
+        strip.label().set("Mikrofon für 🎤")?;
+        assert_eq!(simulator.string("Strip[0].Label")?, "Mikrofon für 🎤");
+        assert_eq!(strip.label().get()?, "Mikrofon für 🎤");
+        remote.set_parameters("Strip[1].Label = \"Gitarre ♫\"")?;
+        assert_eq!(remote.parameters().strip(1)?.label().get()?, "Gitarre ♫");
+
+        assert!(matches!(
+            strip.label().set("a\0b"),
+            Err(SetParameterError::ValueContainsNul { name }) if name == "Strip[0].Label"
+        ));
+        assert!(matches!(
+            remote.set_parameter_float(ParameterNameRef::from_str("Strip[0]\0.Gain"), 0.0),
+            Err(SetParameterError::NulError(_))
+        ));
+        assert!(matches!(
+            remote.set_parameters("Strip[0].Mute = 1;\0"),
+            Err(SetParametersError::ContainsNul(18))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn write_only_values() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, mut remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let strip = remote.parameters().strip(0)?;
+
+        assert_eq!(strip.app_gain().last_set(), None);
+        strip.app_gain().set("Google Chrome".to_owned(), -6.5)?;
+        assert_eq!(
+            simulator.string("Strip[0].AppGain")?,
+            "(\"Google Chrome\", -6.5)"
+        );
+        assert_eq!(
+            strip.app_gain().last_set(),
+            Some(("Google Chrome".to_owned(), -6.5))
+        );
+        strip.app_mute().set("Discord".to_owned(), true)?;
+        assert_eq!(simulator.string("Strip[0].AppMute")?, "(\"Discord\", 1)");
+        assert!(matches!(
+            strip.app_mute().set("\"Discord\"".to_owned(), true),
+            Err(SetParameterError::InvalidString { .. })
+        ));
+        assert_eq!(
+            strip.app_mute().last_set(),
+            Some(("Discord".to_owned(), true))
+        );
+
+        strip.app_gain_indexed(ZIndex(1)).set(0.5)?;
+        remote.set_parameters(
+            "strip[0].app[1].gain -= 0.25; Strip[1].FadeTo = (-10, 200); Strip[0].Gain = -3",
+        )?;
+        assert_eq!(strip.app_gain_indexed(ZIndex(1)).last_set(), Some(0.25));
+        assert_eq!(
+            remote.parameters().strip(1)?.fade_to().last_set(),
+            Some((-10, 200))
+        );
+        assert_eq!(remote.last_set_value(&strip.gain().name), None);
+
+        simulator.set_program(VoicemeeterApplication::VoicemeeterPotato);
+        remote.update_program()?;
+        assert_eq!(remote.parameters().strip(0)?.app_gain().last_set(), None);
+        Ok(())
+    }
+}
@@ -49,78 +49,98 @@ impl<'a> Bus<'a> {
     }
 
     /// Label
-    pub fn label(&self) -> StringParameter {
+    pub fn label(&self) -> StringParameter<'_> {
         StringParameter::new(self.param("Label"), self.remote)
     }
 
     /// Mono Button
-    pub fn mono(&self) -> IntParameter {
+    pub fn mono(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("Mono"), self.remote, 0..=2)
     }
 
     /// Mute Button
-    pub fn mute(&self) -> BoolParameter {
+    pub fn mute(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Mute"), self.remote)
     }
 
     /// EQ Button
-    pub fn eq_on(&self) -> BoolParameter {
+    pub fn eq_on(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("EQ.on"), self.remote)
     }
 
     /// EQ Memory Slot
-    pub fn eq_ab(&self) -> BoolParameter {
+    pub fn eq_ab(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("EQ.AB"), self.remote)
     }
 
     /// Gain slider
-    pub fn gain(&self) -> FloatParameter {
+    pub fn gain(&self) -> FloatParameter<'_> {
         FloatParameter::new(self.param("gain"), self.remote, -60.0..=12.)
     }
 
     /// Bus mode Normal
-    pub fn mode(&self) -> BusModeParameter {
+    pub fn mode(&self) -> BusModeParameter<'_> {
         BusModeParameter::new(self.remote, self.bus_index)
     }
 
     /// EQ on channel
-    pub fn eq(&self, channel: usize) -> EqChannelParameter {
+    pub fn eq(&self, channel: usize) -> EqChannelParameter<'_> {
         EqChannelParameter::new_bus(self.remote, self.bus_index, channel)
     }
+    /// All cells of all EQ channels, see [`EqProfile`]
+    pub fn eq_profile(&self) -> EqProfileParameter<'a> {
+        EqProfileParameter::new_bus(self.remote, self.bus_index)
+    }
     /// Fade to
-    pub fn fade_to(&self) -> TupleParameter<'_, f32, usize> {
+    pub fn fade_to(&self) -> TupleParameter<'_, f32, usize, true, false> {
         TupleParameter::new(self.param("FadeTo"), self.remote)
     }
     /// Fade by
-    pub fn fade_by(&self) -> TupleParameter<'_, f32, usize> {
+    pub fn fade_by(&self) -> TupleParameter<'_, f32, usize, true, false> {
         TupleParameter::new(self.param("FadeBy"), self.remote)
     }
     /// BUS SEL Button
-    pub fn sel(&self) -> BoolParameter {
+    pub fn sel(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Sel"), self.remote)
     }
     /// Reverb return
-    pub fn return_reverb(&self) -> IntParameter {
-        IntParameter::new(self.param("ReturnReverb"), self.remote, 0..=10)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn return_reverb(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("ReturnReverb");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Delay return
-    pub fn return_delay(&self) -> IntParameter {
-        IntParameter::new(self.param("ReturnDelay"), self.remote, 0..=10)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn return_delay(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("ReturnDelay");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Fx1 Return
-    pub fn return_fx1(&self) -> IntParameter {
-        IntParameter::new(self.param("ReturnFx1"), self.remote, 0..=10)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn return_fx1(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("ReturnFx1");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Fx2 Return
-    pub fn return_fx2(&self) -> IntParameter {
-        IntParameter::new(self.param("ReturnFx2"), self.remote, 0..=10)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn return_fx2(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("ReturnFx2");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Monitor
-    pub fn monitor(&self) -> BoolParameter {
+    pub fn monitor(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Monitor"), self.remote)
     }
     /// Audio Device information
-    pub fn device(&self) -> BusDevice {
+    pub fn device(&self) -> BusDevice<'_> {
         BusDevice::new(self.remote, self.bus_index)
     }
 }
@@ -289,6 +309,14 @@ impl<'a> BusModeParameter<'a> {
     }
 }
 
+impl script::Assignable<BusMode> for BusModeParameter<'_> {
+    fn assignment(&self, value: BusMode) -> Result<String, SetParameterError> {
+        let name = self.param(value);
+        script::check_supported(self.remote, &name)?;
+        Ok(format!("{name} = 1"))
+    }
+}
+
 /// Bus device parameters
 pub struct BusDevice<'a> {
     remote: &'a VoicemeeterRemote,
@@ -330,3 +358,25 @@ impl<'a> BusDevice<'a> {
         StringParameter::new(self.param("asio"), self.remote)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn fx_returns() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, mut remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        remote.parameters().bus(5)?.return_delay()?.set(7.5)?;
+        assert_eq!(simulator.float("Bus[5].ReturnDelay")?, 7.5);
+
+        simulator.set_program(VoicemeeterApplication::VoicemeeterBanana);
+        remote.update_program()?;
+        assert!(matches!(
+            remote.parameters().bus(0)?.return_reverb(),
+            Err(ParameterError::Version(_))
+        ));
+        Ok(())
+    }
+}
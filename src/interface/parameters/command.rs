@@ -0,0 +1,235 @@
+//! Commands
+use super::*;
+use errors::*;
+
+/// Amount of macro buttons in Voicemeeter.
+pub(crate) const MACRO_BUTTONS: i32 = 80;
+
+/// Commands for Voicemeeter
+///
+/// Most commands are actions, they are run by setting the parameter to `true`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use voicemeeter::VoicemeeterRemote;
+///
+/// # let remote: VoicemeeterRemote = todo!();
+/// let command = remote.parameters().command();
+/// command.save().set(r"C:\Users\me\Documents\Voicemeeter\live.xml")?;
+/// command.button(3)?.state().set(true)?;
+/// command.restart().set(true)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct VoicemeeterCommand<'a> {
+    remote: &'a VoicemeeterRemote,
+}
+
+impl<'a> VoicemeeterCommand<'a> {
+    #[doc(hidden)]
+    pub fn new(remote: &'a VoicemeeterRemote) -> Self {
+        VoicemeeterCommand { remote }
+    }
+
+    /// Get the identifier for a command: `Command.{dot}`
+    pub fn param(&self, dot: impl Display) -> Cow<'static, ParameterNameRef> {
+        Cow::Owned(format!("{COMMAND}.{}", dot).into())
+    }
+
+    /// Shutdown Voicemeeter
+    pub fn shutdown(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("Shutdown"), self.remote)
+    }
+    /// Show the Voicemeeter window
+    pub fn show(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("Show"), self.remote)
+    }
+    /// Restart the audio engine
+    pub fn restart(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("Restart"), self.remote)
+    }
+    /// Eject the cassettes of the recorder
+    pub fn eject(&self) -> Result<BoolParameter<'_, true, false>, ParameterError> {
+        const VALID: &[VoicemeeterApplication] = &[
+            VoicemeeterApplication::VoicemeeterBanana,
+            VoicemeeterApplication::VoicemeeterPotato,
+            VoicemeeterApplication::PotatoX64Bits,
+        ];
+        let param = self.param("Eject");
+        check_version(self.remote, VALID, &param)?;
+        Ok(BoolParameter::new(param, self.remote))
+    }
+    /// Reset the whole configuration
+    pub fn reset(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("Reset"), self.remote)
+    }
+    /// Lock the Voicemeeter GUI
+    pub fn lock(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("Lock"), self.remote)
+    }
+    /// Save the complete settings to an XML file
+    pub fn save(&self) -> StringParameter<'_, true, false> {
+        StringParameter::new(self.param("Save"), self.remote)
+    }
+    /// Load the complete settings from an XML file
+    pub fn load(&self) -> StringParameter<'_, true, false> {
+        StringParameter::new(self.param("Load"), self.remote)
+    }
+    /// Save the EQ settings of a bus to an XML file
+    pub fn save_bus_eq(
+        &self,
+        bus: impl BusIndex,
+    ) -> Result<StringParameter<'_, true, false>, ParameterError> {
+        let bus = bus.into_bus_index(&self.remote.program())?;
+        Ok(StringParameter::new(
+            self.param(format_args!("SaveBUSEQ[{bus}]")),
+            self.remote,
+        ))
+    }
+    /// Load the EQ settings of a bus from an XML file
+    pub fn load_bus_eq(
+        &self,
+        bus: impl BusIndex,
+    ) -> Result<StringParameter<'_, true, false>, ParameterError> {
+        let bus = bus.into_bus_index(&self.remote.program())?;
+        Ok(StringParameter::new(
+            self.param(format_args!("LoadBUSEQ[{bus}]")),
+            self.remote,
+        ))
+    }
+    /// Save the EQ settings of a strip to an XML file
+    ///
+    /// Only available on physical strips in Voicemeeter Potato, like [`Strip::eq`].
+    pub fn save_strip_eq(
+        &self,
+        strip: impl StripIndex,
+    ) -> Result<StringParameter<'_, true, false>, ParameterError> {
+        self.strip_eq("SaveStripEQ", strip)
+    }
+    /// Load the EQ settings of a strip from an XML file
+    ///
+    /// Only available on physical strips in Voicemeeter Potato, like [`Strip::eq`].
+    pub fn load_strip_eq(
+        &self,
+        strip: impl StripIndex,
+    ) -> Result<StringParameter<'_, true, false>, ParameterError> {
+        self.strip_eq("LoadStripEQ", strip)
+    }
+
+    fn strip_eq(
+        &self,
+        command: &str,
+        strip: impl StripIndex,
+    ) -> Result<StringParameter<'_, true, false>, ParameterError> {
+        let index = strip.into_strip_index(&self.remote.program())?;
+        let strip = Parameters {
+            remote: self.remote,
+        }
+        .strip(index)?;
+        let param = self.param(format_args!("{command}[{index}]"));
+        check_version(self.remote, POTATO, &param)?;
+        if !strip.is_physical() {
+            Err(InvalidTypeError::ExpectedPhysical {
+                name: STRIP,
+                strip_index: index,
+                parameter: param.to_string(),
+            }
+            .into())
+        } else {
+            Ok(StringParameter::new(param, self.remote))
+        }
+    }
+
+    /// A macro button
+    ///
+    /// Macro buttons can also be read and changed with [`VoicemeeterRemote::get_macrobutton_state`] and friends.
+    pub fn button(
+        &self,
+        button: impl Into<LogicalButton>,
+    ) -> Result<VoicemeeterCommandButton<'a>, ParameterError> {
+        let button = button.into();
+        if !(0..MACRO_BUTTONS).contains(&button.0 .0) {
+            return Err(OutOfRangeError {
+                name: format!("{COMMAND}.Button"),
+                index: button.0,
+                program: self.remote.program(),
+            }
+            .into());
+        }
+        Ok(VoicemeeterCommandButton {
+            remote: self.remote,
+            button,
+        })
+    }
+
+    /// Show the VBAN-Chat dialog
+    pub fn dialog_show_vban_chat(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("DialogShow.VBANCHAT"), self.remote)
+    }
+}
+
+/// Commands for a macro button: `Command.Button[i]`
+pub struct VoicemeeterCommandButton<'a> {
+    remote: &'a VoicemeeterRemote,
+    button: LogicalButton,
+}
+
+impl<'a> VoicemeeterCommandButton<'a> {
+    /// Get the identifier for a command on this button: `Command.Button[i].{dot}`
+    pub fn param(&self, dot: impl Display) -> Cow<'static, ParameterNameRef> {
+        Cow::Owned(format!("{COMMAND}.Button[{}].{}", self.button.0, dot).into())
+    }
+
+    /// Change the button state, running the commands of the button
+    pub fn state(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("State"), self.remote)
+    }
+    /// Change the button state, without running the commands of the button
+    pub fn state_only(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("StateOnly"), self.remote)
+    }
+    /// Change the trigger state of the button
+    pub fn trigger(&self) -> BoolParameter<'_, true, false> {
+        BoolParameter::new(self.param("Trigger"), self.remote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn commands() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, mut remote) = remote(VoicemeeterApplication::Voicemeeter);
+        let command = remote.parameters().command();
+
+        command.button(5)?.state().set(true)?;
+        assert!(remote.get_macrobutton_state(5)?.0);
+        assert_eq!(command.button(5)?.state().last_set(), Some(true));
+        assert!(matches!(
+            command.button(80),
+            Err(ParameterError::OutOfRange(_))
+        ));
+        command.load_bus_eq(1)?.set("bus.xml")?;
+        assert_eq!(simulator.string("Command.LoadBUSEQ[1]")?, "bus.xml");
+        assert!(matches!(command.eject(), Err(ParameterError::Version(_))));
+        assert!(matches!(
+            command.save_strip_eq(0),
+            Err(ParameterError::Version(_))
+        ));
+
+        simulator.set_program(VoicemeeterApplication::VoicemeeterPotato);
+        remote.update_program()?;
+        let command = remote.parameters().command();
+        command.eject()?.set(true)?;
+        command.save_strip_eq(4)?.set("strip.xml")?;
+        assert_eq!(simulator.string("Command.SaveStripEQ[4]")?, "strip.xml");
+        assert!(matches!(
+            command.save_strip_eq(5),
+            Err(ParameterError::Type(_))
+        ));
+        Ok(())
+    }
+}
@@ -1,5 +1,12 @@
 //! Common structs for EQ
+//!
+//! See [`preset`] to import and export EQ presets of other tools.
+pub mod preset;
+
+use std::ops::RangeInclusive;
+
 use super::*;
+use set_parameters::SetParametersError;
 
 enum Mode {
     Strip,
@@ -15,6 +22,13 @@ impl std::fmt::Display for Mode {
     }
 }
 
+/// Range of the frequency of an EQ cell in Hz.
+const FREQUENCY: RangeInclusive<f32> = 20.0..=20_000.0;
+/// Range of the gain of an EQ cell in dB.
+const GAIN: RangeInclusive<f32> = -36.0..=18.0;
+/// Range of the quality of an EQ cell.
+const QUALITY: RangeInclusive<f32> = 1.0..=100.0;
+
 /// Parameter for EQ on a specific channel and input/output (bus/strip)
 pub struct EqChannelParameter<'a> {
     remote: &'a VoicemeeterRemote,
@@ -55,28 +69,303 @@ impl<'a> EqChannelParameter<'a> {
         Cow::Owned(format!("{}.cell[{}].{}", self.name(), cell, dot.to_string()).into())
     }
     /// Turn EQ cell on or off
-    pub fn on(&self, cell: usize) -> BoolParameter {
+    pub fn on(&self, cell: usize) -> BoolParameter<'_> {
         BoolParameter::new(self.param(cell, "on"), self.remote)
     }
-    /// Type of EQ filter.
-    pub fn type_(&self, cell: usize) -> IntParameter {
-        // TODO: Enum Parameter
+    /// Type of EQ filter, see [`EqFilterType`] for the values.
+    pub fn type_(&self, cell: usize) -> IntParameter<'_> {
         IntParameter::new(self.param(cell, "type"), self.remote, 0..=6)
     }
     /// Frequency of the EQ filter.
-    pub fn f(&self, cell: usize) -> FloatParameter {
+    pub fn f(&self, cell: usize) -> FloatParameter<'_> {
         // TODO: Enum Parameter
-        FloatParameter::new(self.param(cell, "f"), self.remote, 20.0..=20_000.0)
+        FloatParameter::new(self.param(cell, "f"), self.remote, FREQUENCY)
     }
     /// Gain of the EQ filter.
-    pub fn gain(&self, cell: usize) -> FloatParameter {
+    pub fn gain(&self, cell: usize) -> FloatParameter<'_> {
         // TODO: Enum Parameter
         // NOTE: Docs say -12 to 12, but interface allows -36 to 18
-        FloatParameter::new(self.param(cell, "gain"), self.remote, -36.0..=18.0)
+        FloatParameter::new(self.param(cell, "gain"), self.remote, GAIN)
     }
     /// Quality of the EQ filter.
-    pub fn q(&self, cell: usize) -> IntParameter {
-        // TODO: Enum Parameter
-        IntParameter::new(self.param(cell, "q"), self.remote, 1..=100)
+    pub fn q(&self, cell: usize) -> FloatParameter<'_> {
+        FloatParameter::new(self.param(cell, "q"), self.remote, QUALITY)
+    }
+}
+
+/// Cells in every channel of an EQ.
+pub const EQ_CELLS: usize = 6;
+
+/// Channels of the EQ of a bus.
+pub const BUS_EQ_CHANNELS: usize = 8;
+
+/// Type of an EQ filter, the value of [`EqChannelParameter::type_`]
+///
+/// With the `serde` feature, this is represented by its variant name, e.g. `"LowShelf"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum EqFilterType {
+    /// Peaking filter
+    #[default]
+    Peak,
+    /// Notch filter
+    Notch,
+    /// Band pass filter
+    BandPass,
+    /// Low pass filter
+    LowPass,
+    /// High pass filter
+    HighPass,
+    /// Low shelf filter
+    LowShelf,
+    /// High shelf filter
+    HighShelf,
+}
+
+impl EqFilterType {
+    const ALL: [EqFilterType; 7] = [
+        EqFilterType::Peak,
+        EqFilterType::Notch,
+        EqFilterType::BandPass,
+        EqFilterType::LowPass,
+        EqFilterType::HighPass,
+        EqFilterType::LowShelf,
+        EqFilterType::HighShelf,
+    ];
+
+    /// The filter type with this value of [`EqChannelParameter::type_`]
+    pub fn from_index(index: i32) -> Option<Self> {
+        Self::ALL.get(usize::try_from(index).ok()?).copied()
+    }
+
+    /// The value of [`EqChannelParameter::type_`] for this filter type
+    pub fn index(self) -> i32 {
+        Self::ALL
+            .iter()
+            .position(|t| *t == self)
+            .unwrap_or_default() as i32
+    }
+}
+
+/// One cell of an EQ channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EqCell {
+    /// Cell is on
+    pub on: bool,
+    /// Filter type
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub filter: EqFilterType,
+    /// Frequency in Hz
+    pub frequency: f32,
+    /// Gain in dB
+    pub gain: f32,
+    /// Quality
+    pub q: f32,
+}
+
+impl Default for EqCell {
+    /// A cell that is off, with a flat peaking filter at 1 kHz.
+    fn default() -> Self {
+        Self {
+            on: false,
+            filter: EqFilterType::Peak,
+            frequency: 1000.0,
+            gain: 0.0,
+            q: 3.0,
+        }
+    }
+}
+
+/// The EQ curve of a bus or strip: all cells of all channels
+///
+/// Read and written with [`EqProfileParameter`], see [`Bus::eq_profile`] and [`Strip::eq_profile`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use voicemeeter::{
+///     interface::parameters::{EqCell, EqFilterType, EqProfile, BUS_EQ_CHANNELS},
+///     VoicemeeterRemote,
+/// };
+///
+/// # let remote: VoicemeeterRemote = todo!();
+/// // cut the room mode at 63 Hz on every channel of A1
+/// let mut correction = EqProfile::flat(BUS_EQ_CHANNELS);
+/// for channel in &mut correction.channels {
+///     channel[0] = EqCell {
+///         on: true,
+///         filter: EqFilterType::Peak,
+///         frequency: 63.0,
+///         gain: -6.5,
+///         q: 4.0,
+///     };
+/// }
+/// let eq = remote.parameters().bus(0)?.eq_profile();
+/// if eq.get()? != correction {
+///     eq.set(&correction)?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EqProfile {
+    /// The cells of every channel
+    pub channels: Vec<[EqCell; EQ_CELLS]>,
+}
+
+impl EqProfile {
+    /// A profile with `channels` channels where all cells are [off](EqCell::default)
+    pub fn flat(channels: usize) -> Self {
+        Self {
+            channels: vec![[EqCell::default(); EQ_CELLS]; channels],
+        }
+    }
+}
+
+/// The whole EQ of a bus or strip, see [`EqProfile`]
+pub struct EqProfileParameter<'a> {
+    remote: &'a VoicemeeterRemote,
+    mode: Mode,
+    index: ZIndex,
+    channels: usize,
+}
+
+impl<'a> EqProfileParameter<'a> {
+    pub(crate) fn new_bus(remote: &'a VoicemeeterRemote, index: ZIndex) -> Self {
+        Self {
+            remote,
+            mode: Mode::Bus,
+            index,
+            channels: BUS_EQ_CHANNELS,
+        }
+    }
+
+    /// EQ of a physical strip, which has two channels.
+    pub(crate) fn new_strip(remote: &'a VoicemeeterRemote, index: ZIndex) -> Self {
+        Self {
+            remote,
+            mode: Mode::Strip,
+            index,
+            channels: 2,
+        }
+    }
+
+    fn channel(&self, channel: usize) -> EqChannelParameter<'a> {
+        match self.mode {
+            Mode::Strip => EqChannelParameter::new_strip(self.remote, self.index, channel),
+            Mode::Bus => EqChannelParameter::new_bus(self.remote, self.index, channel),
+        }
+    }
+
+    /// Amount of channels of the EQ
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Read all cells of all channels
+    ///
+    /// Filter types that are not known are read as [`EqFilterType::Peak`].
+    pub fn get(&self) -> Result<EqProfile, GetParameterError> {
+        let channels = (0..self.channels)
+            .map(|channel| {
+                let eq = self.channel(channel);
+                let mut cells = [EqCell::default(); EQ_CELLS];
+                for (index, cell) in cells.iter_mut().enumerate() {
+                    *cell = EqCell {
+                        on: eq.on(index).get()?,
+                        filter: EqFilterType::from_index(eq.type_(index).get()?)
+                            .unwrap_or_default(),
+                        frequency: eq.f(index).get()?,
+                        gain: eq.gain(index).get()?,
+                        q: eq.q(index).get()?,
+                    };
+                }
+                Ok(cells)
+            })
+            .collect::<Result<_, GetParameterError>>()?;
+        Ok(EqProfile { channels })
+    }
+
+    /// Write all cells of all channels in `profile` with a single script
+    ///
+    /// Values are checked like [`Script`] does, and a profile with more channels than the EQ is rejected
+    /// with [`SetParameterError::TooManyChannels`].
+    pub fn set(&self, profile: &EqProfile) -> Result<(), SetParametersError> {
+        if profile.channels.len() > self.channels {
+            return Err(SetParameterError::TooManyChannels {
+                name: format!("{}[{}].EQ", self.mode, self.index),
+                channels: profile.channels.len(),
+                max: self.channels,
+            }
+            .into());
+        }
+        let mut script = Script::new();
+        for (channel, cells) in profile.channels.iter().enumerate() {
+            let eq = self.channel(channel);
+            for (index, cell) in cells.iter().enumerate() {
+                script = script
+                    .set(&eq.on(index), cell.on)?
+                    .set(&eq.type_(index), cell.filter.index())?
+                    .set(&eq.f(index), cell.frequency)?
+                    .set(&eq.gain(index), cell.gain)?
+                    .set(&eq.q(index), cell.q)?;
+            }
+        }
+        script.apply(self.remote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn eq_profile() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        let bus = remote.parameters().bus(1)?;
+        let eq = bus.eq_profile();
+        assert_eq!(eq.get()?, EqProfile::flat(BUS_EQ_CHANNELS));
+
+        let mut profile = EqProfile::flat(BUS_EQ_CHANNELS);
+        profile.channels[3][5] = EqCell {
+            on: true,
+            filter: EqFilterType::HighShelf,
+            frequency: 8000.0,
+            gain: -4.5,
+            q: 1.7,
+        };
+        eq.set(&profile)?;
+        assert_eq!(simulator.float("Bus[1].EQ.channel[3].cell[5].type")?, 6.0);
+        assert_eq!(eq.get()?, profile);
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&profile)?;
+            assert_eq!(json["channels"][3][5]["type"], "HighShelf");
+            assert_eq!(serde_json::from_value::<EqProfile>(json)?, profile);
+        }
+
+        profile.channels[0][0].gain = 40.0;
+        assert!(eq.set(&profile).is_err());
+        assert!(matches!(
+            eq.set(&EqProfile::flat(BUS_EQ_CHANNELS + 1)),
+            Err(SetParametersError::SetParameterError(SetParameterError::TooManyChannels {
+                name,
+                channels: 9,
+                max: 8,
+            })) if name == "Bus[1].EQ"
+        ));
+
+        let strip = remote.parameters().strip(0)?.eq_profile()?;
+        assert_eq!(strip.channels(), 2);
+        strip.set(&EqProfile::flat(1))?;
+        assert!(matches!(
+            remote.parameters().strip(5)?.eq_profile(),
+            Err(ParameterError::Type(_))
+        ));
+        Ok(())
     }
 }
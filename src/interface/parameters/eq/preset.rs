@@ -0,0 +1,573 @@
+//! Import and export of EQ presets made with other tools.
+//!
+//! Two text formats are supported, see [`EqPresetFormat`]:
+//!
+//! * [Equalizer APO](https://sourceforge.net/p/equalizerapo/wiki/Configuration%20reference/) configurations,
+//!   which is also the format of the parametric EQ files of [AutoEq](https://github.com/jaakkopasanen/AutoEq).
+//! * Filter settings exported from [REW](https://www.roomeqwizard.com/).
+//!
+//! ```text
+//! Preamp: -6.2 dB
+//! Filter 1: ON LSC Fc 105 Hz Gain 6.5 dB Q 0.70
+//! Filter 2: ON PK Fc 194 Hz Gain -2.8 dB Q 1.35
+//! ```
+//!
+//! Filters are mapped to the [`EqFilterType`]s of Voicemeeter, and fill the cells of a channel in order.
+//! Filters without a counterpart in Voicemeeter are skipped, values outside of the ranges of Voicemeeter are clamped,
+//! and filters that do not fit in the [cells](EQ_CELLS) of a channel are dropped. Every such change is reported as an [`EqImportWarning`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use voicemeeter::{
+//!     interface::parameters::{eq::preset::EqPresetFormat, EqProfile},
+//!     VoicemeeterRemote,
+//! };
+//!
+//! # let remote: VoicemeeterRemote = todo!();
+//! let eq = remote.parameters().bus(0)?.eq_profile();
+//! let source = std::fs::read_to_string("ParametricEQ.txt")?;
+//! let import = EqProfile::import(&source, EqPresetFormat::EqualizerApo, eq.channels())?;
+//! for warning in &import.warnings {
+//!     eprintln!("{warning}");
+//! }
+//! eq.set(&import.profile)?;
+//!
+//! std::fs::write("A1.txt", eq.get()?.export(EqPresetFormat::EqualizerApo, None))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::iter::Peekable;
+use std::ops::RangeInclusive;
+use std::str::SplitWhitespace;
+
+use super::{EqCell, EqFilterType, EqProfile, EQ_CELLS, FREQUENCY, GAIN, QUALITY};
+
+/// Format of an EQ preset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EqPresetFormat {
+    /// Equalizer APO configuration, also used for the parametric EQ files of AutoEq
+    ///
+    /// The `Filter`, `Preamp` and `Channel` commands are imported, other commands are ignored with a warning.
+    /// Channels are selected with their number, starting at `1`, or with the names `L`, `R`, `C`, `SUB`, `RL`, `RR`, `SL` and `SR`.
+    ///
+    /// Exported profiles have a `Channel` section for every channel, unless all channels are the same.
+    EqualizerApo,
+    /// Filter settings exported from REW
+    ///
+    /// Only the `Filter` lines are imported, and applied to all channels.
+    /// REW filter settings have a single channel, only the first channel of a profile is exported.
+    Rew,
+}
+
+/// The result of [`EqProfile::import`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EqImport {
+    /// The imported profile
+    pub profile: EqProfile,
+    /// Preamp of the preset in dB
+    ///
+    /// The EQ of Voicemeeter has no preamp, apply it to the gain of the bus or strip to avoid clipping.
+    pub preamp: f32,
+    /// Changes made to fit the preset in the EQ of Voicemeeter
+    pub warnings: Vec<EqImportWarning>,
+}
+
+/// A change made to fit a preset in the EQ of Voicemeeter, see [`EqImport`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum EqImportWarning {
+    /// The filter type has no counterpart in Voicemeeter, the filter was skipped.
+    UnsupportedFilter {
+        /// Line of the filter, starting at `1`
+        line: usize,
+        /// The filter type
+        filter: String,
+    },
+    /// A value is outside of the range of Voicemeeter and was clamped.
+    Clamped {
+        /// Line of the filter, starting at `1`
+        line: usize,
+        /// Name of the value
+        value: &'static str,
+        /// The value in the preset
+        from: f32,
+        /// The imported value
+        to: f32,
+    },
+    /// A channel has more filters than cells, the last filters were dropped.
+    Truncated {
+        /// The channel, starting at `1` like the `Channel` command
+        channel: usize,
+        /// Amount of dropped filters
+        dropped: usize,
+    },
+    /// A command or channel is not supported and was ignored.
+    Ignored {
+        /// Line of the command, starting at `1`
+        line: usize,
+        /// The ignored text
+        text: String,
+    },
+}
+
+impl std::fmt::Display for EqImportWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EqImportWarning::UnsupportedFilter { line, filter } => {
+                write!(
+                    f,
+                    "line {line}: filter type `{filter}` is not supported, skipped"
+                )
+            }
+            EqImportWarning::Clamped {
+                line,
+                value,
+                from,
+                to,
+            } => write!(
+                f,
+                "line {line}: {value} {from} is out of range, clamped to {to}"
+            ),
+            EqImportWarning::Truncated { channel, dropped } => write!(
+                f,
+                "channel {channel}: {dropped} filters do not fit in the {EQ_CELLS} cells, dropped"
+            ),
+            EqImportWarning::Ignored { line, text } => write!(f, "line {line}: ignored `{text}`"),
+        }
+    }
+}
+
+/// Errors that can happen when importing a preset with [`EqProfile::import`].
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EqPresetError {
+    /// A filter could not be parsed, or has no frequency.
+    #[error("invalid filter on line {line}: `{text}`")]
+    InvalidFilter {
+        /// Line of the filter, starting at `1`
+        line: usize,
+        /// The filter
+        text: String,
+    },
+    /// A preamp could not be parsed.
+    #[error("invalid preamp on line {line}: `{text}`")]
+    InvalidPreamp {
+        /// Line of the preamp, starting at `1`
+        line: usize,
+        /// The preamp
+        text: String,
+    },
+}
+
+impl EqProfile {
+    /// Import a preset in `format` into a profile with `channels` channels
+    ///
+    /// Use the [channels of the EQ](super::EqProfileParameter::channels) the profile will be set on.
+    /// Filters without a quality use the lowest quality of Voicemeeter, `1`.
+    pub fn import(
+        source: &str,
+        format: EqPresetFormat,
+        channels: usize,
+    ) -> Result<EqImport, EqPresetError> {
+        let mut preamp = 0.0;
+        let mut warnings = vec![];
+        let mut filters = vec![vec![]; channels];
+        let mut selected: Vec<usize> = (0..channels).collect();
+        for (index, text) in source.lines().enumerate() {
+            let line = index + 1;
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            match text
+                .split_once(':')
+                .map(|(c, args)| (c.trim(), args.trim()))
+            {
+                Some((command, args)) if is_filter(command) => {
+                    if let Some(cell) = parse_filter(line, text, args, &mut warnings)? {
+                        for &channel in &selected {
+                            filters[channel].push(cell);
+                        }
+                    }
+                }
+                // REW filter settings start with free text
+                _ if format == EqPresetFormat::Rew => {}
+                Some((command, args)) if command.eq_ignore_ascii_case("Preamp") => {
+                    preamp += args
+                        .split_whitespace()
+                        .next()
+                        .and_then(parse_number)
+                        .ok_or_else(|| EqPresetError::InvalidPreamp {
+                            line,
+                            text: text.to_owned(),
+                        })?;
+                }
+                Some((command, args)) if command.eq_ignore_ascii_case("Channel") => {
+                    selected = parse_channels(line, args, channels, &mut warnings);
+                }
+                _ => warnings.push(EqImportWarning::Ignored {
+                    line,
+                    text: text.to_owned(),
+                }),
+            }
+        }
+        let channels = filters
+            .into_iter()
+            .enumerate()
+            .map(|(channel, filters)| {
+                if filters.len() > EQ_CELLS {
+                    warnings.push(EqImportWarning::Truncated {
+                        channel: channel + 1,
+                        dropped: filters.len() - EQ_CELLS,
+                    });
+                }
+                let mut cells = [EqCell::default(); EQ_CELLS];
+                for (cell, filter) in cells.iter_mut().zip(filters) {
+                    *cell = filter;
+                }
+                cells
+            })
+            .collect();
+        Ok(EqImport {
+            profile: EqProfile { channels },
+            preamp,
+            warnings,
+        })
+    }
+
+    /// Export the profile as a preset in `format`
+    ///
+    /// Every cell is exported, cells that are off as `OFF` filters. A `preamp` in dB, like the one of an
+    /// [`EqImport`], is written as a `Preamp` command. REW filter settings have no preamp, it is left out.
+    pub fn export(&self, format: EqPresetFormat, preamp: Option<f32>) -> String {
+        let mut out = String::new();
+        match format {
+            EqPresetFormat::EqualizerApo => {
+                if let Some(preamp) = preamp {
+                    out.push_str(&format!("Preamp: {preamp} dB\n"));
+                }
+                if self.channels.windows(2).all(|w| w[0] == w[1]) {
+                    if let Some(cells) = self.channels.first() {
+                        write_filters(&mut out, cells);
+                    }
+                } else {
+                    for (channel, cells) in self.channels.iter().enumerate() {
+                        out.push_str(&format!("Channel: {}\n", channel + 1));
+                        write_filters(&mut out, cells);
+                    }
+                }
+            }
+            EqPresetFormat::Rew => {
+                out.push_str("Filter Settings file\n\nEqualiser: Generic\n\n");
+                if let Some(cells) = self.channels.first() {
+                    write_filters(&mut out, cells);
+                }
+            }
+        }
+        out
+    }
+}
+
+fn write_filters(out: &mut String, cells: &[EqCell; EQ_CELLS]) {
+    for (index, cell) in cells.iter().enumerate() {
+        let (filter, gain) = match cell.filter {
+            EqFilterType::Peak => ("PK", true),
+            EqFilterType::Notch => ("NO", false),
+            EqFilterType::BandPass => ("BP", false),
+            EqFilterType::LowPass => ("LPQ", false),
+            EqFilterType::HighPass => ("HPQ", false),
+            EqFilterType::LowShelf => ("LSC", true),
+            EqFilterType::HighShelf => ("HSC", true),
+        };
+        let state = if cell.on { "ON" } else { "OFF" };
+        out.push_str(&format!(
+            "Filter {}: {state} {filter} Fc {} Hz",
+            index + 1,
+            cell.frequency
+        ));
+        if gain {
+            out.push_str(&format!(" Gain {} dB", cell.gain));
+        }
+        out.push_str(&format!(" Q {}\n", cell.q));
+    }
+}
+
+/// `Filter` or `Filter 3`
+fn is_filter(command: &str) -> bool {
+    command
+        .get(..6)
+        .is_some_and(|c| c.eq_ignore_ascii_case("Filter"))
+        && command[6..].trim().bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Keys of the values of a filter
+fn is_key(token: &str) -> bool {
+    ["Fc", "Gain", "Q", "BW"]
+        .iter()
+        .any(|key| token.eq_ignore_ascii_case(key))
+}
+
+/// Parse a number, REW writes decimal commas in some locales.
+fn parse_number(token: &str) -> Option<f32> {
+    token
+        .replace(',', ".")
+        .parse::<f32>()
+        .ok()
+        .filter(|n| n.is_finite())
+}
+
+fn next_number(tokens: &mut Peekable<SplitWhitespace<'_>>) -> Option<f32> {
+    tokens.next().and_then(parse_number)
+}
+
+/// Parse the arguments of a filter: `ON PK Fc 63 Hz Gain -6.5 dB Q 4`
+fn parse_filter(
+    line: usize,
+    text: &str,
+    args: &str,
+    warnings: &mut Vec<EqImportWarning>,
+) -> Result<Option<EqCell>, EqPresetError> {
+    let invalid = || EqPresetError::InvalidFilter {
+        line,
+        text: text.to_owned(),
+    };
+    let mut tokens = args.split_whitespace().peekable();
+    let on = match tokens.next() {
+        Some(state) if state.eq_ignore_ascii_case("ON") => true,
+        Some(state) if state.eq_ignore_ascii_case("OFF") => false,
+        _ => return Err(invalid()),
+    };
+    // the type can have multiple words, e.g. `LS 6dB`
+    let mut kind = vec![];
+    while let Some(token) = tokens.next_if(|t| !is_key(t)) {
+        kind.push(token);
+    }
+    let filter = match kind.first().map(|k| k.to_ascii_uppercase()).as_deref() {
+        Some("PK" | "PEQ" | "MODAL") => EqFilterType::Peak,
+        Some("NO") => EqFilterType::Notch,
+        Some("BP") => EqFilterType::BandPass,
+        Some("LP" | "LPQ") => EqFilterType::LowPass,
+        Some("HP" | "HPQ") => EqFilterType::HighPass,
+        Some("LS" | "LSC") => EqFilterType::LowShelf,
+        Some("HS" | "HSC") => EqFilterType::HighShelf,
+        // an unused filter in REW
+        Some("NONE") => return Ok(None),
+        Some(_) => {
+            warnings.push(EqImportWarning::UnsupportedFilter {
+                line,
+                filter: kind.join(" "),
+            });
+            return Ok(None);
+        }
+        None => return Err(invalid()),
+    };
+
+    let (mut frequency, mut gain, mut q) = (None, 0.0, None);
+    while let Some(key) = tokens.next() {
+        match key.to_ascii_uppercase().as_str() {
+            "FC" => frequency = Some(next_number(&mut tokens).ok_or_else(invalid)?),
+            "GAIN" => gain = next_number(&mut tokens).ok_or_else(invalid)?,
+            "Q" => q = Some(next_number(&mut tokens).ok_or_else(invalid)?),
+            "BW" => {
+                tokens.next_if(|t| t.eq_ignore_ascii_case("Oct"));
+                let octaves = next_number(&mut tokens)
+                    .filter(|n| *n > 0.0)
+                    .ok_or_else(invalid)?;
+                let ratio = 2f32.powf(octaves);
+                q = Some(ratio.sqrt() / (ratio - 1.0));
+            }
+            // units and values Voicemeeter has no use for
+            _ => {}
+        }
+    }
+    let frequency = frequency.ok_or_else(invalid)?;
+
+    let mut clamp = |value: &'static str, from: f32, range: RangeInclusive<f32>| {
+        let to = from.clamp(*range.start(), *range.end());
+        if to != from {
+            warnings.push(EqImportWarning::Clamped {
+                line,
+                value,
+                from,
+                to,
+            });
+        }
+        to
+    };
+    Ok(Some(EqCell {
+        on,
+        filter,
+        frequency: clamp("frequency", frequency, FREQUENCY),
+        gain: clamp("gain", gain, GAIN),
+        q: clamp("Q", q.unwrap_or(*QUALITY.start()), QUALITY),
+    }))
+}
+
+/// Parse the channels of a `Channel` command: `L R`, `1 2` or `all`
+fn parse_channels(
+    line: usize,
+    args: &str,
+    channels: usize,
+    warnings: &mut Vec<EqImportWarning>,
+) -> Vec<usize> {
+    if args.eq_ignore_ascii_case("all") {
+        return (0..channels).collect();
+    }
+    args.split_whitespace()
+        .filter_map(|name| {
+            let channel = match name.to_ascii_uppercase().as_str() {
+                "L" => Some(0),
+                "R" => Some(1),
+                "C" => Some(2),
+                "SUB" => Some(3),
+                "RL" => Some(4),
+                "RR" => Some(5),
+                "SL" => Some(6),
+                "SR" => Some(7),
+                number => number.parse::<usize>().ok().and_then(|n| n.checked_sub(1)),
+            }
+            .filter(|channel| *channel < channels);
+            if channel.is_none() {
+                warnings.push(EqImportWarning::Ignored {
+                    line,
+                    text: format!("Channel: {name}"),
+                });
+            }
+            channel
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn autoeq() -> Result<(), EqPresetError> {
+        let source = "\
+Preamp: -6.2 dB
+Filter 1: ON LSC Fc 105 Hz Gain 6.5 dB Q 0.70
+Filter 2: ON PK Fc 194 Hz Gain -2.8 dB Q 1.35
+Filter 3: ON PK Fc 3140 Hz Gain 4.1 dB Q 2.9
+Filter 4: OFF PK Fc 5120 Hz Gain -3.0 dB Q 4.25
+Filter 5: ON AP Fc 800 Hz Q 1
+Filter 6: ON HP Fc 15 Hz
+Filter 7: ON HSC Fc 10000 Hz Gain -1.6 dB Q 0.70
+Filter 8: ON PK Fc 7000 Hz Gain 2.0 dB Q 3
+";
+        let import = EqProfile::import(source, EqPresetFormat::EqualizerApo, 2)?;
+        assert_eq!(import.preamp, -6.2);
+        assert_eq!(import.profile.channels.len(), 2);
+        let cells = import.profile.channels[1];
+        assert_eq!(
+            cells[0],
+            EqCell {
+                on: true,
+                filter: EqFilterType::LowShelf,
+                frequency: 105.0,
+                gain: 6.5,
+                q: 1.0,
+            }
+        );
+        assert!(!cells[3].on);
+        assert_eq!(cells[4].filter, EqFilterType::HighPass);
+        assert_eq!(cells[4].frequency, 20.0);
+        assert_eq!(cells[5].filter, EqFilterType::HighShelf);
+        assert_eq!(
+            import.warnings,
+            [
+                EqImportWarning::Clamped {
+                    line: 2,
+                    value: "Q",
+                    from: 0.7,
+                    to: 1.0,
+                },
+                EqImportWarning::UnsupportedFilter {
+                    line: 6,
+                    filter: "AP".to_owned(),
+                },
+                EqImportWarning::Clamped {
+                    line: 7,
+                    value: "frequency",
+                    from: 15.0,
+                    to: 20.0,
+                },
+                EqImportWarning::Clamped {
+                    line: 8,
+                    value: "Q",
+                    from: 0.7,
+                    to: 1.0,
+                },
+                EqImportWarning::Truncated {
+                    channel: 1,
+                    dropped: 1,
+                },
+                EqImportWarning::Truncated {
+                    channel: 2,
+                    dropped: 1,
+                },
+            ]
+        );
+        assert!(matches!(
+            EqProfile::import("Filter 1: ON PK Gain 3 dB", EqPresetFormat::EqualizerApo, 2),
+            Err(EqPresetError::InvalidFilter { line: 1, .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn channels_and_rew() -> Result<(), EqPresetError> {
+        let source = "\
+# room correction
+Channel: L
+Filter: ON PK Fc 63 Hz Gain -6 dB Q 4
+Channel: 2 SR
+Filter: ON NO Fc 50 Hz BW Oct 0.1
+Include: headphones.txt
+";
+        let import = EqProfile::import(source, EqPresetFormat::EqualizerApo, 2)?;
+        assert_eq!(import.profile.channels[0][0].frequency, 63.0);
+        assert!(!import.profile.channels[0][1].on);
+        assert_eq!(import.profile.channels[1][0].filter, EqFilterType::Notch);
+        assert!((import.profile.channels[1][0].q - 14.4).abs() < 0.1);
+        assert_eq!(import.warnings.len(), 2);
+        assert_eq!(
+            import.warnings[1].to_string(),
+            "line 6: ignored `Include: headphones.txt`"
+        );
+
+        // different channels are exported in sections
+        let exported = import
+            .profile
+            .export(EqPresetFormat::EqualizerApo, Some(-4.5));
+        assert!(exported
+            .starts_with("Preamp: -4.5 dB\nChannel: 1\nFilter 1: ON PK Fc 63 Hz Gain -6 dB Q 4\n"));
+        let reimported = EqProfile::import(&exported, EqPresetFormat::EqualizerApo, 2)?;
+        assert_eq!(reimported.profile, import.profile);
+        assert_eq!(reimported.preamp, -4.5);
+        assert!(reimported.warnings.is_empty());
+
+        let rew = "\
+Filter Settings file
+
+Room EQ V5.20
+Dated: 18 Oct 2026 12:00:00
+
+Equaliser: Generic
+Filter  1: ON  PK       Fc   63,0 Hz  Gain  -6,5 dB  Q  4,000
+Filter  2: ON  None
+Filter  3: ON  LS 6dB   Fc   80,0 Hz  Gain   3,0 dB
+";
+        let import = EqProfile::import(rew, EqPresetFormat::Rew, 8)?;
+        assert!(import.warnings.is_empty());
+        assert_eq!(import.profile.channels.len(), 8);
+        assert_eq!(import.profile.channels[7][0].gain, -6.5);
+        assert_eq!(import.profile.channels[7][1].filter, EqFilterType::LowShelf);
+        let exported = import.profile.export(EqPresetFormat::Rew, Some(-4.5));
+        assert!(!exported.contains("Preamp"));
+        let reimported = EqProfile::import(&exported, EqPresetFormat::Rew, 8)?;
+        assert_eq!(reimported.profile, import.profile);
+        Ok(())
+    }
+}
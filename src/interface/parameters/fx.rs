@@ -2,6 +2,9 @@
 use super::*;
 
 /// Fx parameters
+///
+/// The send levels of the effects are set on the strips with [`Strip::reverb`] and friends,
+/// and the return levels on the buses with [`Bus::return_reverb`] and friends.
 pub struct VoicemeeterFx<'a> {
     remote: &'a VoicemeeterRemote,
 }
@@ -24,19 +27,104 @@ impl<'a> VoicemeeterFx<'a> {
     }
 
     /// Reverb status
-    pub fn reverb_on(&self) -> BoolParameter {
+    pub fn reverb_on(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Reverb.On"), self.remote)
     }
     /// Reverb AB choice
-    pub fn reverb_ab(&self) -> BoolParameter {
+    pub fn reverb_ab(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Reverb.AB"), self.remote)
     }
+    /// Reverb room size
+    pub fn reverb_size(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.Size"), self.remote, 0.0..=10.0)
+    }
+    /// Reverb damping of high frequencies
+    pub fn reverb_damp(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.Damp"), self.remote, 0.0..=10.0)
+    }
+    /// Reverb diffusion
+    pub fn reverb_diffusion(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.Diffusion"), self.remote, 0.0..=10.0)
+    }
+    /// Reverb bass multiplier
+    pub fn reverb_bass(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.Bass"), self.remote, 0.0..=10.0)
+    }
+    /// Reverb low cut frequency in Hz
+    pub fn reverb_low_cut(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.LoCut"), self.remote, 10.0..=500.0)
+    }
+    /// Reverb high cut frequency in Hz
+    pub fn reverb_high_cut(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.HiCut"), self.remote, 1000.0..=20000.0)
+    }
+    /// Reverb wet level in dB
+    pub fn reverb_wet(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.Wet"), self.remote, -60.0..=0.0)
+    }
+    /// Reverb dry level in dB
+    pub fn reverb_dry(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.Dry"), self.remote, -60.0..=0.0)
+    }
+    /// Reverb pre-delay in ms
+    pub fn reverb_pre_delay(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Reverb.PreDelay"), self.remote, 0.0..=500.0)
+    }
     /// Delay status
-    pub fn delay_on(&self) -> BoolParameter {
+    pub fn delay_on(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Delay.On"), self.remote)
     }
     /// Delay AB choice
-    pub fn delay_ab(&self) -> BoolParameter {
+    pub fn delay_ab(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Delay.AB"), self.remote)
     }
+    /// Delay time in ms
+    pub fn delay_time(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Delay.Time"), self.remote, 0.0..=2000.0)
+    }
+    /// Delay feedback in percent
+    pub fn delay_feedback(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Delay.Feedback"), self.remote, 0.0..=100.0)
+    }
+    /// Delay high pass frequency in Hz
+    pub fn delay_hp(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Delay.HP"), self.remote, 10.0..=1000.0)
+    }
+    /// Delay low pass frequency in Hz
+    pub fn delay_lp(&self) -> FloatParameter<'_> {
+        FloatParameter::new(self.param("Delay.LP"), self.remote, 1000.0..=20000.0)
+    }
+    /// Delay ping-pong between the left and right channel
+    pub fn delay_ping_pong(&self) -> BoolParameter<'_> {
+        BoolParameter::new(self.param("Delay.PingPong"), self.remote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn fx() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, mut remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        let fx = remote.parameters().fx()?;
+
+        fx.reverb_on().set(true)?;
+        assert_eq!(simulator.float("Fx.Reverb.On")?, 1.0);
+        fx.reverb_pre_delay().set(120.0)?;
+        assert_eq!(fx.reverb_pre_delay().get()?, 120.0);
+        assert!(fx.delay_time().set(2500.0).is_err());
+        fx.delay_ping_pong().set(true)?;
+        assert!(fx.delay_ping_pong().get()?);
+
+        simulator.set_program(VoicemeeterApplication::VoicemeeterBanana);
+        remote.update_program()?;
+        assert!(matches!(
+            remote.parameters().fx(),
+            Err(ParameterError::Version(_))
+        ));
+        Ok(())
+    }
 }
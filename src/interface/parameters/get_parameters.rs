@@ -6,18 +6,13 @@
 //! * [`is_parameters_dirty`](VoicemeeterRemote::is_parameters_dirty)
 //! * [`get_parameter_float`](VoicemeeterRemote::get_parameter_float)
 //! * [`get_parameter_string`](VoicemeeterRemote::get_parameter_string)
-use std::{
-    ffi::{CStr, CString, NulError},
-    os::raw::c_char,
-    ptr,
-};
+use std::ffi::{CString, NulError};
 
 use crate::types::ParameterNameRef;
 
 use crate::VoicemeeterRemote;
 
 impl VoicemeeterRemote {
-    // TODO: Only call from one thread, limit it
     /// Check if parameters have changed
     ///
     /// Call this function periodically to check if parameters have changed, typically every 10ms.
@@ -26,8 +21,18 @@ impl VoicemeeterRemote {
     /// # Security
     ///
     /// This method must only be called from one thread.
+    /// While a [`ParameterWatcher`](super::ParameterWatcher) is running, calls from other threads return [`IsParametersDirtyError::PolledByWatcher`].
     pub fn is_parameters_dirty(&self) -> Result<bool, IsParametersDirtyError> {
-        let res = unsafe { self.raw.VBVMR_IsParametersDirty() };
+        if self
+            .login_state
+            .parameter_poller
+            .lock()
+            .unwrap()
+            .is_some_and(|id| id != std::thread::current().id())
+        {
+            return Err(IsParametersDirtyError::PolledByWatcher);
+        }
+        let res = self.backend.is_parameters_dirty();
         match res {
             0 => Ok(false),
             1 => Ok(true),
@@ -43,10 +48,7 @@ impl VoicemeeterRemote {
         let mut f = 0.0f32;
         let param = CString::new(param.as_ref())?;
         tracing::debug!("getting float parameter");
-        let res = unsafe {
-            self.raw
-                .VBVMR_GetParameterFloat(param.as_ptr() as *mut _, &mut f)
-        };
+        let res = self.backend.get_parameter_float(&param, &mut f);
         match res {
             0 => Ok(f),
             -1 => Err(GetParameterError::CannotGetClient),
@@ -68,19 +70,14 @@ impl VoicemeeterRemote {
         &self,
         param: &ParameterNameRef,
     ) -> Result<String, GetParameterError> {
-        let param = CString::new(param.as_ref()).unwrap();
-        let mut output = [0 as c_char; 512];
+        let param = CString::new(param.as_ref())?;
+        let mut output = [0u16; 512];
         tracing::debug!("getting string parameter");
-        let res = unsafe {
-            self.raw
-                .VBVMR_GetParameterStringA(param.as_ptr() as *mut _, ptr::addr_of_mut!(output[0]))
-        };
+        let res = self.backend.get_parameter_string_w(&param, &mut output);
         match res {
             0 => {
-                let output = unsafe { CStr::from_ptr(ptr::addr_of!(output[0])) }
-                    .to_string_lossy()
-                    .into_owned();
-                Ok(output)
+                let len = output.iter().position(|&c| c == 0).unwrap_or(output.len());
+                Ok(String::from_utf16_lossy(&output[..len]))
             }
             -1 => Err(GetParameterError::CannotGetClient),
             -2 => Err(GetParameterError::NoServer),
@@ -89,7 +86,7 @@ impl VoicemeeterRemote {
             )), // NOTE: Lossless always (assuming vmr doesn't modify :) ), unsafe?
             -5 => Err(GetParameterError::StructureMismatch(
                 param.to_string_lossy().into_owned(),
-                "float",
+                "string",
             )),
             s => Err(GetParameterError::Other(s)),
         }
@@ -100,8 +97,8 @@ impl VoicemeeterRemote {
 #[derive(Debug, thiserror::Error, Clone)]
 #[non_exhaustive]
 pub enum GetParameterError {
-    /// Could not make a c-compatible string. This is a bug.
-    #[error("could not make into a c-string")]
+    /// The parameter name contains a nul character.
+    #[error("parameter name contains a nul character")]
     NulError(#[from] NulError),
     /// Unexpected error
     #[error("error (unexpected)")]
@@ -133,4 +130,7 @@ pub enum IsParametersDirtyError {
     /// An unknown error code occured.
     #[error("unexpected error occurred: error code {0}")]
     Other(i32),
+    /// A [`ParameterWatcher`](super::ParameterWatcher) is polling the parameters on another thread.
+    #[error("parameters are polled by a watcher on another thread")]
+    PolledByWatcher,
 }
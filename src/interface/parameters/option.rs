@@ -28,7 +28,7 @@ impl<'a> VoicemeeterOption<'a> {
     /// `96000.0`
     /// `176400.0`
     /// `192000.0`
-    pub fn sr(&self) -> FloatParameter {
+    pub fn sr(&self) -> FloatParameter<'_> {
         // FIXME: Enum parameter
         FloatParameter::new_unranged(self.param("sr"), self.remote)
     }
@@ -37,39 +37,39 @@ impl<'a> VoicemeeterOption<'a> {
     ///   ---  |   ---
     ///  `false` | default ASIO samplerate
     ///  `true`  | preferred samplerate
-    pub fn asio_sr(&self) -> BoolParameter {
+    pub fn asio_sr(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("ASIOsr"), self.remote)
     }
     /// BUS output delay
-    pub fn delay(&self, bus: usize) -> IntParameter {
+    pub fn delay(&self, bus: usize) -> IntParameter<'_> {
         IntParameter::new(self.param(format!("delay[{bus}]")), self.remote, 0..=500)
     }
     /// MME buffer size
-    pub fn buffer_mme(&self) -> IntParameter {
+    pub fn buffer_mme(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("buffer.mme"), self.remote, 128..=2048)
     }
     /// WDM buffer size
-    pub fn buffer_wdm(&self) -> IntParameter {
+    pub fn buffer_wdm(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("buffer.wdm"), self.remote, 128..=2048)
     }
     /// KS buffer size
-    pub fn buffer_ks(&self) -> IntParameter {
+    pub fn buffer_ks(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("buffer.ks"), self.remote, 128..=2048)
     }
     /// ASIO buffer size
-    pub fn buffer_asio(&self) -> IntParameter {
+    pub fn buffer_asio(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("buffer.asio"), self.remote, 128..=2048)
     }
     /// WDM input exclusive
-    pub fn mode_exlusif(&self) -> BoolParameter {
+    pub fn mode_exlusif(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("mode.exclusif"), self.remote)
     }
     /// WDM swift mode
-    pub fn mode_swift(&self) -> BoolParameter {
+    pub fn mode_swift(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("mode.swift"), self.remote)
     }
     /// Option Monitor on SEL
-    pub fn monitor_on_sel(&self) -> BoolParameter {
+    pub fn monitor_on_sel(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("MonitorOnSEL"), self.remote)
     }
 }
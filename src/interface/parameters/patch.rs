@@ -0,0 +1,315 @@
+//! Patch
+use std::ops::Range;
+
+use super::*;
+use errors::*;
+use set_parameters::SetParametersError;
+
+/// Channels of a bus in the output and composite patches.
+const BUS_CHANNELS: usize = 8;
+
+/// Amount of input channels of the strips in `program`, or of the physical strips only.
+///
+/// Physical strips have two channels and virtual strips eight.
+pub(crate) fn input_channels(program: VoicemeeterApplication, physical_only: bool) -> usize {
+    program
+        .devices()
+        .iter()
+        .filter(|d| d.is_strip())
+        .filter(|d| {
+            !physical_only
+                || !matches!(
+                    d,
+                    Device::VirtualInput | Device::VirtualInputAux | Device::VirtualInput8
+                )
+        })
+        .filter_map(|d| d.input(&program))
+        .map(|c| c.size)
+        .sum()
+}
+
+/// Patch parameters for ASIO patching, composite mode and inserts
+///
+/// Channels are numbered over all strips, starting with the two channels of the first strip.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use voicemeeter::VoicemeeterRemote;
+///
+/// # let remote: VoicemeeterRemote = todo!();
+/// let patch = remote.parameters().patch();
+/// // patch ASIO input 3 to the right channel of the first strip
+/// patch.asio(1)?.set(3)?;
+///
+/// // insert all channels of the third strip
+/// let inserts = patch.inserts()?;
+/// let mut matrix = inserts.get()?;
+/// for channel in inserts.channels(2)? {
+///     matrix[channel] = true;
+/// }
+/// inserts.set(&matrix)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct VoicemeeterPatch<'a> {
+    remote: &'a VoicemeeterRemote,
+}
+
+impl<'a> VoicemeeterPatch<'a> {
+    #[doc(hidden)]
+    pub fn new(remote: &'a VoicemeeterRemote) -> Self {
+        VoicemeeterPatch { remote }
+    }
+
+    /// Get the identifier for a patch: `Patch.{dot}`
+    pub fn param(&self, dot: impl Display) -> Cow<'static, ParameterNameRef> {
+        Cow::Owned(format!("{PATCH}.{}", dot).into())
+    }
+
+    fn check_index(&self, name: &str, index: usize, count: usize) -> Result<(), ParameterError> {
+        if index < count {
+            Ok(())
+        } else {
+            Err(OutOfRangeError {
+                name: format!("{PATCH}.{name}"),
+                index: ZIndex(index as i32),
+                program: self.remote.program(),
+            }
+            .into())
+        }
+    }
+
+    /// Amount of input channels in the [ASIO patch](VoicemeeterPatch::asio), two for every physical strip
+    pub fn asio_channels(&self) -> usize {
+        input_channels(self.remote.program(), true)
+    }
+
+    /// ASIO input channel patched to an input channel of the physical strips, when A1 is an ASIO device
+    pub fn asio(&self, channel: usize) -> Result<IntParameter<'_>, ParameterError> {
+        self.check_index("asio", channel, self.asio_channels())?;
+        Ok(IntParameter::new(
+            self.param(format_args!("asio[{channel}]")),
+            self.remote,
+            0..,
+        ))
+    }
+
+    /// ASIO output channel patched to a channel of bus `A2` to `A5`, when A1 is an ASIO device
+    pub fn out(
+        &self,
+        bus: impl BusIndex,
+        channel: usize,
+    ) -> Result<IntParameter<'_>, ParameterError> {
+        let program = self.remote.program();
+        let index = bus.into_bus_index(&program)?;
+        let name = program
+            .devices()
+            .iter()
+            .find_map(|d| match d.as_bus_index(&program) {
+                Some((i, name)) if i.0 == index.0 => Some(name),
+                _ => None,
+            });
+        let Some(name @ ("A2" | "A3" | "A4" | "A5")) = name else {
+            return Err(OutOfRangeError {
+                name: format!("{PATCH}.Out"),
+                index,
+                program,
+            }
+            .into());
+        };
+        self.check_index(&format!("Out{name}"), channel, BUS_CHANNELS)?;
+        Ok(IntParameter::new(
+            self.param(format_args!("Out{name}[{channel}]")),
+            self.remote,
+            0..,
+        ))
+    }
+
+    /// Input channel patched to a channel in composite mode, `0` is the default input
+    pub fn composite(&self, channel: usize) -> Result<IntParameter<'_>, ParameterError> {
+        const VALID: &[VoicemeeterApplication] = &[
+            VoicemeeterApplication::VoicemeeterBanana,
+            VoicemeeterApplication::VoicemeeterPotato,
+            VoicemeeterApplication::PotatoX64Bits,
+        ];
+        let param = self.param(format_args!("composite[{channel}]"));
+        check_version(self.remote, VALID, &param)?;
+        self.check_index("composite", channel, BUS_CHANNELS)?;
+        let inputs = input_channels(self.remote.program(), false) as i32;
+        Ok(IntParameter::new(param, self.remote, 0..=inputs))
+    }
+
+    /// Patch the inputs of composite mode after the faders
+    pub fn post_fader_composite(&self) -> Result<BoolParameter<'_>, ParameterError> {
+        const VALID: &[VoicemeeterApplication] = &[
+            VoicemeeterApplication::VoicemeeterBanana,
+            VoicemeeterApplication::VoicemeeterPotato,
+            VoicemeeterApplication::PotatoX64Bits,
+        ];
+        let param = self.param("PostFaderComposite");
+        check_version(self.remote, VALID, &param)?;
+        Ok(BoolParameter::new(param, self.remote))
+    }
+
+    /// Virtual ASIO insert on an input channel
+    pub fn insert(&self, channel: usize) -> Result<BoolParameter<'_>, ParameterError> {
+        let inserts = self.inserts()?;
+        self.check_index("insert", channel, inserts.len())?;
+        Ok(BoolParameter::new(inserts.param(channel), self.remote))
+    }
+
+    /// Virtual ASIO inserts of all input channels, see [`VoicemeeterInserts`]
+    pub fn inserts(&self) -> Result<VoicemeeterInserts<'a>, ParameterError> {
+        const VALID: &[VoicemeeterApplication] = &[
+            VoicemeeterApplication::VoicemeeterBanana,
+            VoicemeeterApplication::VoicemeeterPotato,
+            VoicemeeterApplication::PotatoX64Bits,
+        ];
+        check_version(self.remote, VALID, &self.param("insert"))?;
+        Ok(VoicemeeterInserts {
+            remote: self.remote,
+        })
+    }
+
+    /// Insert after the effects
+    pub fn post_fx_insert(&self) -> Result<BoolParameter<'_>, ParameterError> {
+        const VALID: &[VoicemeeterApplication] = &[
+            VoicemeeterApplication::VoicemeeterPotato,
+            VoicemeeterApplication::PotatoX64Bits,
+        ];
+        let param = self.param("PostFxInsert");
+        check_version(self.remote, VALID, &param)?;
+        Ok(BoolParameter::new(param, self.remote))
+    }
+}
+
+/// The virtual ASIO inserts of all input channels: `Patch.insert[k]`
+///
+/// The matrix has one value for every input channel, use [`channels`](VoicemeeterInserts::channels) to find the channels of a strip.
+pub struct VoicemeeterInserts<'a> {
+    remote: &'a VoicemeeterRemote,
+}
+
+impl<'a> VoicemeeterInserts<'a> {
+    fn param(&self, channel: usize) -> Cow<'static, ParameterNameRef> {
+        Cow::Owned(format!("{PATCH}.insert[{channel}]").into())
+    }
+
+    /// Amount of input channels
+    pub fn len(&self) -> usize {
+        input_channels(self.remote.program(), false)
+    }
+
+    /// Returns `true` if there are no input channels
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The input channels of a strip
+    pub fn channels(&self, strip: impl StripIndex) -> Result<Range<usize>, ParameterError> {
+        let program = self.remote.program();
+        let index = strip.into_strip_index(&program)?;
+        let mut start = 0;
+        for device in program.devices().iter().filter(|d| d.is_strip()) {
+            let size = device.input(&program).map_or(0, |c| c.size);
+            if device
+                .as_strip_index(&program)
+                .is_some_and(|i| i.0 == index.0)
+            {
+                return Ok(start..start + size);
+            }
+            start += size;
+        }
+        Err(OutOfRangeError {
+            name: STRIP.to_owned(),
+            index,
+            program,
+        }
+        .into())
+    }
+
+    /// Get the inserts of all input channels
+    pub fn get(&self) -> Result<Vec<bool>, GetParameterError> {
+        (0..self.len())
+            .map(|channel| Ok(self.remote.get_parameter_float(&self.param(channel))? == 1.0))
+            .collect()
+    }
+
+    /// Set the inserts of the first `inserts.len()` input channels with a single script
+    ///
+    /// More inserts than [input channels](Self::len) are rejected with [`SetParameterError::TooManyChannels`].
+    pub fn set(&self, inserts: &[bool]) -> Result<(), SetParametersError> {
+        if inserts.len() > self.len() {
+            return Err(SetParameterError::TooManyChannels {
+                name: format!("{PATCH}.insert"),
+                channels: inserts.len(),
+                max: self.len(),
+            }
+            .into());
+        }
+        let script: String = inserts
+            .iter()
+            .enumerate()
+            .map(|(channel, insert)| format!("{} = {};\n", self.param(channel), u8::from(*insert)))
+            .collect();
+        if script.is_empty() {
+            return Ok(());
+        }
+        self.remote.set_parameters(&script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn patch() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, voicemeeter) = remote(VoicemeeterApplication::Voicemeeter);
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let patch = remote.parameters().patch();
+
+        assert_eq!(patch.asio_channels(), 6);
+        patch.asio(5)?.set(3)?;
+        assert_eq!(simulator.float("Patch.asio[5]")?, 3.0);
+        assert!(matches!(patch.asio(6), Err(ParameterError::OutOfRange(_))));
+        patch.out(Device::OutputA3, 7)?.set(2)?;
+        assert_eq!(simulator.float("Patch.OutA3[7]")?, 2.0);
+        assert!(patch.out(Device::OutputA1, 0).is_err());
+        assert!(patch.out(3, 0).is_err());
+        assert!(matches!(
+            patch.post_fx_insert(),
+            Err(ParameterError::Version(_))
+        ));
+        assert!(patch.composite(7)?.set(23).is_err());
+
+        let inserts = patch.inserts()?;
+        assert_eq!(inserts.len(), 22);
+        assert_eq!(inserts.channels(2)?, 4..6);
+        assert_eq!(inserts.channels(Device::VirtualInputAux)?, 14..22);
+        let mut matrix = inserts.get()?;
+        for channel in inserts.channels(3)? {
+            matrix[channel] = true;
+        }
+        inserts.set(&matrix)?;
+        assert!(patch.insert(13)?.get()?);
+        assert!(!patch.insert(14)?.get()?);
+        assert_eq!(inserts.get()?, matrix);
+        assert!(matches!(
+            inserts.set(&[false; 23]),
+            Err(SetParametersError::SetParameterError(SetParameterError::TooManyChannels {
+                name,
+                channels: 23,
+                max: 22,
+            })) if name == "Patch.insert"
+        ));
+
+        assert!(matches!(
+            voicemeeter.parameters().patch().inserts(),
+            Err(ParameterError::Version(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,520 @@
+//! Smooth ramps of float parameters.
+//!
+//! A [`RampEngine`] moves any writable [`FloatParameter`] from its current value to a target over a duration,
+//! following a [`Curve`]. The ramps run on a background thread, every tick the new values of all running ramps
+//! are sent with a single [`set_parameters`](VoicemeeterRemote::set_parameters) call.
+//!
+//! Starting a ramp on a parameter that is already ramping retargets it, the new ramp starts from the current value of the old one.
+//! If Voicemeeter can not be reached, e.g. because it was closed, the ramps that could not be sent are cancelled.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use voicemeeter::{interface::parameters::ramp::Curve, VoicemeeterRemote};
+//!
+//! let remote = VoicemeeterRemote::new()?;
+//! let ramps = remote.ramps()?;
+//! let strip = remote.parameters().strip(0)?;
+//!
+//! ramps.ramp(&strip.gain(), -20.0, Duration::from_secs(2), Curve::SCurve)?;
+//! ramps.ramp(&strip.pan_x(), 0.5, Duration::from_millis(500), Curve::Linear)?;
+//! ramps.wait();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    types::{ParameterName, ParameterNameRef},
+    VoicemeeterRemote,
+};
+
+use super::{
+    get_parameters::GetParameterError,
+    set_parameters::{SetParameterError, SetParametersError},
+    snapshot::script_statement,
+    FloatParameter, ParameterValue,
+};
+
+/// Interval between updates of running ramps.
+pub const DEFAULT_RAMP_INTERVAL: Duration = Duration::from_millis(20);
+
+impl VoicemeeterRemote {
+    /// Start a [`RampEngine`], updating ramps every [`DEFAULT_RAMP_INTERVAL`].
+    pub fn ramps(&self) -> Result<RampEngine, RampError> {
+        self.ramps_every(DEFAULT_RAMP_INTERVAL)
+    }
+
+    /// Start a [`RampEngine`], updating ramps every `interval`.
+    pub fn ramps_every(&self, interval: Duration) -> Result<RampEngine, RampError> {
+        let shared = Arc::new(Shared::default());
+        let thread = std::thread::Builder::new()
+            .name("voicemeeter-ramps".to_owned())
+            .spawn({
+                let remote = self.clone();
+                let shared = shared.clone();
+                move || run(remote, shared, interval)
+            })
+            .map_err(RampError::Spawn)?;
+        Ok(RampEngine {
+            remote: self.clone(),
+            shared,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// The shape of a ramp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Curve {
+    /// Move at a constant speed.
+    #[default]
+    Linear,
+    /// Start fast and slow down towards the target, like an exponential decay.
+    Exponential,
+    /// Start and end slowly, see [smoothstep](https://en.wikipedia.org/wiki/Smoothstep).
+    SCurve,
+}
+
+impl Curve {
+    /// The progress towards the target after `t` of the duration, both in `0.0..=1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Curve::Linear => t,
+            Curve::Exponential => (1.0 - (-5.0 * t).exp()) / (1.0 - (-5.0f32).exp()),
+            Curve::SCurve => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// How a parameter moves during a ramp.
+#[derive(Debug, Clone)]
+pub(crate) enum Motion {
+    /// Interpolate a float along a curve.
+    Curve { from: f32, to: f32, curve: Curve },
+    /// Switch to a value after a fraction of the duration.
+    Switch { to: ParameterValue, at: f32 },
+}
+
+#[derive(Debug, Clone)]
+struct Ramp {
+    motion: Motion,
+    start: Instant,
+    duration: Duration,
+    /// The last value sent to Voicemeeter.
+    sent: Option<ParameterValue>,
+}
+
+impl Ramp {
+    /// The value at `now`, or `None` if a switch has not happened yet.
+    fn value_at(&self, now: Instant) -> Option<ParameterValue> {
+        let t = if self.is_done(now) {
+            1.0
+        } else {
+            now.duration_since(self.start).as_secs_f32() / self.duration.as_secs_f32()
+        };
+        match &self.motion {
+            Motion::Curve { to, .. } if t >= 1.0 => Some(ParameterValue::Float(*to)),
+            Motion::Curve { from, to, curve } => {
+                Some(ParameterValue::Float(from + (to - from) * curve.apply(t)))
+            }
+            Motion::Switch { to, at } if t >= *at => Some(to.clone()),
+            Motion::Switch { .. } => None,
+        }
+    }
+
+    fn is_done(&self, now: Instant) -> bool {
+        now.duration_since(self.start) >= self.duration
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    stop: AtomicBool,
+    /// `true` while sending fails, to only warn once.
+    failing: AtomicBool,
+    ramps: Mutex<HashMap<ParameterName, Ramp>>,
+    /// Notified when the last ramp is done.
+    idle: Condvar,
+}
+
+/// Runs ramps on a background thread, see the [module documentation](self).
+///
+/// The thread is stopped when the engine is dropped, running ramps stop where they are.
+pub struct RampEngine {
+    remote: VoicemeeterRemote,
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for RampEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RampEngine")
+            .field("remote", &self.remote)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RampEngine {
+    /// Ramp `parameter` from its current value to `target` over `duration`.
+    ///
+    /// The target is checked against the [range](FloatParameter::range) of the parameter, or clamped if the parameter is [clamped](FloatParameter::clamped).
+    /// If the parameter is already ramping, the new ramp starts from the current value of that ramp.
+    /// Write-only parameters start from their [last set value](VoicemeeterRemote::last_set_value).
+    pub fn ramp<const READ: bool>(
+        &self,
+        parameter: &FloatParameter<'_, true, READ>,
+        target: f32,
+        duration: Duration,
+        curve: Curve,
+    ) -> Result<(), RampError> {
+        let to = parameter.checked(target)?;
+        let from = match self.current(&parameter.name) {
+            Some(from) => from,
+            None if READ => parameter.remote.get_parameter_float(&parameter.name)?,
+            None => parameter
+                .remote
+                .last_set_value(&parameter.name)
+                .and_then(|v| v.as_f32())
+                .ok_or_else(|| RampError::UnknownStart(parameter.name.to_string()))?,
+        };
+        self.start(
+            [(
+                parameter.name.clone().into_owned(),
+                Motion::Curve { from, to, curve },
+            )],
+            duration,
+        );
+        Ok(())
+    }
+
+    pub(crate) fn remote(&self) -> &VoicemeeterRemote {
+        &self.remote
+    }
+
+    /// The current value of a running float ramp.
+    pub(crate) fn current(&self, name: &ParameterNameRef) -> Option<f32> {
+        self.shared
+            .ramps
+            .lock()
+            .unwrap()
+            .get(name)?
+            .value_at(Instant::now())?
+            .as_f32()
+    }
+
+    /// Start ramps that all take `duration`, replacing running ramps of the same parameters.
+    pub(crate) fn start(
+        &self,
+        motions: impl IntoIterator<Item = (ParameterName, Motion)>,
+        duration: Duration,
+    ) {
+        let start = Instant::now();
+        let mut ramps = self.shared.ramps.lock().unwrap();
+        for (name, motion) in motions {
+            ramps.insert(
+                name,
+                Ramp {
+                    motion,
+                    start,
+                    duration,
+                    sent: None,
+                },
+            );
+        }
+    }
+
+    /// Stop the ramp of a parameter where it is. Returns `false` if the parameter was not ramping.
+    pub fn cancel(&self, name: &ParameterNameRef) -> bool {
+        let mut ramps = self.shared.ramps.lock().unwrap();
+        let cancelled = ramps.remove(name).is_some();
+        if ramps.is_empty() {
+            self.shared.idle.notify_all();
+        }
+        cancelled
+    }
+
+    /// Stop all ramps where they are.
+    pub fn cancel_all(&self) {
+        self.shared.ramps.lock().unwrap().clear();
+        self.shared.idle.notify_all();
+    }
+
+    /// Returns `true` if the parameter is ramping.
+    pub fn is_ramping(&self, name: &ParameterNameRef) -> bool {
+        self.shared.ramps.lock().unwrap().contains_key(name)
+    }
+
+    /// Block until all ramps are done or cancelled.
+    pub fn wait(&self) {
+        let ramps = self.shared.ramps.lock().unwrap();
+        let _ramps = self
+            .shared
+            .idle
+            .wait_while(ramps, |ramps| {
+                !ramps.is_empty() && !self.shared.stop.load(Ordering::Relaxed)
+            })
+            .unwrap();
+    }
+}
+
+impl Drop for RampEngine {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn run(remote: VoicemeeterRemote, shared: Arc<Shared>, interval: Duration) {
+    while !shared.stop.load(Ordering::Relaxed) {
+        tick(&remote, &shared, Instant::now());
+        std::thread::sleep(interval);
+    }
+    // wake up waiting threads, the ramps will not finish
+    let _ramps = shared.ramps.lock().unwrap();
+    shared.idle.notify_all();
+}
+
+/// Send the values of all running ramps at `now` in one script, and remove the finished ramps.
+fn tick(remote: &VoicemeeterRemote, shared: &Shared, now: Instant) {
+    // the ramps are identified by their start, `ramp` may replace them while the script is sent
+    let mut sending = vec![];
+    let mut rejected = vec![];
+    let mut script = String::new();
+    {
+        let ramps = shared.ramps.lock().unwrap();
+        if ramps.is_empty() {
+            return;
+        }
+        for (name, ramp) in ramps.iter() {
+            let Some(value) = ramp.value_at(now) else {
+                continue;
+            };
+            if ramp.sent.as_ref() != Some(&value) {
+                match script_statement(name, &value) {
+                    Ok(statement) => {
+                        let _ = writeln!(script, "{statement}");
+                        sending.push((name.clone(), ramp.start, value));
+                    }
+                    // can not be written in a script
+                    Err(_) => rejected.push((name.clone(), ramp.start)),
+                }
+            }
+        }
+    }
+
+    let mut cancel = false;
+    let sent = if script.is_empty() {
+        0
+    } else {
+        match remote.set_parameters(&script) {
+            Ok(()) => {
+                shared.failing.store(false, Ordering::Relaxed);
+                sending.len()
+            }
+            // lines before the failing line have been applied, drop the failing ramp
+            Err(SetParametersError::ScriptError(line)) => {
+                shared.failing.store(false, Ordering::Relaxed);
+                let failed = line.saturating_sub(1).min(sending.len() - 1);
+                let (name, start, _) = &sending[failed];
+                rejected.push((name.clone(), *start));
+                failed
+            }
+            // nothing has been applied, cancel the ramps instead of retrying every tick
+            Err(e) => {
+                if !shared.failing.swap(true, Ordering::Relaxed) {
+                    tracing::warn!("could not update ramps, cancelling them: {e}");
+                }
+                cancel = true;
+                0
+            }
+        }
+    };
+
+    // finished ramps are removed after their last value has been sent, so `wait` returns after that
+    let mut ramps = shared.ramps.lock().unwrap();
+    if cancel {
+        rejected.extend(sending.drain(..).map(|(name, start, _)| (name, start)));
+    }
+    for (name, start, value) in sending.into_iter().take(sent) {
+        if let Some(ramp) = ramps.get_mut(&name).filter(|ramp| ramp.start == start) {
+            ramp.sent = Some(value);
+        }
+    }
+    for (name, start) in rejected {
+        if ramps.get(&name).is_some_and(|ramp| ramp.start == start) {
+            if !cancel {
+                tracing::warn!(%name, "ramp rejected by voicemeeter, cancelling it");
+            }
+            ramps.remove(&name);
+        }
+    }
+    ramps.retain(|_, ramp| !(ramp.is_done(now) && ramp.sent == ramp.value_at(now)));
+    if ramps.is_empty() {
+        shared.idle.notify_all();
+    }
+}
+
+/// Errors that can happen when starting a [`RampEngine`] or a ramp.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RampError {
+    /// Could not spawn the thread.
+    #[error("could not spawn the ramp thread")]
+    Spawn(#[source] std::io::Error),
+    /// The target is not valid for the parameter.
+    #[error(transparent)]
+    Target(#[from] SetParameterError),
+    /// Could not read the current value of the parameter.
+    #[error(transparent)]
+    Start(#[from] GetParameterError),
+    /// The write-only parameter has not been set, so the ramp has no start value.
+    #[error("the value of write-only parameter `{0}` is not known")]
+    UnknownStart(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn curves() {
+        for curve in [Curve::Linear, Curve::Exponential, Curve::SCurve] {
+            assert_eq!(curve.apply(0.0), 0.0);
+            assert!((curve.apply(1.0) - 1.0).abs() < 1e-6);
+            assert!(curve.apply(0.25) < curve.apply(0.75));
+        }
+        assert_eq!(Curve::SCurve.apply(0.5), 0.5);
+        assert!(Curve::Exponential.apply(0.5) > 0.5);
+    }
+
+    #[test]
+    fn rejected_ramp() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let shared = Shared::default();
+        let start = Instant::now();
+        for (name, to) in [
+            ("Strip[0].Gain", -6.0),
+            ("Strip[42].Gain", -6.0),
+            ("Strip[1].Gain", -3.0),
+        ] {
+            shared.ramps.lock().unwrap().insert(
+                name.into(),
+                Ramp {
+                    motion: Motion::Switch {
+                        to: ParameterValue::Float(to),
+                        at: 0.0,
+                    },
+                    start,
+                    duration: Duration::ZERO,
+                    sent: None,
+                },
+            );
+        }
+
+        // the ramps after the rejected one are sent again
+        tick(&remote, &shared, start);
+        tick(&remote, &shared, start);
+        assert!(shared.ramps.lock().unwrap().is_empty());
+        assert_eq!(simulator.float("Strip[0].Gain")?, -6.0);
+        assert_eq!(simulator.float("Strip[1].Gain")?, -3.0);
+        Ok(())
+    }
+
+    #[test]
+    fn closed_voicemeeter() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let ramps = remote.ramps_every(Duration::from_millis(1))?;
+        let strip = remote.parameters().strip(0)?;
+
+        ramps.ramp(&strip.gain(), -60.0, Duration::from_secs(60), Curve::Linear)?;
+        std::thread::sleep(Duration::from_millis(10));
+        simulator.set_program(VoicemeeterApplication::None);
+        let (done, waited) = std::sync::mpsc::channel();
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                ramps.wait();
+                let _ = done.send(());
+            });
+            let waited = waited.recv_timeout(Duration::from_secs(5));
+            ramps.cancel_all();
+            assert!(waited.is_ok(), "wait did not return");
+        });
+        assert!(!ramps.is_ramping(&strip.gain().name));
+        Ok(())
+    }
+
+    #[test]
+    fn ramps() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let ramps = remote.ramps_every(Duration::from_millis(1))?;
+        let strip = remote.parameters().strip(0)?;
+
+        ramps.ramp(
+            &strip.gain(),
+            -10.0,
+            Duration::from_millis(30),
+            Curve::SCurve,
+        )?;
+        ramps.ramp(&strip.pan_x(), 0.5, Duration::ZERO, Curve::Linear)?;
+        assert!(ramps.is_ramping(&strip.gain().name));
+        ramps.wait();
+        assert_eq!(simulator.float("Strip[0].Gain")?, -10.0);
+        assert_eq!(simulator.float("Strip[0].Pan_x")?, 0.5);
+        assert!(!ramps.is_ramping(&strip.gain().name));
+
+        // retarget a slow ramp
+        ramps.ramp(&strip.gain(), -60.0, Duration::from_secs(60), Curve::Linear)?;
+        std::thread::sleep(Duration::from_millis(10));
+        ramps.ramp(
+            &strip.gain(),
+            6.0,
+            Duration::from_millis(20),
+            Curve::Exponential,
+        )?;
+        ramps.wait();
+        assert_eq!(simulator.float("Strip[0].Gain")?, 6.0);
+
+        ramps.ramp(&strip.gain(), -60.0, Duration::from_secs(60), Curve::Linear)?;
+        // wait for the ramp to move
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while simulator.float("Strip[0].Gain")? == 6.0 && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(ramps.cancel(&strip.gain().name));
+        ramps.wait();
+        let stopped = simulator.float("Strip[0].Gain")?;
+        assert!(stopped < 6.0 && stopped > -60.0, "{stopped}");
+
+        assert!(matches!(
+            ramps.ramp(&strip.gain(), 20.0, Duration::ZERO, Curve::Linear),
+            Err(RampError::Target(SetParameterError::OutOfRange { .. }))
+        ));
+        assert!(matches!(
+            ramps.ramp(
+                &strip.app_gain_indexed(crate::types::ZIndex(0)),
+                1.0,
+                Duration::ZERO,
+                Curve::Linear
+            ),
+            Err(RampError::UnknownStart(_))
+        ));
+        Ok(())
+    }
+}
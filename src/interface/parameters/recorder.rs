@@ -18,32 +18,32 @@ impl<'a> VoicemeeterRecorder<'a> {
         Cow::Owned(format!("{RECORDER}.{}", dot).into())
     }
     /// Stop the recorder
-    pub fn stop(&self) -> BoolParameter {
+    pub fn stop(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("stop"), self.remote)
     }
     /// Start the recorder
-    pub fn play(&self) -> BoolParameter {
+    pub fn play(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("play"), self.remote)
     }
     /// Play from position
-    pub fn replay(&self) -> BoolParameter {
+    pub fn replay(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("replay"), self.remote)
     }
     /// Fast forward
-    pub fn ff(&self) -> BoolParameter {
+    pub fn ff(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("ff"), self.remote)
     }
     /// Rewind
-    pub fn rew(&self) -> BoolParameter {
+    pub fn rew(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("rew"), self.remote)
     }
     /// Goto position
-    pub fn goto(&self) -> StringParameter {
+    pub fn goto(&self) -> StringParameter<'_> {
         StringParameter::new(self.param("goto"), self.remote)
     }
 
     /// Set the assignation of the recorder
-    pub fn out_bus_assignation(&self, bus: &Device) -> Result<BoolParameter, ParameterError> {
+    pub fn out_bus_assignation(&self, bus: &Device) -> Result<BoolParameter<'_>, ParameterError> {
         if !bus.is_bus() {
             return Err(InvalidTypeError::ExpectedBus {
                 device: format!("{:?}", bus),
@@ -52,9 +52,9 @@ impl<'a> VoicemeeterRecorder<'a> {
         }
         Ok(BoolParameter::new(
             self.param(
-                bus.as_bus_index(&self.remote.program)
+                bus.as_bus_index(&self.remote.program())
                     .ok_or(DeviceError {
-                        program: self.remote.program,
+                        program: self.remote.program(),
                         device: *bus,
                     })?
                     .1,
@@ -64,12 +64,12 @@ impl<'a> VoicemeeterRecorder<'a> {
     }
 
     /// Record
-    pub fn record(&self) -> BoolParameter {
+    pub fn record(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("record"), self.remote)
     }
 
     /// Pause
-    pub fn pause(&self) -> BoolParameter {
+    pub fn pause(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("pause"), self.remote)
     }
 
@@ -79,27 +79,27 @@ impl<'a> VoicemeeterRecorder<'a> {
     }
 
     /// Set samplerate
-    pub fn samplerate(&self) -> IntParameter {
-        IntParameter::new(self.param("samplerate"), self.remote, 0..=2)
+    pub fn samplerate(&self) -> IntParameter<'_> {
+        IntParameter::new(self.param("samplerate"), self.remote, 22050..=96000)
     }
 
     /// Arm a strip to use as pre-fader input (multiple)
-    pub fn arm_strip(&self, strip: impl StripIndex) -> Result<BoolParameter, ParameterError> {
+    pub fn arm_strip(&self, strip: impl StripIndex) -> Result<BoolParameter<'_>, ParameterError> {
         Ok(BoolParameter::new(
             self.param(format_args!(
                 "ArmStrip({})",
-                strip.into_strip_index(&self.remote.program)?
+                strip.into_strip_index(&self.remote.program())?
             )),
             self.remote,
         ))
     }
 
     /// Arm a bus to use as post-fader output (single)
-    pub fn arm_bus(&self, bus: impl BusIndex) -> Result<BoolParameter, ParameterError> {
+    pub fn arm_bus(&self, bus: impl BusIndex) -> Result<BoolParameter<'_>, ParameterError> {
         Ok(BoolParameter::new(
             self.param(format_args!(
                 "ArmBus({})",
-                bus.into_bus_index(&self.remote.program)?
+                bus.into_bus_index(&self.remote.program())?
             )),
             self.remote,
         ))
@@ -111,17 +111,17 @@ impl<'a> VoicemeeterRecorder<'a> {
     }
 
     /// Set the bit resolution. On of `8`, `16`, `24`, `32`
-    pub fn bit_resolution(&self) -> IntParameter {
+    pub fn bit_resolution(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("bitResolution"), self.remote, 8..=32)
     }
 
     /// Channels to use for recording post-fader outputs, `2`, `4`, `6`, `8`
-    pub fn channel(&self) -> IntParameter {
+    pub fn channel(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("Channel"), self.remote, 1..=8)
     }
 
     /// Set the bitrate for the recording of mp3
-    pub fn kbps(&self) -> IntParameter {
+    pub fn kbps(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("kbps"), self.remote, 32..=320)
     }
 
@@ -133,12 +133,12 @@ impl<'a> VoicemeeterRecorder<'a> {
     /// |2  | AIFF|
     /// |3 | BWF|
     /// |100 | MP3|
-    pub fn file_type(&self) -> IntParameter {
+    pub fn file_type(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("FileType"), self.remote, 1..=100)
     }
 
     /// Set playback gain
-    pub fn gain(&self) -> FloatParameter {
+    pub fn gain(&self) -> FloatParameter<'_> {
         FloatParameter::new(self.param("gain"), self.remote, -60.0..=12.0)
     }
 }
@@ -159,22 +159,22 @@ impl<'a> VoicemeeterRecorderMode<'a> {
     }
 
     /// Record bus
-    pub fn recbus(&self) -> BoolParameter {
+    pub fn recbus(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("recbus"), self.remote)
     }
 
     /// Play on load
-    pub fn play_on_load(&self) -> BoolParameter {
+    pub fn play_on_load(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("PlayOnLoad"), self.remote)
     }
 
     /// Loop
-    pub fn loop_(&self) -> BoolParameter {
+    pub fn loop_(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Loop"), self.remote)
     }
 
     /// MultiTrack
-    pub fn multi_track(&self) -> BoolParameter {
+    pub fn multi_track(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("MultiTrack"), self.remote)
     }
 }
@@ -0,0 +1,1165 @@
+//! Registry of all known parameters.
+//!
+//! Every parameter that can be accessed through [`Parameters`](super::Parameters) is described by a [`ParameterInfo`],
+//! with its value kind, range, unit, access and the programs supporting it.
+//!
+//! # Functions
+//!
+//! * [`parameters`]
+//! * [`lookup`]
+//!
+//! # Templates
+//!
+//! Indexes in the [name template](ParameterInfo::name) are written as `[x]` or `(x)`, where `x` is
+//!
+//! * `i` - the strip index in `Strip[i]`, the bus index in `Bus[i]` and the strip index in `Recorder.ArmStrip(i)`
+//! * `b` - a bus index
+//! * `c` - an EQ channel
+//! * `k` - an EQ cell
+//! * `s` - a VBAN stream
+//! * `a` - an application index
+//! * `m` - a macro button
+//! * `p` - an input channel of the physical strips
+//! * `n` - an input channel
+//! * `o` - a channel of a bus
+//!
+//! # Examples
+//!
+//! ```rust
+//! use voicemeeter::interface::parameters::registry::{self, ParameterKind};
+//! use voicemeeter::types::VoicemeeterApplication;
+//!
+//! let gain = registry::lookup("Strip[0].Gain").unwrap();
+//! assert_eq!(gain.kind, ParameterKind::Float);
+//! assert_eq!(gain.range, Some(-60.0..=12.0));
+//! assert_eq!(gain.unit, Some("dB"));
+//!
+//! let names = gain.names(VoicemeeterApplication::VoicemeeterBanana);
+//! assert_eq!(names.len(), 5);
+//! assert_eq!(names[4].as_str(), "Strip[4].Gain");
+//! ```
+use std::ops::RangeInclusive;
+
+use super::eq::{BUS_EQ_CHANNELS, EQ_CELLS};
+use crate::types::{Device, ParameterName, VoicemeeterApplication};
+
+use VoicemeeterApplication::{PotatoX64Bits, Voicemeeter, VoicemeeterBanana, VoicemeeterPotato};
+
+/// The kind of value of a parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ParameterKind {
+    /// A float parameter that is `0` or `1`, see [`BoolParameter`](super::BoolParameter).
+    Bool,
+    /// A float parameter with integer values, see [`IntParameter`](super::IntParameter).
+    Int,
+    /// A float parameter, see [`FloatParameter`](super::FloatParameter).
+    Float,
+    /// A string parameter, see [`StringParameter`](super::StringParameter).
+    String,
+    /// A string parameter holding a tuple, see [`TupleParameter`](super::TupleParameter).
+    Tuple,
+}
+
+/// Which strips or buses a parameter applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Scope {
+    /// All strips or buses, or the parameter is not on a strip or bus.
+    All,
+    /// Only physical strips or buses (`A` buses).
+    Physical,
+    /// Only virtual strips or buses (`B` buses).
+    Virtual,
+}
+
+/// Description of a parameter, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ParameterInfo {
+    /// Template of the parameter name, e.g. `Strip[i].Gain`.
+    pub name: &'static str,
+    /// Kind of value.
+    pub kind: ParameterKind,
+    /// Range of valid values for [int](ParameterKind::Int) and [float](ParameterKind::Float) parameters, if known.
+    pub range: Option<RangeInclusive<f32>>,
+    /// Unit of the value, e.g. `dB`, `Hz` or `ms`.
+    pub unit: Option<&'static str>,
+    /// `true` if the parameter can be read.
+    pub read: bool,
+    /// `true` if the parameter can be written.
+    pub write: bool,
+    /// Programs supporting this parameter.
+    pub programs: &'static [VoicemeeterApplication],
+    /// Strips or buses supporting this parameter.
+    pub scope: Scope,
+    /// Short description.
+    pub description: &'static str,
+}
+
+const ALL: &[VoicemeeterApplication] = &[
+    Voicemeeter,
+    VoicemeeterBanana,
+    VoicemeeterPotato,
+    PotatoX64Bits,
+];
+const BANANA: &[VoicemeeterApplication] = &[VoicemeeterBanana, VoicemeeterPotato, PotatoX64Bits];
+const POTATO: &[VoicemeeterApplication] = &[VoicemeeterPotato, PotatoX64Bits];
+
+impl ParameterInfo {
+    const fn new(name: &'static str, kind: ParameterKind, description: &'static str) -> Self {
+        Self {
+            name,
+            kind,
+            range: None,
+            unit: None,
+            read: true,
+            write: true,
+            programs: ALL,
+            scope: Scope::All,
+            description,
+        }
+    }
+
+    const fn range(self, min: f32, max: f32) -> Self {
+        Self {
+            range: Some(RangeInclusive::new(min, max)),
+            ..self
+        }
+    }
+
+    const fn unit(self, unit: &'static str) -> Self {
+        Self {
+            unit: Some(unit),
+            ..self
+        }
+    }
+
+    const fn read_only(self) -> Self {
+        Self {
+            write: false,
+            ..self
+        }
+    }
+
+    const fn write_only(self) -> Self {
+        Self {
+            read: false,
+            ..self
+        }
+    }
+
+    const fn programs(self, programs: &'static [VoicemeeterApplication]) -> Self {
+        Self { programs, ..self }
+    }
+
+    const fn scope(self, scope: Scope) -> Self {
+        Self { scope, ..self }
+    }
+
+    /// Returns `true` if `program` supports this parameter.
+    pub fn supports(&self, program: VoicemeeterApplication) -> bool {
+        self.programs.contains(&program)
+    }
+
+    /// All names of this parameter in `program`, with the indexes filled in.
+    ///
+    /// Returns nothing if the program does not support the parameter, or if an index can not be enumerated,
+    /// like the application index of `Strip[i].App[a].Gain`.
+    pub fn names(&self, program: VoicemeeterApplication) -> Vec<ParameterName> {
+        let mut names = vec![];
+        if !self.supports(program) {
+            return names;
+        }
+        let devices = program.devices();
+        let strips = devices.iter().filter(|d| d.is_strip()).count();
+        let physical_strips = devices
+            .iter()
+            .filter(|d| d.is_strip() && !is_virtual(d))
+            .count();
+        let buses: Vec<_> = devices
+            .iter()
+            .filter_map(|d| d.as_bus_index(&program))
+            .collect();
+        let streams = if program == Voicemeeter { 4 } else { 8 };
+        let count = |placeholder: u8, channels: usize| match placeholder {
+            b'i' if self.scope == Scope::Physical => Some(physical_strips),
+            b'i' => Some(strips),
+            b'b' => Some(buses.len()),
+            b'c' => Some(channels),
+            b'k' => Some(EQ_CELLS),
+            b's' => Some(streams),
+            b'm' => Some(super::command::MACRO_BUTTONS as usize),
+            b'p' => Some(super::patch::input_channels(program, true)),
+            b'n' => Some(super::patch::input_channels(program, false)),
+            b'o' => Some(BUS_EQ_CHANNELS),
+            _ => None,
+        };
+        let in_scope = |physical: bool| match self.scope {
+            Scope::All => true,
+            Scope::Physical => physical,
+            Scope::Virtual => !physical,
+        };
+
+        if let Some(rest) = self.name.strip_prefix("Strip[i]") {
+            for device in devices {
+                let Some(index) = device.as_strip_index(&program) else {
+                    continue;
+                };
+                if in_scope(!is_virtual(device)) {
+                    let channels = device.input(&program).map_or(0, |c| c.size);
+                    let prefix = format!("{}[{index}]", super::STRIP);
+                    expand(prefix, rest, &|p| count(p, channels), &mut names);
+                }
+            }
+        } else if let Some(rest) = self.name.strip_prefix("Bus[i]") {
+            for (index, name) in &buses {
+                if in_scope(name.starts_with('A')) {
+                    let prefix = format!("{}[{index}]", super::BUS);
+                    expand(prefix, rest, &|p| count(p, BUS_EQ_CHANNELS), &mut names);
+                }
+            }
+        } else {
+            expand(String::new(), self.name, &|p| count(p, 0), &mut names);
+        }
+        names
+    }
+
+    /// Returns `true` if `name` is a name of this parameter, ignoring case like Voicemeeter.
+    pub fn matches(&self, name: &str) -> bool {
+        let (mut template, mut name) = (self.name.as_bytes(), name.as_bytes());
+        loop {
+            match (template, name) {
+                ([], []) => return true,
+                (
+                    [open @ (b'[' | b'('), placeholder, close @ (b']' | b')'), t_rest @ ..],
+                    [n_open, n_rest @ ..],
+                ) if placeholder.is_ascii_lowercase() && open == n_open => {
+                    let digits = n_rest.iter().take_while(|c| c.is_ascii_digit()).count();
+                    if digits == 0 || n_rest.get(digits) != Some(close) {
+                        return false;
+                    }
+                    template = t_rest;
+                    name = &n_rest[digits + 1..];
+                }
+                ([t, t_rest @ ..], [n, n_rest @ ..]) if t.eq_ignore_ascii_case(n) => {
+                    template = t_rest;
+                    name = n_rest;
+                }
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// Returns `true` if `device` is a virtual strip.
+fn is_virtual(device: &Device) -> bool {
+    matches!(
+        device,
+        Device::VirtualInput | Device::VirtualInputAux | Device::VirtualInput8
+    )
+}
+
+/// Fill in the first placeholder in `template` with every index, and push the names to `names`.
+fn expand(
+    prefix: String,
+    template: &str,
+    count: &dyn Fn(u8) -> Option<usize>,
+    names: &mut Vec<ParameterName>,
+) {
+    let placeholder = template
+        .as_bytes()
+        .windows(3)
+        .position(|w| matches!(w, [b'[', p, b']'] | [b'(', p, b')'] if p.is_ascii_lowercase()));
+    let Some(start) = placeholder else {
+        names.push(ParameterName::from(prefix + template));
+        return;
+    };
+    let Some(indexes) = count(template.as_bytes()[start + 1]) else {
+        return;
+    };
+    for index in 0..indexes {
+        let prefix = format!(
+            "{prefix}{}{index}{}",
+            &template[..=start],
+            &template[start + 2..start + 3]
+        );
+        expand(prefix, &template[start + 3..], count, names);
+    }
+}
+
+/// All known parameters.
+pub fn parameters() -> &'static [ParameterInfo] {
+    PARAMETERS
+}
+
+/// Find the parameter that `name` is a name of, e.g. `Strip[0].Gain`.
+///
+/// The indexes in the name are not checked against a program, use [`ParameterInfo::names`] for that.
+pub fn lookup(name: &str) -> Option<&'static ParameterInfo> {
+    PARAMETERS.iter().find(|p| p.matches(name))
+}
+
+use ParameterKind::{Bool, Float, Int, String as Str, Tuple};
+use Scope::{Physical, Virtual};
+
+const fn p(name: &'static str, kind: ParameterKind, description: &'static str) -> ParameterInfo {
+    ParameterInfo::new(name, kind, description)
+}
+
+static PARAMETERS: &[ParameterInfo] = &[
+    // Strips
+    p("Strip[i].Mono", Bool, "Mono button"),
+    p("Strip[i].Mute", Bool, "Mute button"),
+    p("Strip[i].Solo", Bool, "Solo button"),
+    p("Strip[i].MC", Bool, "Mute center button"),
+    p("Strip[i].Gain", Float, "Gain slider")
+        .range(-60.0, 12.0)
+        .unit("dB"),
+    p("Strip[i].GainLayer[b]", Float, "Gain slider for a bus")
+        .range(-60.0, 12.0)
+        .unit("dB"),
+    p("Strip[i].Pan_x", Float, "Pan in x direction").range(-0.5, 0.5),
+    p("Strip[i].Pan_y", Float, "Pan in y direction"),
+    p("Strip[i].Color_x", Float, "Color in x direction")
+        .range(-0.5, 0.5)
+        .scope(Physical),
+    p("Strip[i].Color_y", Float, "Color in y direction")
+        .range(0.0, 1.0)
+        .scope(Physical),
+    p("Strip[i].fx_x", Float, "FX in x direction")
+        .range(-0.5, 0.5)
+        .scope(Physical),
+    p("Strip[i].fx_y", Float, "FX in y direction")
+        .range(0.0, 1.0)
+        .scope(Physical),
+    p("Strip[i].Audability", Float, "Audability knob").range(0.0, 10.0),
+    p("Strip[i].Comp", Float, "Compressor knob").range(0.0, 10.0),
+    p("Strip[i].Gate", Float, "Gate knob").range(0.0, 10.0),
+    p("Strip[i].Denoiser", Float, "Denoiser knob")
+        .range(0.0, 10.0)
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].Karaoke", Int, "Karaoke mode").range(0.0, 4.0),
+    p("Strip[i].Limit", Int, "Limiter")
+        .range(-40.0, 12.0)
+        .unit("dB"),
+    p("Strip[i].EQGain1", Float, "Bass EQ knob")
+        .range(-12.0, 12.0)
+        .unit("dB")
+        .scope(Virtual),
+    p("Strip[i].EQGain2", Float, "Medium EQ knob")
+        .range(-12.0, 12.0)
+        .unit("dB")
+        .scope(Virtual),
+    p("Strip[i].EQGain3", Float, "Treble EQ knob")
+        .range(-12.0, 12.0)
+        .unit("dB")
+        .scope(Virtual),
+    p("Strip[i].Label", Str, "Label"),
+    p("Strip[i].A1", Bool, "Route to bus A1"),
+    p("Strip[i].A2", Bool, "Route to bus A2").programs(BANANA),
+    p("Strip[i].A3", Bool, "Route to bus A3").programs(BANANA),
+    p("Strip[i].A4", Bool, "Route to bus A4").programs(POTATO),
+    p("Strip[i].A5", Bool, "Route to bus A5").programs(POTATO),
+    p("Strip[i].B1", Bool, "Route to bus B1"),
+    p("Strip[i].B2", Bool, "Route to bus B2").programs(BANANA),
+    p("Strip[i].B3", Bool, "Route to bus B3").programs(POTATO),
+    p("Strip[i].EQ.on", Bool, "EQ button"),
+    p("Strip[i].EQ.AB", Bool, "EQ memory slot"),
+    p("Strip[i].EQ.channel[c].cell[k].on", Bool, "EQ cell on")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].EQ.channel[c].cell[k].type", Int, "EQ filter type")
+        .range(0.0, 6.0)
+        .programs(POTATO)
+        .scope(Physical),
+    p(
+        "Strip[i].EQ.channel[c].cell[k].f",
+        Float,
+        "EQ filter frequency",
+    )
+    .range(20.0, 20_000.0)
+    .unit("Hz")
+    .programs(POTATO)
+    .scope(Physical),
+    p(
+        "Strip[i].EQ.channel[c].cell[k].gain",
+        Float,
+        "EQ filter gain",
+    )
+    .range(-36.0, 18.0)
+    .unit("dB")
+    .programs(POTATO)
+    .scope(Physical),
+    p(
+        "Strip[i].EQ.channel[c].cell[k].q",
+        Float,
+        "EQ filter quality",
+    )
+    .range(1.0, 100.0)
+    .programs(POTATO)
+    .scope(Physical),
+    p(
+        "Strip[i].FadeTo",
+        Tuple,
+        "Fade gain to a value in a time in ms",
+    )
+    .write_only(),
+    p(
+        "Strip[i].FadeBy",
+        Tuple,
+        "Fade gain by a value in a time in ms",
+    )
+    .write_only(),
+    p("Strip[i].Reverb", Float, "Reverb send knob")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Strip[i].Delay", Float, "Delay send knob")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Strip[i].Fx1", Float, "Fx1 send knob")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Strip[i].Fx2", Float, "Fx2 send knob")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Strip[i].PostReverb", Bool, "Post fader reverb send").programs(POTATO),
+    p("Strip[i].PostDelay", Bool, "Post fader delay send").programs(POTATO),
+    p("Strip[i].PostFx1", Bool, "Post fader Fx1 send").programs(POTATO),
+    p("Strip[i].PostFx2", Bool, "Post fader Fx2 send").programs(POTATO),
+    p("Strip[i].App[a].Gain", Float, "Application gain")
+        .range(0.0, 1.0)
+        .write_only(),
+    p("Strip[i].App[a].Mute", Bool, "Application mute").write_only(),
+    p("Strip[i].AppGain", Tuple, "Application gain by name").write_only(),
+    p("Strip[i].AppMute", Tuple, "Application mute by name").write_only(),
+    p("Strip[i].device.name", Str, "Name of the audio device")
+        .read_only()
+        .scope(Physical),
+    p("Strip[i].device.sr", Int, "Samplerate of the audio device")
+        .unit("Hz")
+        .read_only()
+        .scope(Physical),
+    p("Strip[i].device.wdm", Str, "Use a WDM device")
+        .write_only()
+        .scope(Physical),
+    p("Strip[i].device.ks", Str, "Use a KS device")
+        .write_only()
+        .scope(Physical),
+    p("Strip[i].device.mme", Str, "Use a MME device")
+        .write_only()
+        .scope(Physical),
+    p("Strip[i].device.asio", Str, "Use an ASIO device")
+        .write_only()
+        .scope(Physical),
+    p("Strip[i].comp.GainIn", Float, "Compressor input gain")
+        .range(-24.0, 24.0)
+        .unit("dB")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].comp.Ratio", Float, "Compressor ratio")
+        .range(1.0, 8.0)
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].comp.Threshold", Float, "Compressor threshold")
+        .range(-40.0, -3.0)
+        .unit("dB")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].comp.Attack", Float, "Compressor attack time")
+        .range(0.0, 200.0)
+        .unit("ms")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].comp.Release", Float, "Compressor release time")
+        .range(0.0, 5000.0)
+        .unit("ms")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].comp.Knee", Float, "Compressor knee")
+        .range(0.0, 1.0)
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].comp.GainOut", Float, "Compressor output gain")
+        .range(-24.0, 24.0)
+        .unit("dB")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].comp.MakeUp", Bool, "Compressor auto make-up")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].Gate.Threshold", Float, "Gate threshold")
+        .range(-60.0, -10.0)
+        .unit("dB")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].Gate.Damping", Float, "Gate damping")
+        .range(-60.0, -10.0)
+        .unit("dB")
+        .programs(POTATO)
+        .scope(Physical),
+    p(
+        "Strip[i].Gate.BPSidechain",
+        Float,
+        "Gate sidechain band pass",
+    )
+    .range(100.0, 4000.0)
+    .unit("Hz")
+    .programs(POTATO)
+    .scope(Physical),
+    p("Strip[i].Gate.Attack", Float, "Gate attack time")
+        .range(0.0, 1000.0)
+        .unit("ms")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].Gate.Hold", Float, "Gate hold time")
+        .range(0.0, 5000.0)
+        .unit("ms")
+        .programs(POTATO)
+        .scope(Physical),
+    p("Strip[i].Gate.Release", Float, "Gate release time")
+        .range(0.0, 5000.0)
+        .unit("ms")
+        .programs(POTATO)
+        .scope(Physical),
+    // Buses
+    p("Bus[i].Label", Str, "Label"),
+    p("Bus[i].Mono", Int, "Mono button").range(0.0, 2.0),
+    p("Bus[i].Mute", Bool, "Mute button"),
+    p("Bus[i].Gain", Float, "Gain slider")
+        .range(-60.0, 12.0)
+        .unit("dB"),
+    p("Bus[i].Sel", Bool, "SEL button"),
+    p("Bus[i].Monitor", Bool, "Monitor button"),
+    p("Bus[i].EQ.on", Bool, "EQ button"),
+    p("Bus[i].EQ.AB", Bool, "EQ memory slot"),
+    p("Bus[i].EQ.channel[c].cell[k].on", Bool, "EQ cell on"),
+    p("Bus[i].EQ.channel[c].cell[k].type", Int, "EQ filter type").range(0.0, 6.0),
+    p(
+        "Bus[i].EQ.channel[c].cell[k].f",
+        Float,
+        "EQ filter frequency",
+    )
+    .range(20.0, 20_000.0)
+    .unit("Hz"),
+    p("Bus[i].EQ.channel[c].cell[k].gain", Float, "EQ filter gain")
+        .range(-36.0, 18.0)
+        .unit("dB"),
+    p("Bus[i].EQ.channel[c].cell[k].q", Float, "EQ filter quality").range(1.0, 100.0),
+    p(
+        "Bus[i].FadeTo",
+        Tuple,
+        "Fade gain to a value in a time in ms",
+    )
+    .write_only(),
+    p(
+        "Bus[i].FadeBy",
+        Tuple,
+        "Fade gain by a value in a time in ms",
+    )
+    .write_only(),
+    p("Bus[i].ReturnReverb", Float, "Reverb return")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Bus[i].ReturnDelay", Float, "Delay return")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Bus[i].ReturnFx1", Float, "Fx1 return")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Bus[i].ReturnFx2", Float, "Fx2 return")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Bus[i].mode.normal", Bool, "Bus mode normal"),
+    p("Bus[i].mode.Amix", Bool, "Bus mode Amix"),
+    p("Bus[i].mode.Bmix", Bool, "Bus mode Bmix"),
+    p("Bus[i].mode.Repeat", Bool, "Bus mode Repeat"),
+    p("Bus[i].mode.Composite", Bool, "Bus mode Composite"),
+    p("Bus[i].mode.TVMix", Bool, "Bus mode TVMix"),
+    p("Bus[i].mode.UpMix21", Bool, "Bus mode UpMix21"),
+    p("Bus[i].mode.UpMix41", Bool, "Bus mode UpMix41"),
+    p("Bus[i].mode.UpMix61", Bool, "Bus mode UpMix61"),
+    p("Bus[i].mode.CenterOnly", Bool, "Bus mode CenterOnly"),
+    p("Bus[i].mode.LFEOnly", Bool, "Bus mode LFEOnly"),
+    p("Bus[i].mode.RearOnly", Bool, "Bus mode RearOnly"),
+    p("Bus[i].device.name", Str, "Name of the audio device")
+        .read_only()
+        .scope(Physical),
+    p("Bus[i].device.sr", Int, "Samplerate of the audio device")
+        .unit("Hz")
+        .read_only()
+        .scope(Physical),
+    p("Bus[i].device.wdm", Str, "Use a WDM device")
+        .write_only()
+        .scope(Physical),
+    p("Bus[i].device.ks", Str, "Use a KS device")
+        .write_only()
+        .scope(Physical),
+    p("Bus[i].device.mme", Str, "Use a MME device")
+        .write_only()
+        .scope(Physical),
+    p("Bus[i].device.asio", Str, "Use an ASIO device")
+        .write_only()
+        .scope(Physical),
+    // Options
+    p("Option.sr", Float, "Preferred samplerate").unit("Hz"),
+    p(
+        "Option.ASIOsr",
+        Bool,
+        "Use the preferred samplerate for ASIO on A1",
+    ),
+    p("Option.delay[b]", Int, "Bus output delay")
+        .range(0.0, 500.0)
+        .unit("ms"),
+    p("Option.buffer.mme", Int, "MME buffer size")
+        .range(128.0, 2048.0)
+        .unit("samples"),
+    p("Option.buffer.wdm", Int, "WDM buffer size")
+        .range(128.0, 2048.0)
+        .unit("samples"),
+    p("Option.buffer.ks", Int, "KS buffer size")
+        .range(128.0, 2048.0)
+        .unit("samples"),
+    p("Option.buffer.asio", Int, "ASIO buffer size")
+        .range(128.0, 2048.0)
+        .unit("samples"),
+    p("Option.mode.exclusif", Bool, "WDM input exclusive mode"),
+    p("Option.mode.swift", Bool, "WDM swift mode"),
+    p("Option.MonitorOnSEL", Bool, "Monitor on SEL"),
+    // Command
+    p("Command.Shutdown", Bool, "Shutdown Voicemeeter").write_only(),
+    p("Command.Show", Bool, "Show Voicemeeter").write_only(),
+    p("Command.Restart", Bool, "Restart the audio engine").write_only(),
+    p("Command.Eject", Bool, "Eject the cassettes")
+        .write_only()
+        .programs(BANANA),
+    p("Command.Reset", Bool, "Reset the whole configuration").write_only(),
+    p("Command.Save", Str, "Save the settings to an XML file").write_only(),
+    p("Command.Load", Str, "Load the settings from an XML file").write_only(),
+    p("Command.Lock", Bool, "Lock the GUI").write_only(),
+    p("Command.Button[m].State", Bool, "Macro button state").write_only(),
+    p(
+        "Command.Button[m].StateOnly",
+        Bool,
+        "Macro button state, without running its commands",
+    )
+    .write_only(),
+    p(
+        "Command.Button[m].Trigger",
+        Bool,
+        "Macro button trigger state",
+    )
+    .write_only(),
+    p(
+        "Command.SaveBUSEQ[b]",
+        Str,
+        "Save the bus EQ to an XML file",
+    )
+    .write_only(),
+    p(
+        "Command.LoadBUSEQ[b]",
+        Str,
+        "Load the bus EQ from an XML file",
+    )
+    .write_only(),
+    p(
+        "Command.SaveStripEQ[i]",
+        Str,
+        "Save the strip EQ to an XML file",
+    )
+    .write_only()
+    .programs(POTATO)
+    .scope(Physical),
+    p(
+        "Command.LoadStripEQ[i]",
+        Str,
+        "Load the strip EQ from an XML file",
+    )
+    .write_only()
+    .programs(POTATO)
+    .scope(Physical),
+    p(
+        "Command.DialogShow.VBANCHAT",
+        Bool,
+        "Show the VBAN-Chat dialog",
+    )
+    .write_only(),
+    // Patch
+    p(
+        "Patch.asio[p]",
+        Int,
+        "ASIO input channel of an input channel",
+    ),
+    p("Patch.OutA2[o]", Int, "ASIO output channel of A2"),
+    p("Patch.OutA3[o]", Int, "ASIO output channel of A3").programs(BANANA),
+    p("Patch.OutA4[o]", Int, "ASIO output channel of A4").programs(POTATO),
+    p("Patch.OutA5[o]", Int, "ASIO output channel of A5").programs(POTATO),
+    p("Patch.composite[o]", Int, "Input channel of composite mode").programs(BANANA),
+    p("Patch.insert[n]", Bool, "Virtual ASIO insert").programs(BANANA),
+    p(
+        "Patch.PostFaderComposite",
+        Bool,
+        "Composite mode after the faders",
+    )
+    .programs(BANANA),
+    p("Patch.PostFxInsert", Bool, "Insert after the effects").programs(POTATO),
+    // Recorder
+    p("Recorder.stop", Bool, "Stop").programs(BANANA),
+    p("Recorder.play", Bool, "Play").programs(BANANA),
+    p("Recorder.replay", Bool, "Play from position").programs(BANANA),
+    p("Recorder.ff", Bool, "Fast forward").programs(BANANA),
+    p("Recorder.rew", Bool, "Rewind").programs(BANANA),
+    p("Recorder.goto", Str, "Go to position").programs(BANANA),
+    p("Recorder.record", Bool, "Record").programs(BANANA),
+    p("Recorder.pause", Bool, "Pause").programs(BANANA),
+    p("Recorder.load", Str, "Load a file to play")
+        .write_only()
+        .programs(BANANA),
+    p("Recorder.A1", Bool, "Play to bus A1").programs(BANANA),
+    p("Recorder.A2", Bool, "Play to bus A2").programs(BANANA),
+    p("Recorder.A3", Bool, "Play to bus A3").programs(BANANA),
+    p("Recorder.A4", Bool, "Play to bus A4").programs(POTATO),
+    p("Recorder.A5", Bool, "Play to bus A5").programs(POTATO),
+    p("Recorder.B1", Bool, "Play to bus B1").programs(BANANA),
+    p("Recorder.B2", Bool, "Play to bus B2").programs(BANANA),
+    p("Recorder.B3", Bool, "Play to bus B3").programs(POTATO),
+    p("Recorder.samplerate", Int, "Recording samplerate")
+        .range(22050.0, 96000.0)
+        .unit("Hz")
+        .programs(BANANA),
+    p("Recorder.ArmStrip(i)", Bool, "Arm a strip for recording").programs(BANANA),
+    p("Recorder.ArmBus(b)", Bool, "Arm a bus for recording").programs(BANANA),
+    p("Recorder.mode.recbus", Bool, "Record bus").programs(BANANA),
+    p("Recorder.mode.PlayOnLoad", Bool, "Play on load").programs(BANANA),
+    p("Recorder.mode.Loop", Bool, "Loop").programs(BANANA),
+    p("Recorder.mode.MultiTrack", Bool, "Multi track").programs(BANANA),
+    p("Recorder.bitResolution", Int, "Bit resolution")
+        .range(8.0, 32.0)
+        .unit("bits")
+        .programs(BANANA),
+    p("Recorder.Channel", Int, "Channels to record")
+        .range(1.0, 8.0)
+        .programs(BANANA),
+    p("Recorder.kbps", Int, "MP3 bitrate")
+        .range(32.0, 320.0)
+        .unit("kbps")
+        .programs(BANANA),
+    p("Recorder.FileType", Int, "File type")
+        .range(1.0, 100.0)
+        .programs(BANANA),
+    p("Recorder.gain", Float, "Playback gain")
+        .range(-60.0, 12.0)
+        .unit("dB")
+        .programs(BANANA),
+    // Fx
+    p("Fx.Reverb.On", Bool, "Reverb on").programs(POTATO),
+    p("Fx.Reverb.AB", Bool, "Reverb memory slot").programs(POTATO),
+    p("Fx.Reverb.Size", Float, "Reverb room size")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Fx.Reverb.Damp", Float, "Reverb damping")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Fx.Reverb.Diffusion", Float, "Reverb diffusion")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Fx.Reverb.Bass", Float, "Reverb bass multiplier")
+        .range(0.0, 10.0)
+        .programs(POTATO),
+    p("Fx.Reverb.LoCut", Float, "Reverb low cut")
+        .range(10.0, 500.0)
+        .unit("Hz")
+        .programs(POTATO),
+    p("Fx.Reverb.HiCut", Float, "Reverb high cut")
+        .range(1000.0, 20_000.0)
+        .unit("Hz")
+        .programs(POTATO),
+    p("Fx.Reverb.Wet", Float, "Reverb wet level")
+        .range(-60.0, 0.0)
+        .unit("dB")
+        .programs(POTATO),
+    p("Fx.Reverb.Dry", Float, "Reverb dry level")
+        .range(-60.0, 0.0)
+        .unit("dB")
+        .programs(POTATO),
+    p("Fx.Reverb.PreDelay", Float, "Reverb pre-delay")
+        .range(0.0, 500.0)
+        .unit("ms")
+        .programs(POTATO),
+    p("Fx.Delay.On", Bool, "Delay on").programs(POTATO),
+    p("Fx.Delay.AB", Bool, "Delay memory slot").programs(POTATO),
+    p("Fx.Delay.Time", Float, "Delay time")
+        .range(0.0, 2000.0)
+        .unit("ms")
+        .programs(POTATO),
+    p("Fx.Delay.Feedback", Float, "Delay feedback")
+        .range(0.0, 100.0)
+        .unit("%")
+        .programs(POTATO),
+    p("Fx.Delay.HP", Float, "Delay high pass")
+        .range(10.0, 1000.0)
+        .unit("Hz")
+        .programs(POTATO),
+    p("Fx.Delay.LP", Float, "Delay low pass")
+        .range(1000.0, 20_000.0)
+        .unit("Hz")
+        .programs(POTATO),
+    p("Fx.Delay.PingPong", Bool, "Delay ping-pong").programs(POTATO),
+    // VBAN
+    p("vban.Enable", Bool, "VBAN functions"),
+    p("vban.instream[s].on", Bool, "Stream on"),
+    p("vban.instream[s].name", Str, "Stream name"),
+    p("vban.instream[s].ip", Str, "IP address from"),
+    p("vban.instream[s].port", Int, "Port").range(0.0, 65535.0),
+    p("vban.instream[s].quality", Int, "Quality").range(0.0, 4.0),
+    p("vban.instream[s].route", Int, "Strip selector").range(0.0, 8.0),
+    p("vban.instream[s].sr", Int, "Samplerate")
+        .range(11025.0, 96000.0)
+        .unit("Hz")
+        .read_only(),
+    p("vban.instream[s].channel", Int, "Channels")
+        .range(1.0, 8.0)
+        .read_only(),
+    p("vban.instream[s].bit", Int, "Data type")
+        .range(1.0, 2.0)
+        .read_only(),
+    p("vban.outstream[s].on", Bool, "Stream on"),
+    p("vban.outstream[s].name", Str, "Stream name"),
+    p("vban.outstream[s].ip", Str, "IP address to"),
+    p("vban.outstream[s].port", Int, "Port").range(0.0, 65535.0),
+    p("vban.outstream[s].quality", Int, "Quality").range(0.0, 4.0),
+    p("vban.outstream[s].route", Int, "Bus selector").range(0.0, 8.0),
+    p("vban.outstream[s].sr", Int, "Samplerate")
+        .range(11025.0, 96000.0)
+        .unit("Hz"),
+    p("vban.outstream[s].channel", Int, "Channels").range(1.0, 8.0),
+    p("vban.outstream[s].bit", Int, "Data type").range(1.0, 2.0),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Debug, ops::Bound};
+
+    use super::super::{
+        bound_to_f32, BoolParameter, FloatParameter, IntParameter, StringParameter,
+    };
+    use super::*;
+    use crate::backend::simulator::remote;
+
+    #[test]
+    fn lookup_and_names() {
+        let cell = lookup("bus[2].eq.channel[7].cell[5].GAIN").unwrap();
+        assert_eq!(cell.name, "Bus[i].EQ.channel[c].cell[k].gain");
+        assert_eq!(cell.range, Some(-36.0..=18.0));
+        assert!(!lookup("Strip[0].App[1].Gain").unwrap().read);
+        assert!(lookup("Strip[x].Gain").is_none());
+        assert!(lookup("Strip[0].Gain2").is_none());
+        assert_eq!(
+            lookup("Recorder.ArmStrip(3)").unwrap().name,
+            "Recorder.ArmStrip(i)"
+        );
+
+        let color = lookup("Strip[0].Color_x").unwrap();
+        let names = color.names(VoicemeeterApplication::Voicemeeter);
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[1].as_str(), "Strip[1].Color_x");
+        assert!(lookup("Strip[0].App[0].Mute")
+            .unwrap()
+            .names(VoicemeeterApplication::VoicemeeterPotato)
+            .is_empty());
+
+        for program in ALL.iter().copied() {
+            for parameter in parameters() {
+                for name in parameter.names(program) {
+                    assert_eq!(lookup(name.as_str()), Some(parameter), "{name}");
+                }
+            }
+            let buses: Vec<_> = program
+                .devices()
+                .iter()
+                .filter_map(|d| Some(format!("Strip[0].{}", d.as_bus_index(&program)?.1)))
+                .collect();
+            let routes: Vec<_> = parameters()
+                .iter()
+                .filter(
+                    |p| match p.name.strip_prefix("Strip[i].").map(str::as_bytes) {
+                        Some(&[b'A' | b'B', n]) => n.is_ascii_digit(),
+                        _ => false,
+                    },
+                )
+                .flat_map(|p| p.names(program).into_iter().take(1))
+                .map(|n| n.to_string())
+                .collect();
+            assert_eq!(routes.len(), buses.len(), "{program}");
+            assert!(buses.iter().all(|b| routes.contains(b)), "{program}");
+        }
+    }
+
+    /// A typed accessor, compared with its registry entry.
+    trait Accessor {
+        fn name(&self) -> &str;
+        fn range(&self) -> Option<(Bound<f32>, Bound<f32>)>;
+    }
+
+    impl<const WRITE: bool, const READ: bool> Accessor for FloatParameter<'_, WRITE, READ> {
+        fn name(&self) -> &str {
+            self.name.as_str()
+        }
+        fn range(&self) -> Option<(Bound<f32>, Bound<f32>)> {
+            FloatParameter::range(self)
+        }
+    }
+
+    impl<const WRITE: bool, const READ: bool> Accessor for IntParameter<'_, WRITE, READ> {
+        fn name(&self) -> &str {
+            self.name.as_str()
+        }
+        fn range(&self) -> Option<(Bound<f32>, Bound<f32>)> {
+            IntParameter::range(self).map(|(start, end)| (bound_to_f32(start), bound_to_f32(end)))
+        }
+    }
+
+    impl<const WRITE: bool, const READ: bool> Accessor for BoolParameter<'_, WRITE, READ> {
+        fn name(&self) -> &str {
+            self.name.as_str()
+        }
+        fn range(&self) -> Option<(Bound<f32>, Bound<f32>)> {
+            None
+        }
+    }
+
+    impl<const WRITE: bool, const READ: bool> Accessor for StringParameter<'_, WRITE, READ> {
+        fn name(&self) -> &str {
+            self.name.as_str()
+        }
+        fn range(&self) -> Option<(Bound<f32>, Bound<f32>)> {
+            None
+        }
+    }
+
+    /// An accessor with a range that depends on the program, only its version gate is checked.
+    struct Unranged(String);
+
+    impl Accessor for Unranged {
+        fn name(&self) -> &str {
+            &self.0
+        }
+        fn range(&self) -> Option<(Bound<f32>, Bound<f32>)> {
+            None
+        }
+    }
+
+    struct Checker(VoicemeeterApplication);
+
+    impl Checker {
+        /// Check that `accessor` is built exactly when `name` is registered for the program, with the registered range.
+        #[track_caller]
+        fn check<E: Debug>(&self, name: &str, accessor: Result<impl Accessor, E>) {
+            let program = self.0;
+            let info = lookup(name).unwrap_or_else(|| panic!("`{name}` is not registered"));
+            let registered = info
+                .names(program)
+                .iter()
+                .any(|n| n.as_str().eq_ignore_ascii_case(name));
+            match accessor {
+                Ok(accessor) => {
+                    assert!(accessor.name().eq_ignore_ascii_case(name), "{name}");
+                    assert!(registered, "{program}: `{name}` is not registered");
+                    let range = info
+                        .range
+                        .as_ref()
+                        .map(|r| (Bound::Included(*r.start()), Bound::Included(*r.end())));
+                    assert_eq!(accessor.range(), range, "{program}: `{name}`");
+                }
+                Err(e) => assert!(!registered, "{program}: `{name}` is registered, but {e:?}"),
+            }
+        }
+
+        #[track_caller]
+        fn ok(&self, accessor: impl Accessor) {
+            let name = accessor.name().to_owned();
+            self.check(&name, Ok::<_, ()>(accessor));
+        }
+    }
+
+    #[test]
+    fn accessors() {
+        for program in ALL.iter().copied() {
+            let (_, remote) = remote(program);
+            let parameters = remote.parameters();
+            let c = Checker(program);
+            let strips = program.devices().iter().filter(|d| d.is_strip()).count();
+
+            for i in 0..strips {
+                let strip = parameters.strip(i).unwrap();
+                let name = |dot: &str| format!("Strip[{i}].{dot}");
+                c.ok(strip.gain());
+                c.ok(strip.gain_layer(0));
+                c.ok(strip.pan_x());
+                c.ok(strip.pan_y());
+                c.check(&name("Color_x"), strip.color_x());
+                c.check(&name("Color_y"), strip.color_y());
+                c.check(&name("fx_x"), strip.fx_x());
+                c.check(&name("fx_y"), strip.fx_y());
+                c.ok(strip.audability());
+                c.ok(strip.comp());
+                c.ok(strip.gate());
+                c.check(&name("Denoiser"), strip.denoiser());
+                c.ok(strip.karaoke());
+                c.ok(strip.limit());
+                c.check(&name("EQGain1"), strip.eq_gain1());
+                c.check(&name("EQGain2"), strip.eq_gain2());
+                c.check(&name("EQGain3"), strip.eq_gain3());
+                c.check(&name("Reverb"), strip.reverb());
+                c.check(&name("Delay"), strip.delay());
+                c.check(&name("Fx1"), strip.fx1());
+                c.check(&name("Fx2"), strip.fx2());
+                c.check(&name("PostReverb"), strip.post_reverb());
+                c.check(&name("PostDelay"), strip.post_delay());
+                c.check(&name("PostFx1"), strip.post_fx1());
+                c.check(&name("PostFx2"), strip.post_fx2());
+
+                let eq = strip.eq(0);
+                let cell = |dot: &str| name(&format!("EQ.channel[0].cell[0].{dot}"));
+                c.check(&cell("on"), eq.as_ref().map(|eq| eq.on(0)));
+                c.check(&cell("type"), eq.as_ref().map(|eq| eq.type_(0)));
+                c.check(&cell("f"), eq.as_ref().map(|eq| eq.f(0)));
+                c.check(&cell("gain"), eq.as_ref().map(|eq| eq.gain(0)));
+                c.check(&cell("q"), eq.as_ref().map(|eq| eq.q(0)));
+
+                let comp = strip.comp_detailed();
+                let comp = comp.as_ref();
+                let comp_name = |dot: &str| name(&format!("comp.{dot}"));
+                c.check(&comp_name("GainIn"), comp.map(|c| c.gain_in()));
+                c.check(&comp_name("Ratio"), comp.map(|c| c.ratio()));
+                c.check(&comp_name("Threshold"), comp.map(|c| c.threshold()));
+                c.check(&comp_name("Attack"), comp.map(|c| c.attack()));
+                c.check(&comp_name("Release"), comp.map(|c| c.release()));
+                c.check(&comp_name("Knee"), comp.map(|c| c.knee()));
+                c.check(&comp_name("GainOut"), comp.map(|c| c.gain_out()));
+                c.check(&comp_name("MakeUp"), comp.map(|c| c.make_up()));
+
+                let gate = strip.gate_detailed();
+                let gate = gate.as_ref();
+                let gate_name = |dot: &str| name(&format!("Gate.{dot}"));
+                c.check(&gate_name("Threshold"), gate.map(|g| g.threshold()));
+                c.check(&gate_name("Damping"), gate.map(|g| g.damping()));
+                c.check(&gate_name("BPSidechain"), gate.map(|g| g.bp_sidechain()));
+                c.check(&gate_name("Attack"), gate.map(|g| g.attack()));
+                c.check(&gate_name("Hold"), gate.map(|g| g.hold()));
+                c.check(&gate_name("Release"), gate.map(|g| g.release()));
+
+                let device = strip.device();
+                c.check(&name("device.sr"), device.as_ref().map(|d| d.sr()));
+
+                let command = parameters.command();
+                c.check(
+                    &format!("Command.SaveStripEQ[{i}]"),
+                    command.save_strip_eq(i),
+                );
+                c.check(
+                    &format!("Command.LoadStripEQ[{i}]"),
+                    command.load_strip_eq(i),
+                );
+            }
+
+            for (i, bus_name) in program
+                .devices()
+                .iter()
+                .filter_map(|d| d.as_bus_index(&program))
+            {
+                let bus = parameters.bus(i).unwrap();
+                let name = |dot: &str| format!("Bus[{i}].{dot}");
+                c.ok(bus.mono());
+                c.ok(bus.gain());
+                c.check(&name("ReturnReverb"), bus.return_reverb());
+                c.check(&name("ReturnDelay"), bus.return_delay());
+                c.check(&name("ReturnFx1"), bus.return_fx1());
+                c.check(&name("ReturnFx2"), bus.return_fx2());
+                if bus_name.starts_with('A') {
+                    c.ok(bus.device().sr());
+                }
+
+                let eq = bus.eq(0);
+                c.ok(eq.on(0));
+                c.ok(eq.type_(0));
+                c.ok(eq.f(0));
+                c.ok(eq.gain(0));
+                c.ok(eq.q(0));
+            }
+
+            let option = parameters.option();
+            c.ok(option.sr());
+            c.ok(option.delay(0));
+            c.ok(option.buffer_mme());
+            c.ok(option.buffer_wdm());
+            c.ok(option.buffer_ks());
+            c.ok(option.buffer_asio());
+
+            let command = parameters.command();
+            c.check("Command.Eject", command.eject());
+            c.check("Command.SaveBUSEQ[0]", command.save_bus_eq(0));
+            c.check("Command.LoadBUSEQ[0]", command.load_bus_eq(0));
+
+            let patch = parameters.patch();
+            let unranged = |p: IntParameter<'_>| Unranged(p.name.to_string());
+            c.check("Patch.asio[0]", patch.asio(0).map(unranged));
+            c.check("Patch.composite[0]", patch.composite(0).map(unranged));
+            c.check("Patch.insert[0]", patch.insert(0));
+            c.check("Patch.PostFaderComposite", patch.post_fader_composite());
+            c.check("Patch.PostFxInsert", patch.post_fx_insert());
+
+            let recorder = parameters.recorder();
+            let recorder = recorder.as_ref();
+            c.check("Recorder.samplerate", recorder.map(|r| r.samplerate()));
+            c.check(
+                "Recorder.bitResolution",
+                recorder.map(|r| r.bit_resolution()),
+            );
+            c.check("Recorder.Channel", recorder.map(|r| r.channel()));
+            c.check("Recorder.kbps", recorder.map(|r| r.kbps()));
+            c.check("Recorder.FileType", recorder.map(|r| r.file_type()));
+            c.check("Recorder.gain", recorder.map(|r| r.gain()));
+
+            let fx = parameters.fx();
+            let fx = fx.as_ref();
+            c.check("Fx.Reverb.On", fx.map(|fx| fx.reverb_on()));
+            c.check("Fx.Reverb.AB", fx.map(|fx| fx.reverb_ab()));
+            c.check("Fx.Reverb.Size", fx.map(|fx| fx.reverb_size()));
+            c.check("Fx.Reverb.Damp", fx.map(|fx| fx.reverb_damp()));
+            c.check("Fx.Reverb.Diffusion", fx.map(|fx| fx.reverb_diffusion()));
+            c.check("Fx.Reverb.Bass", fx.map(|fx| fx.reverb_bass()));
+            c.check("Fx.Reverb.LoCut", fx.map(|fx| fx.reverb_low_cut()));
+            c.check("Fx.Reverb.HiCut", fx.map(|fx| fx.reverb_high_cut()));
+            c.check("Fx.Reverb.Wet", fx.map(|fx| fx.reverb_wet()));
+            c.check("Fx.Reverb.Dry", fx.map(|fx| fx.reverb_dry()));
+            c.check("Fx.Reverb.PreDelay", fx.map(|fx| fx.reverb_pre_delay()));
+            c.check("Fx.Delay.On", fx.map(|fx| fx.delay_on()));
+            c.check("Fx.Delay.AB", fx.map(|fx| fx.delay_ab()));
+            c.check("Fx.Delay.Time", fx.map(|fx| fx.delay_time()));
+            c.check("Fx.Delay.Feedback", fx.map(|fx| fx.delay_feedback()));
+            c.check("Fx.Delay.HP", fx.map(|fx| fx.delay_hp()));
+            c.check("Fx.Delay.LP", fx.map(|fx| fx.delay_lp()));
+            c.check("Fx.Delay.PingPong", fx.map(|fx| fx.delay_ping_pong()));
+
+            let vban = parameters.vban();
+            let instream = vban.incoming_stream(0).unwrap();
+            c.ok(instream.port());
+            c.ok(instream.quality());
+            c.ok(instream.route());
+            c.ok(instream.sample_rate());
+            c.ok(instream.channel());
+            c.ok(instream.bit());
+            let outstream = vban.outgoing_stream(0).unwrap();
+            c.ok(outstream.port());
+            c.ok(outstream.quality());
+            c.ok(outstream.route());
+            c.ok(outstream.sample_rate());
+            c.ok(outstream.channel());
+            c.ok(outstream.bit());
+        }
+    }
+}
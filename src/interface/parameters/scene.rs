@@ -0,0 +1,566 @@
+//! Named scenes that can be recalled instantly or crossfaded.
+//!
+//! A [`Scene`] is a named set of parameter values, captured from the mixer with [`VoicemeeterRemote::capture_scene`]
+//! or loaded from a file. Scene files are [`set_parameters`](VoicemeeterRemote::set_parameters) scripts
+//! with one assignment per parameter, the file name without the [extension](SCENE_EXTENSION) is the name of the scene.
+//!
+//! * [`VoicemeeterRemote::recall_scene`] sets all values at once.
+//! * [`RampEngine::crossfade`] ramps float parameters like gain, pan, EQ and sends to their values in the scene,
+//!   and switches the other parameters like mute, bus assignments and bus modes at a [point](Crossfade::switch_at) of the fade.
+//!
+//! A [`SceneBook`] holds scenes by name, and can bind them to macro buttons and MIDI messages with [`Trigger`]s.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use voicemeeter::{
+//!     interface::parameters::scene::{Crossfade, SceneBook, Trigger},
+//!     types::LogicalButton,
+//!     VoicemeeterRemote,
+//! };
+//!
+//! let remote = VoicemeeterRemote::new()?;
+//! let ramps = remote.ramps()?;
+//! let mut scenes = SceneBook::load_dir("scenes")?;
+//! scenes.bind(Trigger::MacroButton(LogicalButton::from(0)), "live");
+//! scenes.bind(Trigger::Midi { status: 0x90, data1: 60 }, "break");
+//!
+//! let live = scenes.get("live").expect("no live scene");
+//! ramps.crossfade(live, Crossfade::new(Duration::from_secs(3)))?;
+//!
+//! loop {
+//!     let message = remote.get_midi_message()?;
+//!     if let Some(scene) = scenes.for_midi(&message) {
+//!         remote.recall_scene(scene)?;
+//!     }
+//!     std::thread::sleep(Duration::from_millis(20));
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    types::{LogicalButton, ParameterName, ParameterNameRef},
+    VoicemeeterRemote,
+};
+
+use super::{
+    get_parameters::GetParameterError,
+    ramp::{Curve, Motion, RampEngine, RampError},
+    registry::{self, ParameterKind},
+    script::syntax::{self, Operator, ParseError, Span, Value},
+    set_parameters::SetParameterError,
+    snapshot::{is_inactive_mode, script_statement},
+    MixerSnapshot, ParameterValue, RestoreFailure, RestoreFailureReason, RestoreReport,
+};
+
+/// The extension of scene files loaded by [`SceneBook::load_dir`].
+pub const SCENE_EXTENSION: &str = "scene";
+
+/// A named set of parameter values, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scene {
+    /// The name of the scene.
+    pub name: String,
+    /// The value of every parameter in the scene.
+    pub values: BTreeMap<ParameterName, ParameterValue>,
+}
+
+impl Scene {
+    /// Create a scene with the parameters of a snapshot that can be set.
+    pub fn from_snapshot(name: impl Into<String>, snapshot: &MixerSnapshot) -> Self {
+        Self {
+            name: name.into(),
+            values: snapshot
+                .scripted_values()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+        }
+    }
+
+    /// Parse a scene from a script, every statement must assign a number or string to a parameter.
+    pub fn parse(name: impl Into<String>, script: &str) -> Result<Self, SceneError> {
+        let ast = syntax::parse(script)?;
+        let mut values = BTreeMap::new();
+        for statement in ast.statements() {
+            let value = match (&statement.operator, &statement.value) {
+                (Operator::Assign, Value::Number(n)) => ParameterValue::Float(*n),
+                (Operator::Assign, Value::String(s)) => ParameterValue::String(s.clone()),
+                _ => {
+                    return Err(SceneError::UnsupportedStatement {
+                        statement: statement.to_string(),
+                        span: statement.span(),
+                    })
+                }
+            };
+            values.insert(ParameterName::from(statement.name.clone()), value);
+        }
+        Ok(Self {
+            name: name.into(),
+            values,
+        })
+    }
+
+    /// Load a scene file, named after the file name without the extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SceneError> {
+        let path = path.as_ref();
+        let script = std::fs::read_to_string(path).map_err(|source| SceneError::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        let name = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        Self::parse(name, &script).map_err(|e| match e {
+            SceneError::Parse { error, .. } => SceneError::Parse {
+                path: Some(path.to_owned()),
+                error,
+            },
+            e => e,
+        })
+    }
+
+    /// Save the scene as a script to `path`.
+    ///
+    /// Nothing is written if a value can not be written in a script, see [`Scene::to_script`].
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SceneError> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_script()?).map_err(|source| SceneError::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    /// Render the scene as a [`set_parameters`](VoicemeeterRemote::set_parameters) script, one parameter per line.
+    ///
+    /// Fails with [`SetParameterError::InvalidString`] if a string value can not be written in a script.
+    pub fn to_script(&self) -> Result<String, SetParameterError> {
+        self.values
+            .iter()
+            .map(|(name, value)| Ok(script_statement(name, value)? + "\n"))
+            .collect()
+    }
+}
+
+/// What recalls a scene in a [`SceneBook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Trigger {
+    /// A macro button.
+    MacroButton(LogicalButton),
+    /// A MIDI message with this status byte and first data byte, e.g. a note on or a control change.
+    ///
+    /// Note on and control change messages with a value of 0 do not trigger.
+    Midi {
+        /// The status byte, including the channel.
+        status: u8,
+        /// The first data byte, like the note or controller number.
+        data1: u8,
+    },
+}
+
+/// Scenes by name, with triggers to recall them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SceneBook {
+    scenes: BTreeMap<String, Scene>,
+    triggers: HashMap<Trigger, String>,
+}
+
+impl SceneBook {
+    /// Create an empty scene book.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load all files with the [`SCENE_EXTENSION`] in a directory.
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Self, SceneError> {
+        let dir = dir.as_ref();
+        let io_error = |source| SceneError::Io {
+            path: dir.to_owned(),
+            source,
+        };
+        let mut book = Self::new();
+        for entry in std::fs::read_dir(dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if path.extension().is_some_and(|e| e == SCENE_EXTENSION) {
+                book.insert(Scene::load(path)?);
+            }
+        }
+        Ok(book)
+    }
+
+    /// Add a scene, replacing and returning the scene with the same name.
+    pub fn insert(&mut self, scene: Scene) -> Option<Scene> {
+        self.scenes.insert(scene.name.clone(), scene)
+    }
+
+    /// Remove a scene, and the triggers of it.
+    pub fn remove(&mut self, name: &str) -> Option<Scene> {
+        self.triggers.retain(|_, scene| scene != name);
+        self.scenes.remove(name)
+    }
+
+    /// The scene with this name.
+    pub fn get(&self, name: &str) -> Option<&Scene> {
+        self.scenes.get(name)
+    }
+
+    /// All scenes, sorted by name.
+    pub fn scenes(&self) -> impl Iterator<Item = &Scene> {
+        self.scenes.values()
+    }
+
+    /// Recall the scene named `name` with `trigger`, replacing the previous scene of the trigger.
+    pub fn bind(&mut self, trigger: Trigger, name: impl Into<String>) {
+        self.triggers.insert(trigger, name.into());
+    }
+
+    /// Remove a trigger.
+    pub fn unbind(&mut self, trigger: Trigger) {
+        self.triggers.remove(&trigger);
+    }
+
+    /// The scene bound to a macro button.
+    pub fn for_button(&self, button: LogicalButton) -> Option<&Scene> {
+        self.triggered(Trigger::MacroButton(button))
+    }
+
+    /// The scene bound to the first triggering message in a buffer from [`get_midi_message`](VoicemeeterRemote::get_midi_message).
+    pub fn for_midi(&self, messages: &[u8]) -> Option<&Scene> {
+        midi_triggers(messages).find_map(|trigger| self.triggered(trigger))
+    }
+
+    fn triggered(&self, trigger: Trigger) -> Option<&Scene> {
+        self.scenes.get(self.triggers.get(&trigger)?)
+    }
+}
+
+/// The triggers in a buffer of MIDI messages.
+fn midi_triggers(mut messages: &[u8]) -> impl Iterator<Item = Trigger> + '_ {
+    std::iter::from_fn(move || loop {
+        let (status, rest) = messages.split_first()?;
+        // program change and channel pressure have one data byte, system messages are skipped
+        let len = match status {
+            0x80..=0xBF | 0xE0..=0xEF => 2,
+            0xC0..=0xDF => 1,
+            _ => {
+                messages = rest;
+                continue;
+            }
+        };
+        let data = rest.get(..len)?;
+        messages = &rest[len..];
+        let pressed = match status & 0xF0 {
+            0x90 | 0xB0 => data[1] > 0,
+            0x80 => false,
+            _ => true,
+        };
+        if pressed {
+            return Some(Trigger::Midi {
+                status: *status,
+                data1: data[0],
+            });
+        }
+    })
+}
+
+/// How to [crossfade](RampEngine::crossfade) to a scene.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[must_use]
+pub struct Crossfade {
+    /// The duration of the fade.
+    pub duration: Duration,
+    /// The curve of the float parameters.
+    pub curve: Curve,
+    /// The point of the fade where the other parameters switch, from `0.0` at the start to `1.0` at the end.
+    pub switch_at: f32,
+}
+
+impl Crossfade {
+    /// A linear fade over `duration`, switching halfway.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            curve: Curve::Linear,
+            switch_at: 0.5,
+        }
+    }
+
+    /// Set the curve of the float parameters.
+    pub fn curve(self, curve: Curve) -> Self {
+        Self { curve, ..self }
+    }
+
+    /// Set the point of the fade where the other parameters switch, clamped to `0.0..=1.0`.
+    pub fn switch_at(self, switch_at: f32) -> Self {
+        Self {
+            switch_at: switch_at.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+}
+
+impl VoicemeeterRemote {
+    /// Capture the current values of all parameters that can be set as a scene.
+    pub fn capture_scene(&self, name: impl Into<String>) -> Result<Scene, GetParameterError> {
+        Ok(Scene::from_snapshot(name, &self.snapshot()?))
+    }
+
+    /// Set all values of a scene at once, see [`restore_snapshot`](VoicemeeterRemote::restore_snapshot).
+    pub fn recall_scene(&self, scene: &Scene) -> Result<RestoreReport, SetParameterError> {
+        self.restore_snapshot(&MixerSnapshot {
+            program: self.program(),
+            values: scene.values.clone(),
+        })
+    }
+}
+
+impl RampEngine {
+    /// Crossfade to a scene, see the [module documentation](self).
+    ///
+    /// Float parameters ramp from their current value, other parameters switch at [`Crossfade::switch_at`].
+    /// Parameters that are not available in the running program are reported in the [`RestoreReport`],
+    /// the fade still runs for the rest.
+    pub fn crossfade(&self, scene: &Scene, fade: Crossfade) -> Result<RestoreReport, RampError> {
+        let remote = self.remote();
+        let program = remote.program();
+        let writable: HashMap<String, &registry::ParameterInfo> = registry::parameters()
+            .iter()
+            .filter(|p| p.write)
+            .flat_map(|p| {
+                p.names(program)
+                    .into_iter()
+                    .map(move |name| (name.as_str().to_ascii_lowercase(), p))
+            })
+            .collect();
+
+        let mut report = RestoreReport::default();
+        let mut motions = vec![];
+        for (name, value) in &scene.values {
+            let Some(info) = writable.get(&name.as_str().to_ascii_lowercase()) else {
+                report.failed.push(RestoreFailure {
+                    name: name.clone(),
+                    value: value.clone(),
+                    reason: RestoreFailureReason::NotAvailable,
+                });
+                continue;
+            };
+            if is_inactive_mode(name, value) {
+                continue;
+            }
+            // can not be written in a script
+            if script_statement(name, value).is_err() {
+                report.failed.push(RestoreFailure {
+                    name: name.clone(),
+                    value: value.clone(),
+                    reason: RestoreFailureReason::Rejected,
+                });
+                continue;
+            }
+            let motion = match (info.kind, value) {
+                (ParameterKind::Float, ParameterValue::Float(to)) => {
+                    match self.float_start(name, info.read)? {
+                        Some(from) if from == *to => continue,
+                        Some(from) => Motion::Curve {
+                            from,
+                            to: *to,
+                            curve: fade.curve,
+                        },
+                        None => Motion::Switch {
+                            to: value.clone(),
+                            at: fade.switch_at,
+                        },
+                    }
+                }
+                _ => Motion::Switch {
+                    to: value.clone(),
+                    at: fade.switch_at,
+                },
+            };
+            motions.push((name.clone(), motion));
+        }
+        self.start(motions, fade.duration);
+        Ok(report)
+    }
+
+    /// The value a float parameter ramps from, or `None` if it is not known.
+    fn float_start(&self, name: &ParameterNameRef, read: bool) -> Result<Option<f32>, RampError> {
+        if let Some(current) = self.current(name) {
+            return Ok(Some(current));
+        }
+        if read {
+            return Ok(Some(self.remote().get_parameter_float(name)?));
+        }
+        Ok(self.remote().last_set_value(name).and_then(|v| v.as_f32()))
+    }
+}
+
+/// Errors that can happen when loading or saving a [`Scene`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SceneError {
+    /// Could not read or write a file.
+    #[error("could not access `{}`", .path.display())]
+    Io {
+        /// The file or directory.
+        path: PathBuf,
+        /// The error.
+        #[source]
+        source: std::io::Error,
+    },
+    /// The scene script could not be parsed.
+    #[error("could not parse scene{}", .path.as_ref().map(|p| format!(" `{}`", p.display())).unwrap_or_default())]
+    Parse {
+        /// The scene file.
+        path: Option<PathBuf>,
+        /// The error.
+        #[source]
+        error: ParseError,
+    },
+    /// A statement in the scene script does not assign a number or string.
+    #[error("scene statement `{statement}` does not assign a number or string")]
+    UnsupportedStatement {
+        /// The statement.
+        statement: String,
+        /// The span of the statement in the script.
+        span: Span,
+    },
+    /// A value in the scene can not be written in a script.
+    #[error("scene can not be written as a script")]
+    Script(#[from] SetParameterError),
+}
+
+impl From<ParseError> for SceneError {
+    fn from(error: ParseError) -> Self {
+        SceneError::Parse { path: None, error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn recall_and_crossfade() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let strip = remote.parameters().strip(0)?;
+
+        strip.gain().set(-10.0)?;
+        strip.mute().set(true)?;
+        let captured = remote.capture_scene("captured")?;
+        assert_eq!(
+            captured.values.get(&ParameterName::from("Strip[0].Gain")),
+            Some(&ParameterValue::Float(-10.0))
+        );
+        strip.gain().set(0.0)?;
+        strip.mute().set(false)?;
+        assert!(remote.recall_scene(&captured)?.is_complete());
+        assert_eq!(strip.gain().get()?, -10.0);
+        assert!(strip.mute().get()?);
+
+        let scene = Scene::parse(
+            "vocals",
+            "Strip[0].Gain = 6\nStrip[0].Mute = 0\nStrip[0].Label = \"Vocals\"\nStrip[9].Gain = 0",
+        )?;
+        let ramps = remote.ramps_every(Duration::from_millis(1))?;
+        let report = ramps.crossfade(
+            &scene,
+            Crossfade::new(Duration::from_millis(300)).switch_at(0.9),
+        )?;
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].name.as_str(), "Strip[9].Gain");
+        std::thread::sleep(Duration::from_millis(50));
+        let gain = simulator.float("Strip[0].Gain")?;
+        assert!(gain > -10.0 && gain < 6.0, "{gain}");
+        assert_eq!(simulator.float("Strip[0].Mute")?, 1.0);
+        ramps.wait();
+        assert_eq!(simulator.float("Strip[0].Gain")?, 6.0);
+        assert_eq!(simulator.float("Strip[0].Mute")?, 0.0);
+        assert_eq!(simulator.string("Strip[0].Label")?, "Vocals");
+
+        assert!(matches!(
+            Scene::parse("bad", "Strip[0].Gain += 1"),
+            Err(SceneError::UnsupportedStatement { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn save_and_load() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let path =
+            std::env::temp_dir().join(format!("voicemeeter-scene-{}.scene", std::process::id()));
+
+        simulator.gui_set_string("Strip[0].Label", "Guitar 'Amp' = 2, left")?;
+        let scene = remote.capture_scene("guitar")?;
+        scene.save(&path)?;
+        let loaded = Scene::load(&path);
+        std::fs::remove_file(&path)?;
+        assert_eq!(loaded?.values, scene.values);
+
+        let label = "a \"quote\"\nStrip[1].Mute = 1";
+        simulator.gui_set_string("Strip[0].Label", label)?;
+        let scene = remote.capture_scene("broken")?;
+        assert!(matches!(
+            scene.save(&path),
+            Err(SceneError::Script(SetParameterError::InvalidString { name, .. })) if name == "Strip[0].Label"
+        ));
+        assert!(!path.exists());
+
+        simulator.gui_set_string("Strip[0].Label", "")?;
+        let engine = remote.ramps()?;
+        let report = engine.crossfade(&scene, Crossfade::new(Duration::from_millis(50)))?;
+        engine.wait();
+        assert_eq!(
+            report.failed,
+            vec![RestoreFailure {
+                name: "Strip[0].Label".into(),
+                value: ParameterValue::String(label.to_owned()),
+                reason: RestoreFailureReason::Rejected,
+            }]
+        );
+        assert_eq!(simulator.string("Strip[0].Label")?, "");
+        assert_eq!(simulator.float("Strip[1].Mute")?, 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn book() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = std::env::temp_dir().join(format!("voicemeeter-scenes-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let scene = Scene::parse("live", "Strip[0].Gain = -3.5\nStrip[1].Label = \"Guitar\"")?;
+        scene.save(dir.join("live.scene"))?;
+        std::fs::write(dir.join("notes.txt"), "not a scene")?;
+        let mut book = SceneBook::load_dir(&dir)?;
+        std::fs::remove_dir_all(&dir)?;
+        assert_eq!(book.get("live"), Some(&scene));
+        assert_eq!(book.scenes().count(), 1);
+
+        book.bind(Trigger::MacroButton(LogicalButton::from(3)), "live");
+        book.bind(
+            Trigger::Midi {
+                status: 0x90,
+                data1: 60,
+            },
+            "live",
+        );
+        assert_eq!(book.for_button(LogicalButton::from(3)), Some(&scene));
+        assert_eq!(book.for_button(LogicalButton::from(4)), None);
+        assert_eq!(book.for_midi(&[0xB0, 1, 5, 0x90, 60, 127]), Some(&scene));
+        assert_eq!(book.for_midi(&[0x90, 60, 0]), None);
+        book.remove("live");
+        assert_eq!(book.for_midi(&[0x90, 60, 127]), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+//! Typed scripts for [`set_parameters`](VoicemeeterRemote::set_parameters).
+//!
+//! A [`Script`] collects assignments to the parameters returned by [`VoicemeeterRemote::parameters`]
+//! and sets all of them with a single call to Voicemeeter.
+//!
+//! Assignments are checked when they are added to the script: read-only parameters can not be assigned,
+//! values must be in the [range](super::FloatParameter::range) of the parameter, and the parameter must be supported by the running program.
+//!
+//! See [`syntax`] to parse, validate and pretty-print scripts written by hand.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use voicemeeter::{interface::parameters::script::Script, types::BusMode, VoicemeeterRemote};
+//!
+//! let remote = VoicemeeterRemote::new()?;
+//! let parameters = remote.parameters();
+//! let strip = parameters.strip(0)?;
+//! let bus = parameters.bus(1)?;
+//!
+//! Script::new()
+//!     .set(&strip.gain(), -6.0)?
+//!     .set(&strip.label(), "Microphone")?
+//!     .set(&strip.a1(), true)?
+//!     .set(&bus.mute(), false)?
+//!     .set(&bus.mode(), BusMode::TvMix)?
+//!     .apply(&remote)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+pub mod syntax;
+
+use crate::VoicemeeterRemote;
+
+use super::{
+    registry,
+    set_parameters::{SetParameterError, SetParametersError},
+    tuple, BoolParameter, FloatParameter, IntParameter, StringParameter, TupleElement,
+    TupleParameter,
+};
+use crate::types::ParameterNameRef;
+
+/// A parameter that can be assigned a value of type `V` in a [`Script`].
+///
+/// Only writable parameters implement this trait.
+pub trait Assignable<V> {
+    /// The statement assigning `value` to this parameter, e.g. `Strip[0].Gain = -6`.
+    fn assignment(&self, value: V) -> Result<String, SetParameterError>;
+}
+
+/// A script setting multiple parameters at once, see the [module documentation](self).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use = "apply the script"]
+pub struct Script {
+    statements: Vec<String>,
+}
+
+impl Script {
+    /// Create an empty script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assign `value` to `parameter`.
+    ///
+    /// Fails if the value is out of range, or if the parameter is not supported by the program of its remote.
+    pub fn set<V>(
+        mut self,
+        parameter: &impl Assignable<V>,
+        value: V,
+    ) -> Result<Self, SetParameterError> {
+        self.statements.push(parameter.assignment(value)?);
+        Ok(self)
+    }
+
+    /// Returns `true` if the script has no assignments.
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    /// Number of assignments in the script.
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    /// Set all parameters with [`VoicemeeterRemote::set_parameters`].
+    ///
+    /// An empty script is not sent to Voicemeeter.
+    pub fn apply(&self, remote: &VoicemeeterRemote) -> Result<(), SetParametersError> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        remote.set_parameters(&self.to_string())
+    }
+}
+
+impl std::fmt::Display for Script {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for statement in &self.statements {
+            writeln!(f, "{statement}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that the program of `remote` supports `name`.
+pub(crate) fn check_supported(
+    remote: &VoicemeeterRemote,
+    name: &ParameterNameRef,
+) -> Result<(), SetParameterError> {
+    match registry::lookup(name.as_str()) {
+        Some(info) if !info.supports(remote.program()) => Err(SetParameterError::NotSupported {
+            name: name.to_string(),
+            program: remote.program(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Quote a string value, rejecting values that can not be written in a script.
+pub(crate) fn quote(name: &ParameterNameRef, value: &str) -> Result<String, SetParameterError> {
+    if value.contains(['"', '\n', '\r', '\0']) {
+        return Err(SetParameterError::InvalidString {
+            name: name.to_string(),
+            value: value.to_owned(),
+        });
+    }
+    Ok(format!("\"{value}\""))
+}
+
+impl<const READ: bool> Assignable<f32> for FloatParameter<'_, true, READ> {
+    fn assignment(&self, value: f32) -> Result<String, SetParameterError> {
+        check_supported(self.remote, &self.name)?;
+        Ok(format!("{} = {}", self.name, self.checked(value)?))
+    }
+}
+
+impl<const READ: bool> Assignable<i32> for IntParameter<'_, true, READ> {
+    fn assignment(&self, value: i32) -> Result<String, SetParameterError> {
+        check_supported(self.remote, &self.name)?;
+        Ok(format!("{} = {}", self.name, self.checked(value)?))
+    }
+}
+
+impl<const READ: bool> Assignable<bool> for BoolParameter<'_, true, READ> {
+    fn assignment(&self, value: bool) -> Result<String, SetParameterError> {
+        check_supported(self.remote, &self.name)?;
+        Ok(format!("{} = {}", self.name, u8::from(value)))
+    }
+}
+
+impl<S: AsRef<str>, const READ: bool> Assignable<S> for StringParameter<'_, true, READ> {
+    fn assignment(&self, value: S) -> Result<String, SetParameterError> {
+        check_supported(self.remote, &self.name)?;
+        Ok(format!(
+            "{} = {}",
+            self.name,
+            quote(&self.name, value.as_ref())?
+        ))
+    }
+}
+
+impl<A: TupleElement, B: TupleElement, const READ: bool> Assignable<(A, B)>
+    for TupleParameter<'_, A, B, true, READ>
+{
+    fn assignment(&self, (a, b): (A, B)) -> Result<String, SetParameterError> {
+        check_supported(self.remote, &self.name)?;
+        Ok(format!(
+            "{} = {}",
+            self.name,
+            tuple::write(&self.name, &a, &b)?
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        backend::simulator::remote,
+        types::{BusMode, VoicemeeterApplication},
+    };
+
+    use super::*;
+
+    #[test]
+    fn build_and_apply() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let parameters = remote.parameters();
+        let strip = parameters.strip(0)?;
+        let bus = parameters.bus(1)?;
+
+        let script = Script::new()
+            .set(&strip.gain(), -6.5)?
+            .set(&strip.label(), "Mic")?
+            .set(&strip.a2(), true)?
+            .set(&strip.limit(), -3)?
+            .set(&bus.mode(), BusMode::TvMix)?
+            .set(&strip.fade_to(), (-10, 200))?;
+        assert_eq!(
+            script.to_string(),
+            "Strip[0].Gain = -6.5\n\
+             Strip[0].Label = \"Mic\"\n\
+             Strip[0].A2 = 1\n\
+             Strip[0].Limit = -3\n\
+             Bus[1].mode.TVMix = 1\n\
+             Strip[0].FadeTo = (-10, 200)\n"
+        );
+        script.apply(&remote)?;
+        assert_eq!(simulator.float("Strip[0].Gain")?, -6.5);
+        assert_eq!(simulator.string("Strip[0].Label")?, "Mic");
+        assert_eq!(simulator.float("Bus[1].mode.TVMix")?, 1.0);
+
+        assert!(matches!(
+            Script::new().set(&strip.gain(), 13.0),
+            Err(SetParameterError::OutOfRange { .. })
+        ));
+        assert_eq!(
+            Script::new()
+                .set(&strip.gain().clamped(), 13.0)?
+                .to_string(),
+            "Strip[0].Gain = 12\n"
+        );
+        assert!(matches!(
+            Script::new().set(&strip.a4(), true),
+            Err(SetParameterError::NotSupported { .. })
+        ));
+        assert!(matches!(
+            Script::new().set(&strip.label(), "a \"quote\""),
+            Err(SetParameterError::InvalidString { .. })
+        ));
+        Script::new().apply(&remote)?;
+        Ok(())
+    }
+}
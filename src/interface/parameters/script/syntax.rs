@@ -0,0 +1,770 @@
+//! Parser, validator and pretty-printer for the script language of macro buttons and [`set_parameters`](crate::VoicemeeterRemote::set_parameters).
+//!
+//! A script is a list of statements separated by `;`, `,` or line breaks. A statement assigns a value to a parameter
+//! with `=`, or adds to or subtracts from its current value with `+=` and `-=`.
+//! Values are numbers, strings in double quotes, or tuples like `("Discord", 0.5)`. Comments start with `//` and end at the line break.
+//!
+//! ```text
+//! Strip[0].Mute = 1; Bus[2].Gain += 3
+//! Strip[1].Label = "Microphone" // the label
+//! Strip[0].FadeTo = (-10, 200)
+//! ```
+//!
+//! [`parse`] returns an [`Ast`], which can be [validated](Ast::validate) against a program and printed back with [`Display`](std::fmt::Display).
+//! All errors carry the [`Span`] of the offending text.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use voicemeeter::interface::parameters::script::syntax::{self, ValidationErrorKind};
+//! use voicemeeter::types::VoicemeeterApplication;
+//!
+//! let source = "strip[0].mute=1;Bus[5].Gain += 3 // louder";
+//! let ast = syntax::parse(source)?;
+//! assert_eq!(ast.to_string(), "strip[0].mute = 1\nBus[5].Gain += 3\n// louder\n");
+//!
+//! // Voicemeeter Banana has five buses
+//! let errors = ast.validate(VoicemeeterApplication::VoicemeeterBanana);
+//! assert_eq!(errors.len(), 1);
+//! assert!(matches!(errors[0].kind, ValidationErrorKind::NoSuchIndex { .. }));
+//! assert_eq!(&source[errors[0].span.range()], "Bus[5].Gain");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::ops::{Range, RangeInclusive};
+
+use crate::types::VoicemeeterApplication;
+
+use super::super::registry::{self, ParameterKind};
+
+/// A range of bytes in the source of a script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Offset of the first byte.
+    pub start: usize,
+    /// Offset after the last byte.
+    pub end: usize,
+}
+
+impl Span {
+    const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// The span as a range, to index the source with.
+    pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// The line and column of the start of the span in `source`, both starting at 1.
+    ///
+    /// The column is counted in characters.
+    pub fn line_column(&self, source: &str) -> (usize, usize) {
+        let before = &source[..self.start.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+}
+
+impl From<Span> for Range<usize> {
+    fn from(span: Span) -> Self {
+        span.range()
+    }
+}
+
+/// A parsed script.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ast {
+    /// The statements and comments, in source order.
+    pub items: Vec<Item>,
+}
+
+/// A statement or comment in a script.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    /// A statement, e.g. `Strip[0].Mute = 1`.
+    Statement(Statement),
+    /// A comment, e.g. `// mute the microphone`.
+    Comment(Comment),
+}
+
+/// A statement setting a parameter, e.g. `Bus[2].Gain += 3`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The parameter name, as written.
+    pub name: String,
+    /// Span of the name.
+    pub name_span: Span,
+    /// The operator.
+    pub operator: Operator,
+    /// Span of the operator.
+    pub operator_span: Span,
+    /// The value.
+    pub value: Value,
+    /// Span of the value.
+    pub value_span: Span,
+}
+
+impl Statement {
+    /// Span of the whole statement.
+    pub const fn span(&self) -> Span {
+        Span::new(self.name_span.start, self.value_span.end)
+    }
+}
+
+/// A comment, e.g. `// mute the microphone`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    /// The text after `//`, without surrounding whitespace.
+    pub text: String,
+    /// Span of the comment, including `//`.
+    pub span: Span,
+}
+
+/// The operator of a [`Statement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    /// `=`
+    Assign,
+    /// `+=`
+    Add,
+    /// `-=`
+    Subtract,
+}
+
+impl std::fmt::Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Operator::Assign => "=",
+            Operator::Add => "+=",
+            Operator::Subtract => "-=",
+        })
+    }
+}
+
+/// The value of a [`Statement`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A number, e.g. `-6.5`.
+    Number(f32),
+    /// A string, without the quotes.
+    String(String),
+    /// A tuple of numbers and strings, e.g. `("Discord", 0.5)`.
+    Tuple(Vec<Value>),
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{number}"),
+            Value::String(string) => write!(f, "\"{string}\""),
+            Value::Tuple(values) => {
+                f.write_str("(")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.name, self.operator, self.value)
+    }
+}
+
+/// Prints one statement or comment per line.
+impl std::fmt::Display for Ast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in &self.items {
+            match item {
+                Item::Statement(statement) => writeln!(f, "{statement}")?,
+                Item::Comment(comment) if comment.text.is_empty() => writeln!(f, "//")?,
+                Item::Comment(comment) => writeln!(f, "// {}", comment.text)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Ast {
+    /// The statements of the script, without comments.
+    pub fn statements(&self) -> impl Iterator<Item = &Statement> {
+        self.items.iter().filter_map(|item| match item {
+            Item::Statement(statement) => Some(statement),
+            Item::Comment(_) => None,
+        })
+    }
+
+    /// Check all statements against the parameters of `program`, see [`ValidationErrorKind`].
+    ///
+    /// Returns an empty list if the script is valid.
+    pub fn validate(&self, program: VoicemeeterApplication) -> Vec<ValidationError> {
+        self.statements()
+            .filter_map(|statement| validate_statement(statement, program).err())
+            .collect()
+    }
+}
+
+/// An error from [`parse`].
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("{kind} at {}..{}", .span.start, .span.end)]
+pub struct ParseError {
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+    /// The offending text.
+    pub span: Span,
+}
+
+/// The kind of a [`ParseError`].
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// Expected a parameter name.
+    #[error("expected a parameter name")]
+    ExpectedName,
+    /// An index in a parameter name is not a number, or is not closed.
+    #[error("invalid index in parameter name")]
+    InvalidIndex,
+    /// Expected `=`, `+=` or `-=`.
+    #[error("expected `=`, `+=` or `-=`")]
+    ExpectedOperator,
+    /// Expected a number, string or tuple.
+    #[error("expected a value")]
+    ExpectedValue,
+    /// A number could not be parsed.
+    #[error("invalid number")]
+    InvalidNumber,
+    /// A string is not closed before the end of the line.
+    #[error("unterminated string")]
+    UnterminatedString,
+    /// A tuple is not closed before the end of the line.
+    #[error("unterminated tuple")]
+    UnterminatedTuple,
+    /// A statement is followed by something other than `;`, `,`, a line break or a comment.
+    #[error("expected `;`, `,` or a line break")]
+    ExpectedSeparator,
+}
+
+/// An error from [`Ast::validate`].
+#[derive(Debug, thiserror::Error, Clone, PartialEq)]
+#[error("{kind} at {}..{}", .span.start, .span.end)]
+pub struct ValidationError {
+    /// What went wrong.
+    pub kind: ValidationErrorKind,
+    /// The offending text.
+    pub span: Span,
+}
+
+/// The kind of a [`ValidationError`].
+#[derive(Debug, thiserror::Error, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+    /// The parameter is not known, see [`registry`].
+    #[error("unknown parameter `{0}`")]
+    UnknownParameter(String),
+    /// The parameter is not supported by the program.
+    #[error("parameter `{name}` is not supported on `{program}`")]
+    NotSupported {
+        /// The parameter.
+        name: String,
+        /// The program.
+        program: VoicemeeterApplication,
+    },
+    /// An index in the parameter name is out of range for the program, e.g. `Strip[7]` on Voicemeeter Banana.
+    #[error("parameter `{name}` does not exist on `{program}`")]
+    NoSuchIndex {
+        /// The parameter.
+        name: String,
+        /// The program.
+        program: VoicemeeterApplication,
+    },
+    /// The parameter can not be set.
+    #[error("parameter `{0}` is read-only")]
+    ReadOnly(String),
+    /// `+=` or `-=` was used on a parameter that is not a number.
+    #[error("parameter `{0}` can not be incremented")]
+    NotIncremental(String),
+    /// The value has the wrong type for the parameter.
+    #[error("expected {} for parameter `{name}`", expected_value(.expected))]
+    WrongType {
+        /// The parameter.
+        name: String,
+        /// The kind of the parameter.
+        expected: ParameterKind,
+    },
+    /// The value is out of the range of the parameter, see [`ParameterInfo`](registry::ParameterInfo).
+    #[error("value {value} is out of range {}..={} for parameter `{name}`", .range.start(), .range.end())]
+    OutOfRange {
+        /// The parameter.
+        name: String,
+        /// The rejected value.
+        value: f32,
+        /// The range of the parameter.
+        range: RangeInclusive<f32>,
+    },
+}
+
+fn expected_value(kind: &ParameterKind) -> &'static str {
+    match kind {
+        ParameterKind::Bool => "0 or 1",
+        ParameterKind::Int => "an integer",
+        ParameterKind::Float => "a number",
+        ParameterKind::String => "a string",
+        ParameterKind::Tuple => "a tuple",
+    }
+}
+
+fn validate_statement(
+    statement: &Statement,
+    program: VoicemeeterApplication,
+) -> Result<(), ValidationError> {
+    let name = &statement.name;
+    let at_name = |kind| ValidationError {
+        kind,
+        span: statement.name_span,
+    };
+    let info = registry::lookup(name)
+        .ok_or_else(|| at_name(ValidationErrorKind::UnknownParameter(name.clone())))?;
+    if !info.supports(program) {
+        return Err(at_name(ValidationErrorKind::NotSupported {
+            name: name.clone(),
+            program,
+        }));
+    }
+    // Names with indexes that can not be enumerated, like applications, are not checked.
+    let names = info.names(program);
+    if !names.is_empty() && !names.iter().any(|n| n.as_str().eq_ignore_ascii_case(name)) {
+        return Err(at_name(ValidationErrorKind::NoSuchIndex {
+            name: name.clone(),
+            program,
+        }));
+    }
+    if !info.write {
+        return Err(at_name(ValidationErrorKind::ReadOnly(name.clone())));
+    }
+
+    let at_value = |kind| ValidationError {
+        kind,
+        span: statement.value_span,
+    };
+    let wrong_type = || {
+        at_value(ValidationErrorKind::WrongType {
+            name: name.clone(),
+            expected: info.kind,
+        })
+    };
+    let number = match (info.kind, &statement.value) {
+        (ParameterKind::Float | ParameterKind::Int, Value::Number(number)) => *number,
+        (ParameterKind::Bool, Value::Number(number)) if statement.operator == Operator::Assign => {
+            if *number != 0.0 && *number != 1.0 {
+                return Err(wrong_type());
+            }
+            return Ok(());
+        }
+        (ParameterKind::Bool | ParameterKind::String | ParameterKind::Tuple, _)
+            if statement.operator != Operator::Assign =>
+        {
+            return Err(ValidationError {
+                kind: ValidationErrorKind::NotIncremental(name.clone()),
+                span: statement.operator_span,
+            });
+        }
+        (ParameterKind::String, Value::String(_)) => return Ok(()),
+        (ParameterKind::Tuple, Value::Tuple(values)) if values.len() == 2 => return Ok(()),
+        _ => return Err(wrong_type()),
+    };
+    if info.kind == ParameterKind::Int && number.fract() != 0.0 {
+        return Err(wrong_type());
+    }
+    match &info.range {
+        // The result of `+=` and `-=` depends on the current value, so only assignments are checked.
+        Some(range) if statement.operator == Operator::Assign && !range.contains(&number) => {
+            Err(at_value(ValidationErrorKind::OutOfRange {
+                name: name.clone(),
+                value: number,
+                range: range.clone(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Parse a script, see the [module documentation](self) for the syntax.
+pub fn parse(source: &str) -> Result<Ast, ParseError> {
+    Parser { source, pos: 0 }.script()
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    fn rest(&self) -> &str {
+        &self.source[self.pos..]
+    }
+
+    /// Skip spaces and tabs, but not line breaks.
+    fn skip_blank(&mut self) {
+        while let Some(b' ' | b'\t' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    /// An error at the character at the current position.
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        let len = self.rest().chars().next().map_or(0, char::len_utf8);
+        self.error_from(kind, self.pos, self.pos + len)
+    }
+
+    fn error_from(&self, kind: ParseErrorKind, start: usize, end: usize) -> ParseError {
+        ParseError {
+            kind,
+            span: Span::new(start, end),
+        }
+    }
+
+    /// Offset of the end of the current line.
+    fn line_end(&self) -> usize {
+        self.rest()
+            .find('\n')
+            .map_or(self.source.len(), |i| self.pos + i)
+    }
+
+    fn script(mut self) -> Result<Ast, ParseError> {
+        let mut items = vec![];
+        let mut separated = true;
+        loop {
+            self.skip_blank();
+            match self.peek() {
+                None => break,
+                Some(b';' | b',' | b'\n') => {
+                    self.pos += 1;
+                    separated = true;
+                }
+                Some(b'/') if self.rest().starts_with("//") => {
+                    items.push(Item::Comment(self.comment()));
+                }
+                Some(_) if separated => {
+                    items.push(Item::Statement(self.statement()?));
+                    separated = false;
+                }
+                Some(_) => return Err(self.error(ParseErrorKind::ExpectedSeparator)),
+            }
+        }
+        Ok(Ast { items })
+    }
+
+    fn comment(&mut self) -> Comment {
+        let start = self.pos;
+        let end = self.line_end();
+        self.pos = end;
+        Comment {
+            text: self.source[start + 2..end].trim().to_owned(),
+            span: Span::new(start, end),
+        }
+    }
+
+    fn statement(&mut self) -> Result<Statement, ParseError> {
+        let name_start = self.pos;
+        let name = self.name()?.to_owned();
+        let name_span = Span::new(name_start, self.pos);
+
+        self.skip_blank();
+        let operator_start = self.pos;
+        let operator = if self.rest().starts_with('=') {
+            Operator::Assign
+        } else if self.rest().starts_with("+=") {
+            Operator::Add
+        } else if self.rest().starts_with("-=") {
+            Operator::Subtract
+        } else {
+            return Err(self.error(ParseErrorKind::ExpectedOperator));
+        };
+        self.pos += operator.to_string().len();
+        let operator_span = Span::new(operator_start, self.pos);
+
+        self.skip_blank();
+        let value_start = self.pos;
+        let value = match self.peek() {
+            Some(b'(') => self.tuple()?,
+            _ => self.scalar()?,
+        };
+        Ok(Statement {
+            name,
+            name_span,
+            operator,
+            operator_span,
+            value,
+            value_span: Span::new(value_start, self.pos),
+        })
+    }
+
+    /// A name like `Strip[0].Mute` or `Recorder.ArmStrip(1)`.
+    fn name(&mut self) -> Result<&str, ParseError> {
+        let start = self.pos;
+        if !self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            return Err(self.error(ParseErrorKind::ExpectedName));
+        }
+        while let Some(c) = self.peek() {
+            match c {
+                c if c.is_ascii_alphanumeric() || c == b'_' || c == b'.' => self.pos += 1,
+                b'[' | b'(' => {
+                    let close = if c == b'[' { b']' } else { b')' };
+                    let index_start = self.pos;
+                    self.pos += 1;
+                    let digits = self
+                        .rest()
+                        .bytes()
+                        .take_while(|c| c.is_ascii_digit())
+                        .count();
+                    self.pos += digits;
+                    if digits == 0 || self.peek() != Some(close) {
+                        let end = self
+                            .rest()
+                            .find(char::from(close))
+                            .map_or(self.pos, |i| (self.pos + i + 1).min(self.line_end()));
+                        return Err(self.error_from(
+                            ParseErrorKind::InvalidIndex,
+                            index_start,
+                            end.max(self.pos),
+                        ));
+                    }
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    /// A number or a string.
+    fn scalar(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        match self.peek() {
+            Some(b'"') => {
+                let end = self.line_end();
+                match self.source[start + 1..end].find('"') {
+                    Some(len) => {
+                        self.pos = start + 1 + len + 1;
+                        Ok(Value::String(
+                            self.source[start + 1..start + 1 + len].to_owned(),
+                        ))
+                    }
+                    None => Err(self.error_from(ParseErrorKind::UnterminatedString, start, end)),
+                }
+            }
+            Some(b'0'..=b'9' | b'-' | b'+' | b'.') => {
+                let len = self
+                    .rest()
+                    .bytes()
+                    .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'+' | b'.'))
+                    .count();
+                self.pos += len;
+                self.source[start..self.pos]
+                    .parse()
+                    .map(Value::Number)
+                    .map_err(|_| self.error_from(ParseErrorKind::InvalidNumber, start, self.pos))
+            }
+            _ => Err(self.error(ParseErrorKind::ExpectedValue)),
+        }
+    }
+
+    /// A tuple like `(-10, 200)`.
+    fn tuple(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        let unterminated = |this: &Self| {
+            this.error_from(ParseErrorKind::UnterminatedTuple, start, this.line_end())
+        };
+        self.pos += 1;
+        let mut values = vec![];
+        loop {
+            self.skip_blank();
+            match self.peek() {
+                Some(b')') if values.is_empty() => break,
+                None | Some(b'\n') => return Err(unterminated(self)),
+                _ => values.push(self.scalar()?),
+            }
+            self.skip_blank();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b')') => break,
+                None | Some(b'\n') => return Err(unterminated(self)),
+                _ => return Err(self.error(ParseErrorKind::ExpectedSeparator)),
+            }
+        }
+        self.pos += 1;
+        Ok(Value::Tuple(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_print() -> Result<(), ParseError> {
+        let source = "// setup\n\
+                      Strip[0].Mute = 1; Bus[2].Gain += 3,Bus[1].Gain-=1.5\r\n\
+                      \n\
+                      Strip[1].Label = \"Mic; 1\"  //  label\n\
+                      Strip[0].AppGain = (\"Discord\", 0.5)\n\
+                      Recorder.ArmStrip(1) = 1;";
+        let ast = parse(source)?;
+        assert_eq!(
+            ast.to_string(),
+            "// setup\n\
+             Strip[0].Mute = 1\n\
+             Bus[2].Gain += 3\n\
+             Bus[1].Gain -= 1.5\n\
+             Strip[1].Label = \"Mic; 1\"\n\
+             // label\n\
+             Strip[0].AppGain = (\"Discord\", 0.5)\n\
+             Recorder.ArmStrip(1) = 1\n"
+        );
+        assert_eq!(parse(&ast.to_string())?.to_string(), ast.to_string());
+
+        let statement = ast.statements().nth(2).unwrap();
+        assert_eq!(&source[statement.name_span.range()], "Bus[1].Gain");
+        assert_eq!(&source[statement.operator_span.range()], "-=");
+        assert_eq!(&source[statement.value_span.range()], "1.5");
+        assert_eq!(&source[statement.span().range()], "Bus[1].Gain-=1.5");
+        assert_eq!(statement.span().line_column(source), (2, 37));
+
+        fn error(source: &str) -> (ParseErrorKind, &str) {
+            let error = parse(source).unwrap_err();
+            (error.kind, &source[error.span.range()])
+        }
+        assert_eq!(error("= 1"), (ParseErrorKind::ExpectedName, "="));
+        assert_eq!(
+            error("Strip[x].Mute = 1"),
+            (ParseErrorKind::InvalidIndex, "[x]")
+        );
+        assert_eq!(
+            error("Strip[0].Mute 1"),
+            (ParseErrorKind::ExpectedOperator, "1")
+        );
+        assert_eq!(
+            error("Strip[0].Mute ="),
+            (ParseErrorKind::ExpectedValue, "")
+        );
+        assert_eq!(
+            error("Strip[0].Gain = 1x2"),
+            (ParseErrorKind::InvalidNumber, "1x2")
+        );
+        assert_eq!(
+            error("Strip[0].Label = \"Mic\nStrip[0].Mute = 1"),
+            (ParseErrorKind::UnterminatedString, "\"Mic")
+        );
+        assert_eq!(
+            error("Strip[0].FadeTo = (-10, 200"),
+            (ParseErrorKind::UnterminatedTuple, "(-10, 200")
+        );
+        assert_eq!(
+            error("Strip[0].Mute = 1 Strip[1].Mute = 1"),
+            (ParseErrorKind::ExpectedSeparator, "S")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validate() -> Result<(), ParseError> {
+        let source = "Strip[0].Mute = 1\n\
+                      Strip[0].Nope = 1\n\
+                      Strip[0].A4 = 1\n\
+                      Strip[5].Mute = 1\n\
+                      Strip[0].Gain = 13\n\
+                      Strip[0].Gain += 13\n\
+                      Strip[0].Mute = 2\n\
+                      Strip[0].Label += 1\n\
+                      Strip[0].Limit = 1.5\n\
+                      Strip[0].FadeTo = (-10, 200)\n\
+                      Strip[0].FadeTo = 1\n\
+                      Strip[0].App[3].Gain = 0.5";
+        let ast = parse(source)?;
+        let errors: Vec<_> = ast
+            .validate(VoicemeeterApplication::VoicemeeterBanana)
+            .into_iter()
+            .map(|e| {
+                let (line, _) = e.span.line_column(source);
+                (line, e.kind, &source[e.span.range()])
+            })
+            .collect();
+        let program = VoicemeeterApplication::VoicemeeterBanana;
+        assert_eq!(
+            errors,
+            [
+                (
+                    2,
+                    ValidationErrorKind::UnknownParameter("Strip[0].Nope".into()),
+                    "Strip[0].Nope"
+                ),
+                (
+                    3,
+                    ValidationErrorKind::NotSupported {
+                        name: "Strip[0].A4".into(),
+                        program
+                    },
+                    "Strip[0].A4"
+                ),
+                (
+                    4,
+                    ValidationErrorKind::NoSuchIndex {
+                        name: "Strip[5].Mute".into(),
+                        program
+                    },
+                    "Strip[5].Mute"
+                ),
+                (
+                    5,
+                    ValidationErrorKind::OutOfRange {
+                        name: "Strip[0].Gain".into(),
+                        value: 13.0,
+                        range: -60.0..=12.0
+                    },
+                    "13"
+                ),
+                (
+                    7,
+                    ValidationErrorKind::WrongType {
+                        name: "Strip[0].Mute".into(),
+                        expected: ParameterKind::Bool
+                    },
+                    "2"
+                ),
+                (
+                    8,
+                    ValidationErrorKind::NotIncremental("Strip[0].Label".into()),
+                    "+="
+                ),
+                (
+                    9,
+                    ValidationErrorKind::WrongType {
+                        name: "Strip[0].Limit".into(),
+                        expected: ParameterKind::Int
+                    },
+                    "1.5"
+                ),
+                (
+                    11,
+                    ValidationErrorKind::WrongType {
+                        name: "Strip[0].FadeTo".into(),
+                        expected: ParameterKind::Tuple
+                    },
+                    "1"
+                ),
+            ]
+        );
+        Ok(())
+    }
+}
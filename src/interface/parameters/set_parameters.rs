@@ -5,12 +5,21 @@
 //! * [`set_parameter_float`](VoicemeeterRemote::set_parameter_float)
 //! * [`set_parameter_string`](VoicemeeterRemote::set_parameter_string)
 //! * [`set_parameters`](VoicemeeterRemote::set_parameters)
-use std::ffi::CString;
+//!
+//! See also [`Script`](super::script::Script) to build a script from typed parameters.
+//!
+//! String values and scripts are sent to Voicemeeter as UTF-16, so they can contain any unicode character except nul.
+use std::{
+    ffi::{CString, NulError},
+    ops::Bound,
+};
 
-use crate::types::ParameterNameRef;
+use crate::types::{ParameterNameRef, VoicemeeterApplication};
 
 use crate::VoicemeeterRemote;
 
+use super::ParameterValue;
+
 impl VoicemeeterRemote {
     /// Set the float value of a parameter. See also [`VoicemeeterRemote::parameters()`] to do this with functions.
     #[tracing::instrument(skip(self))]
@@ -19,14 +28,16 @@ impl VoicemeeterRemote {
         param: &ParameterNameRef,
         value: f32,
     ) -> Result<(), SetParameterError> {
-        let param = CString::new(param.as_ref()).unwrap();
+        let param = CString::new(param.as_ref())?;
         tracing::debug!("setting float parameter");
-        let res = unsafe {
-            self.raw
-                .VBVMR_SetParameterFloat(param.as_ptr() as *mut _, value)
-        };
+        let res = self.backend.set_parameter_float(&param, value);
         match res {
-            0 => Ok(()),
+            0 => {
+                self.login_state
+                    .written
+                    .record(&param.to_string_lossy(), ParameterValue::Float(value));
+                Ok(())
+            }
             -1 => Err(SetParameterError::CannotGetClient),
             -2 => Err(SetParameterError::NoServer),
             -3 => Err(SetParameterError::UnknownParameter(
@@ -43,15 +54,20 @@ impl VoicemeeterRemote {
         param: &ParameterNameRef,
         value: &str,
     ) -> Result<(), SetParameterError> {
-        let param = CString::new(param.as_ref()).unwrap();
-        let value = CString::new(value).unwrap();
+        let param = CString::new(param.as_ref())?;
+        let wide = encode_wide(value).ok_or_else(|| SetParameterError::ValueContainsNul {
+            name: param.to_string_lossy().into_owned(),
+        })?;
         tracing::debug!("setting string parameter");
-        let res = unsafe {
-            self.raw
-                .VBVMR_SetParameterStringA(param.as_ptr() as *mut _, value.as_ptr() as *mut _)
-        };
+        let res = self.backend.set_parameter_string_w(&param, &wide);
         match res {
-            0 => Ok(()),
+            0 => {
+                self.login_state.written.record(
+                    &param.to_string_lossy(),
+                    ParameterValue::String(value.to_owned()),
+                );
+                Ok(())
+            }
             -1 => Err(SetParameterError::CannotGetClient),
             -2 => Err(SetParameterError::NoServer),
             -3 => Err(SetParameterError::UnknownParameter(
@@ -61,15 +77,35 @@ impl VoicemeeterRemote {
         }
     }
 
-    // TODO: Example script.
     /// Set parameters using a script. Similar to macro button scripts.
+    ///
+    /// Statements are separated by new lines, `;` or `,`. Use a [`Script`](super::script::Script) to build a script from typed parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use voicemeeter::VoicemeeterRemote;
+    ///
+    /// let remote = VoicemeeterRemote::new()?;
+    /// remote.set_parameters(
+    ///     "Strip[0].Gain = -6.0
+    ///      Strip[0].Label = \"Microphone\"
+    ///      Bus[0].Mute = 1",
+    /// )?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn set_parameters(&self, script: &str) -> Result<(), SetParametersError> {
-        let script = CString::new(script).unwrap();
-        let res = unsafe { self.raw.VBVMR_SetParameters(script.as_ptr() as *mut _) };
+        let wide = encode_wide(script).ok_or_else(|| {
+            SetParametersError::ContainsNul(script.find('\0').unwrap_or_default())
+        })?;
+        let res = self.backend.set_parameters_w(&wide);
 
         match res {
             l if l > 0 => Err(SetParametersError::ScriptError(l as usize)),
-            0 => Ok(()),
+            0 => {
+                self.login_state.written.record_script(script);
+                Ok(())
+            }
             -1 => Err(SetParameterError::CannotGetClient.into()),
             -2 => Err(SetParameterError::NoServer.into()),
             s => Err(SetParameterError::Other(s).into()),
@@ -84,6 +120,9 @@ pub enum SetParametersError {
     /// Script error
     #[error("script error on line: {0}")]
     ScriptError(usize),
+    /// The script contains a nul character at the given byte offset.
+    #[error("script contains a nul character at byte {0}")]
+    ContainsNul(usize),
     /// An error occured when setting parameters.
     #[error(transparent)]
     SetParameterError(#[from] SetParameterError),
@@ -103,7 +142,77 @@ pub enum SetParameterError {
     /// Unknown parameter.
     #[error("unknown parameter: {0}")]
     UnknownParameter(String),
+    /// The parameter name contains a nul character.
+    #[error("parameter name contains a nul character")]
+    NulError(#[from] NulError),
+    /// The string value contains a nul character.
+    #[error("value for parameter `{name}` contains a nul character")]
+    ValueContainsNul {
+        /// The parameter that was set.
+        name: String,
+    },
+    /// The value is outside of the range of the parameter.
+    #[error("value {value} is out of range {} for parameter `{name}`", DisplayRange(.range))]
+    OutOfRange {
+        /// The parameter that was set.
+        name: String,
+        /// The rejected value.
+        value: f32,
+        /// The range of valid values.
+        range: (Bound<f32>, Bound<f32>),
+    },
+    /// The parameter is not supported by the running program.
+    #[error("parameter `{name}` is not supported on `{program}`")]
+    NotSupported {
+        /// The parameter that was set.
+        name: String,
+        /// The running program.
+        program: VoicemeeterApplication,
+    },
+    /// The string can not be written in a script, it contains a quote, a line break or a nul character.
+    #[error("value {value:?} for parameter `{name}` can not be written in a script")]
+    InvalidString {
+        /// The parameter that was set.
+        name: String,
+        /// The rejected value.
+        value: String,
+    },
+    /// The value has more channels than the parameter, e.g. an [`EqProfile`](super::eq::EqProfile) for a smaller EQ.
+    #[error("{channels} channels do not fit in the {max} channels of `{name}`")]
+    TooManyChannels {
+        /// The parameter that was set.
+        name: String,
+        /// The channels in the value.
+        channels: usize,
+        /// The channels of the parameter.
+        max: usize,
+    },
     /// An unknown error code occured.
     #[error("unexpected error occurred: error code {0}")]
     Other(i32),
 }
+
+/// Encode `value` as a nul-terminated UTF-16 string, or `None` if it contains a nul character.
+fn encode_wide(value: &str) -> Option<Vec<u16>> {
+    if value.contains('\0') {
+        return None;
+    }
+    Some(value.encode_utf16().chain([0]).collect())
+}
+
+/// Formats a range like rust syntax, e.g. `-60..=12`.
+struct DisplayRange<'a>(&'a (Bound<f32>, Bound<f32>));
+
+impl std::fmt::Display for DisplayRange<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 .0 {
+            Bound::Included(start) | Bound::Excluded(start) => write!(f, "{start}..")?,
+            Bound::Unbounded => f.write_str("..")?,
+        }
+        match self.0 .1 {
+            Bound::Included(end) => write!(f, "={end}"),
+            Bound::Excluded(end) => write!(f, "{end}"),
+            Bound::Unbounded => Ok(()),
+        }
+    }
+}
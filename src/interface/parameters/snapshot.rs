@@ -0,0 +1,504 @@
+//! Snapshots of the whole mixer.
+//!
+//! A [`MixerSnapshot`] holds the value of every readable strip, bus, EQ cell, compressor, gate, fx, option and VBAN stream
+//! parameter of the running program. It can be rendered as a [`set_parameters`](VoicemeeterRemote::set_parameters) script
+//! and restored with [`VoicemeeterRemote::restore_snapshot`].
+//!
+//! # Functions
+//!
+//! * [`snapshot`](VoicemeeterRemote::snapshot)
+//! * [`restore_snapshot`](VoicemeeterRemote::restore_snapshot)
+//! * [`changes_since`](VoicemeeterRemote::changes_since)
+//!
+//! Two snapshots can be compared with [`MixerSnapshot::diff`], the [`SnapshotDiff`] can be rendered
+//! as a script that only sets the parameters that changed.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use voicemeeter::VoicemeeterRemote;
+//!
+//! let remote = VoicemeeterRemote::new()?;
+//! let snapshot = remote.snapshot()?;
+//! println!("{}", snapshot.to_script()?);
+//!
+//! // ... later
+//! let report = remote.restore_snapshot(&snapshot)?;
+//! for failure in &report.failed {
+//!     println!("could not restore {}: {}", failure.name, failure.reason);
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::collections::{BTreeMap, HashMap};
+
+use crate::types::{ParameterName, ParameterNameRef, VoicemeeterApplication};
+use crate::VoicemeeterRemote;
+
+use super::{
+    get_parameters::GetParameterError,
+    registry,
+    script::quote,
+    set_parameters::{SetParameterError, SetParametersError},
+    watcher::read,
+    ParameterChange, ParameterValue, BUS, RECORDER,
+};
+
+/// Whether a parameter can be restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Access {
+    ReadWrite,
+    ReadOnly,
+}
+
+/// All parameters in a snapshot of `program`.
+///
+/// These are all readable parameters in the [registry](super::registry), except for the recorder
+/// whose parameters are transport buttons and not part of the mixer state.
+fn parameter_names(program: VoicemeeterApplication) -> Vec<(ParameterName, Access)> {
+    registry::parameters()
+        .iter()
+        .filter(|p| p.read && !p.name.starts_with(RECORDER))
+        .flat_map(|p| {
+            let access = if p.write {
+                Access::ReadWrite
+            } else {
+                Access::ReadOnly
+            };
+            p.names(program).into_iter().map(move |name| (name, access))
+        })
+        .collect()
+}
+
+/// Returns `true` for bus mode parameters that are off.
+///
+/// Only the active mode is restored, turning a mode off is not meaningful.
+pub(crate) fn is_inactive_mode(name: &ParameterNameRef, value: &ParameterValue) -> bool {
+    name.as_str().starts_with(BUS)
+        && name.as_str().contains(".mode.")
+        && value.as_f32() == Some(0.0)
+}
+
+/// A statement setting `name` to `value` in a [`set_parameters`](VoicemeeterRemote::set_parameters) script.
+///
+/// Fails for strings that can not be quoted, see [`SetParameterError::InvalidString`].
+pub(crate) fn script_statement(
+    name: &ParameterNameRef,
+    value: &ParameterValue,
+) -> Result<String, SetParameterError> {
+    match value {
+        ParameterValue::Float(f) => Ok(format!("{name} = {f}")),
+        ParameterValue::String(s) => Ok(format!("{name} = {}", quote(name, s)?)),
+    }
+}
+
+/// The values of all parameters of the mixer, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixerSnapshot {
+    /// The program the snapshot was taken from.
+    pub program: VoicemeeterApplication,
+    /// The value of every parameter.
+    pub values: BTreeMap<ParameterName, ParameterValue>,
+}
+
+impl MixerSnapshot {
+    /// The value of a parameter.
+    pub fn get(&self, name: &ParameterNameRef) -> Option<&ParameterValue> {
+        self.values.get(name)
+    }
+
+    /// Render the snapshot as a [`set_parameters`](VoicemeeterRemote::set_parameters) script, one parameter per line.
+    ///
+    /// Read only parameters, like the device names, and bus modes that are off are left out.
+    /// Fails with [`SetParameterError::InvalidString`] if a string value can not be written in a script.
+    pub fn to_script(&self) -> Result<String, SetParameterError> {
+        self.scripted_values()
+            .map(|(name, value)| Ok(script_statement(name, value)? + "\n"))
+            .collect()
+    }
+
+    /// The values set by [`to_script`](MixerSnapshot::to_script).
+    pub(crate) fn scripted_values(
+        &self,
+    ) -> impl Iterator<Item = (&ParameterName, &ParameterValue)> {
+        let names: HashMap<_, _> = parameter_names(self.program).into_iter().collect();
+        self.values
+            .iter()
+            .filter(move |(name, value)| is_scripted(&names, name, value))
+    }
+
+    /// Compare this snapshot with a newer snapshot.
+    pub fn diff(&self, new: &MixerSnapshot) -> SnapshotDiff {
+        let mut diff = SnapshotDiff {
+            program: new.program,
+            changed: vec![],
+            added: vec![],
+            removed: vec![],
+        };
+        for (name, old) in &self.values {
+            match new.values.get(name) {
+                Some(new) if new != old => diff.changed.push(ParameterChange {
+                    name: name.clone(),
+                    old: old.clone(),
+                    new: new.clone(),
+                }),
+                Some(_) => {}
+                None => diff.removed.push((name.clone(), old.clone())),
+            }
+        }
+        for (name, new) in &new.values {
+            if !self.values.contains_key(name) {
+                diff.added.push((name.clone(), new.clone()));
+            }
+        }
+        diff
+    }
+}
+
+/// Returns `true` if the parameter should be part of a script.
+fn is_scripted(
+    names: &HashMap<ParameterName, Access>,
+    name: &ParameterNameRef,
+    value: &ParameterValue,
+) -> bool {
+    names.get(name) != Some(&Access::ReadOnly) && !is_inactive_mode(name, value)
+}
+
+/// The differences between two [`MixerSnapshot`]s, created with [`MixerSnapshot::diff`].
+///
+/// The [`Display`](std::fmt::Display) implementation lists every difference on its own line.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotDiff {
+    /// The program of the newer snapshot.
+    pub program: VoicemeeterApplication,
+    /// Parameters with different values.
+    pub changed: Vec<ParameterChange>,
+    /// Parameters only in the newer snapshot.
+    pub added: Vec<(ParameterName, ParameterValue)>,
+    /// Parameters only in the older snapshot.
+    pub removed: Vec<(ParameterName, ParameterValue)>,
+}
+
+impl SnapshotDiff {
+    /// Returns `true` if the snapshots are the same.
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Render a [`set_parameters`](VoicemeeterRemote::set_parameters) script that turns the older snapshot into the newer one.
+    ///
+    /// Only changed and added parameters are set. Like [`MixerSnapshot::to_script`], read only parameters
+    /// and bus modes that were turned off are left out, and strings that can not be written in a script
+    /// are an error.
+    pub fn to_script(&self) -> Result<String, SetParameterError> {
+        let names: HashMap<_, _> = parameter_names(self.program).into_iter().collect();
+        self.changed
+            .iter()
+            .map(|c| (&c.name, &c.new))
+            .chain(self.added.iter().map(|(name, value)| (name, value)))
+            .filter(|(name, value)| is_scripted(&names, name, value))
+            .map(|(name, value)| Ok(script_statement(name, value)? + "\n"))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changed {
+            writeln!(f, "{}: {} -> {}", change.name, change.old, change.new)?;
+        }
+        for (name, value) in &self.added {
+            writeln!(f, "{name}: added {value}")?;
+        }
+        for (name, value) in &self.removed {
+            writeln!(f, "{name}: removed {value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl VoicemeeterRemote {
+    /// Take a [`MixerSnapshot`] of all parameters of the running program.
+    ///
+    /// Parameters that are not known by the running version of Voicemeeter are left out.
+    pub fn snapshot(&self) -> Result<MixerSnapshot, GetParameterError> {
+        let mut values = BTreeMap::new();
+        for (name, _) in parameter_names(self.program()) {
+            match read(self, &name) {
+                Ok(value) => {
+                    values.insert(name, value);
+                }
+                Err(GetParameterError::UnknownParameter(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(MixerSnapshot {
+            program: self.program(),
+            values,
+        })
+    }
+
+    /// Take a new snapshot and compare it with `since`, to see what was changed in Voicemeeter.
+    pub fn changes_since(&self, since: &MixerSnapshot) -> Result<SnapshotDiff, GetParameterError> {
+        Ok(since.diff(&self.snapshot()?))
+    }
+
+    /// Restore a [`MixerSnapshot`] with a single [`set_parameters`](VoicemeeterRemote::set_parameters) script.
+    ///
+    /// Read only parameters are skipped. Parameters that are not available in the running program
+    /// or are rejected by Voicemeeter are reported in the [`RestoreReport`], the rest is still restored.
+    pub fn restore_snapshot(
+        &self,
+        snapshot: &MixerSnapshot,
+    ) -> Result<RestoreReport, SetParameterError> {
+        let names: HashMap<_, _> = parameter_names(self.program()).into_iter().collect();
+        let mut report = RestoreReport::default();
+        let mut statements = vec![];
+        for (name, value) in &snapshot.values {
+            match names.get(name) {
+                None => report.failed.push(RestoreFailure {
+                    name: name.clone(),
+                    value: value.clone(),
+                    reason: RestoreFailureReason::NotAvailable,
+                }),
+                Some(Access::ReadOnly) => {}
+                Some(Access::ReadWrite) if is_inactive_mode(name, value) => {}
+                Some(Access::ReadWrite) => match script_statement(name, value) {
+                    Ok(statement) => statements.push((name, value, statement)),
+                    // can not be written in a script
+                    Err(_) => report.failed.push(RestoreFailure {
+                        name: name.clone(),
+                        value: value.clone(),
+                        reason: RestoreFailureReason::Rejected,
+                    }),
+                },
+            }
+        }
+
+        let mut rest = statements;
+        while !rest.is_empty() {
+            let script = rest
+                .iter()
+                .map(|(_, _, statement)| statement.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let (failed, applied) = match self.set_parameters(&script) {
+                Ok(()) => break,
+                // lines before the failing line have been applied, continue after it
+                Err(SetParametersError::ScriptError(line)) => (line.saturating_sub(1), true),
+                // nothing has been applied, retry without the failing statement
+                Err(SetParametersError::ContainsNul(offset)) => {
+                    (script[..offset].matches('\n').count(), false)
+                }
+                Err(SetParametersError::SetParameterError(e)) => return Err(e),
+            };
+            let failed = failed.min(rest.len() - 1);
+            let (name, value, _) = rest.remove(failed);
+            if applied {
+                rest.drain(..failed);
+            }
+            report.failed.push(RestoreFailure {
+                name: name.clone(),
+                value: value.clone(),
+                reason: RestoreFailureReason::Rejected,
+            });
+        }
+        Ok(report)
+    }
+}
+
+/// The result of [`VoicemeeterRemote::restore_snapshot`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RestoreReport {
+    /// The parameters that could not be restored.
+    pub failed: Vec<RestoreFailure>,
+}
+
+impl RestoreReport {
+    /// Returns `true` if every parameter was restored.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// A parameter that could not be restored.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RestoreFailure {
+    /// The parameter.
+    pub name: ParameterName,
+    /// The value in the snapshot.
+    pub value: ParameterValue,
+    /// Why the parameter could not be restored.
+    pub reason: RestoreFailureReason,
+}
+
+/// Reason a parameter could not be restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum RestoreFailureReason {
+    /// The parameter does not exist in the running program.
+    NotAvailable,
+    /// Voicemeeter rejected the statement in the script.
+    Rejected,
+}
+
+impl std::fmt::Display for RestoreFailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreFailureReason::NotAvailable => f.write_str("not available in this program"),
+            RestoreFailureReason::Rejected => f.write_str("rejected by voicemeeter"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::simulator::remote;
+
+    use super::*;
+
+    #[test]
+    fn snapshot_and_restore() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        simulator.gui_set_float("Strip[0].Gain", -6.0)?;
+        simulator.gui_set_string("Strip[7].Label", "Music")?;
+        simulator.gui_set_float("Bus[2].EQ.channel[3].cell[1].gain", 4.5)?;
+        simulator.gui_set_float("Bus[1].mode.Amix", 1.0)?;
+        simulator.gui_set_float("vban.outstream[7].port", 6990.0)?;
+
+        let snapshot = remote.snapshot()?;
+        assert_eq!(
+            snapshot.get("Strip[0].Gain".into()),
+            Some(&ParameterValue::Float(-6.0))
+        );
+        assert_eq!(
+            snapshot
+                .get("Strip[7].Label".into())
+                .and_then(|v| v.as_str()),
+            Some("Music")
+        );
+        assert!(snapshot.get("Strip[0].device.name".into()).is_some());
+        assert!(snapshot
+            .get("Strip[0].EQ.channel[1].cell[5].q".into())
+            .is_some());
+        assert!(snapshot.get("Strip[7].comp.Ratio".into()).is_none());
+        assert!(snapshot.get("Fx.Reverb.On".into()).is_some());
+        let script = snapshot.to_script()?;
+        assert!(script.contains("Strip[7].Label = \"Music\"\n"));
+        assert!(script.contains("Bus[1].mode.Amix = 1\n"));
+        assert!(!script.contains("Bus[1].mode.normal"));
+        assert!(!script.contains("device.name"));
+        assert!(!script.contains("instream[0].sr"));
+        assert!(script.contains("outstream[0].sr"));
+
+        simulator.gui_set_float("Strip[0].Gain", 0.0)?;
+        simulator.gui_set_string("Strip[7].Label", "")?;
+        simulator.gui_set_float("Bus[2].EQ.channel[3].cell[1].gain", 0.0)?;
+        let report = remote.restore_snapshot(&snapshot)?;
+        assert!(report.is_complete(), "{report:?}");
+        assert_eq!(simulator.float("Strip[0].Gain")?, -6.0);
+        assert_eq!(simulator.string("Strip[7].Label")?, "Music");
+        assert_eq!(simulator.float("Bus[2].EQ.channel[3].cell[1].gain")?, 4.5);
+        assert_eq!(simulator.float("Bus[1].mode.Amix")?, 1.0);
+
+        // restoring a potato snapshot on banana
+        simulator.set_program(VoicemeeterApplication::VoicemeeterBanana);
+        let mut remote = remote;
+        remote.update_program()?;
+        let report = remote.restore_snapshot(&snapshot)?;
+        assert!(report
+            .failed
+            .iter()
+            .all(|f| f.reason == RestoreFailureReason::NotAvailable));
+        assert!(report
+            .failed
+            .iter()
+            .any(|f| f.name.as_str() == "Strip[7].Label"));
+        assert_eq!(simulator.float("Strip[0].Gain")?, -6.0);
+        Ok(())
+    }
+
+    #[test]
+    fn unquotable_string() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let label = "a \"quote\"\nStrip[1].Mute = 1";
+        simulator.gui_set_string("Strip[0].Label", label)?;
+        simulator.gui_set_float("Strip[0].Gain", -3.0)?;
+        let snapshot = remote.snapshot()?;
+        assert!(matches!(
+            snapshot.to_script(),
+            Err(SetParameterError::InvalidString { name, .. }) if name == "Strip[0].Label"
+        ));
+
+        simulator.gui_set_string("Strip[0].Label", "")?;
+        simulator.gui_set_float("Strip[0].Gain", 0.0)?;
+        let report = remote.restore_snapshot(&snapshot)?;
+        assert_eq!(
+            report.failed,
+            vec![RestoreFailure {
+                name: "Strip[0].Label".into(),
+                value: ParameterValue::String(label.to_owned()),
+                reason: RestoreFailureReason::Rejected,
+            }]
+        );
+        assert_eq!(simulator.string("Strip[0].Label")?, "");
+        assert_eq!(simulator.float("Strip[0].Gain")?, -3.0);
+        assert_eq!(simulator.float("Strip[1].Mute")?, 0.0);
+
+        let diff = remote.snapshot()?.diff(&snapshot);
+        assert!(matches!(
+            diff.to_script(),
+            Err(SetParameterError::InvalidString { name, .. }) if name == "Strip[0].Label"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn diff() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let before = remote.snapshot()?;
+        assert!(remote.changes_since(&before)?.is_empty());
+
+        simulator.gui_set_float("Strip[1].Mute", 1.0)?;
+        simulator.gui_set_string("Bus[0].Label", "Speakers")?;
+        simulator.gui_set_float("Bus[3].mode.normal", 0.0)?;
+        simulator.gui_set_float("Bus[3].mode.TVMix", 1.0)?;
+        let diff = remote.changes_since(&before)?;
+        assert_eq!(diff.changed.len(), 4, "{diff}");
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+        assert!(diff
+            .to_string()
+            .contains("Bus[0].Label: \"\" -> \"Speakers\"\n"));
+        assert_eq!(
+            diff.to_script()?,
+            "Bus[0].Label = \"Speakers\"\n\
+             Bus[3].mode.TVMix = 1\n\
+             Strip[1].Mute = 1\n"
+        );
+
+        let after = remote.snapshot()?;
+        remote.set_parameters(&after.diff(&before).to_script()?)?;
+        assert!(remote.changes_since(&before)?.is_empty());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        simulator.gui_set_float("Strip[0].Gain", -6.5)?;
+        simulator.gui_set_string("Strip[7].Label", "Music")?;
+        let snapshot = remote.snapshot()?;
+
+        let json = serde_json::to_value(&snapshot)?;
+        assert_eq!(json["program"], "Potato");
+        assert_eq!(json["values"]["Strip[0].Gain"], -6.5);
+        assert_eq!(json["values"]["Strip[7].Label"], "Music");
+        assert_eq!(serde_json::from_value::<MixerSnapshot>(json)?, snapshot);
+        Ok(())
+    }
+}
@@ -51,7 +51,7 @@ impl<'a> Strip<'a> {
     /// Strip is physical
     #[rustfmt::skip]
     pub fn is_physical(&self) -> bool {
-        matches!((self.remote.program, self.strip_index.0),
+        matches!((self.remote.program(), self.strip_index.0),
             | (VoicemeeterApplication::Voicemeeter, 0..=1)
             | (VoicemeeterApplication::VoicemeeterBanana, 0..=2)
             | (VoicemeeterApplication::VoicemeeterPotato, 0..=4)
@@ -61,38 +61,38 @@ impl<'a> Strip<'a> {
 
     /// Strip is virtual
     pub fn is_virtual(&self) -> bool {
-        !(self.is_physical() || matches!(self.remote.program, VoicemeeterApplication::Other))
+        !(self.is_physical() || matches!(self.remote.program(), VoicemeeterApplication::Other))
     }
 
     /// Mono Button
-    pub fn mono(&self) -> BoolParameter {
+    pub fn mono(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Mono"), self.remote)
     }
 
     /// Mute Button
-    pub fn mute(&self) -> BoolParameter {
+    pub fn mute(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Mute"), self.remote)
     }
 
     /// Solo Button
-    pub fn solo(&self) -> BoolParameter {
+    pub fn solo(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("Solo"), self.remote)
     }
 
     // FIXME: Only available in virtual input and input8
     /// Mute Center Button
-    pub fn mute_center(&self) -> BoolParameter {
+    pub fn mute_center(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("MC"), self.remote)
     }
 
     /// Gain slider
-    pub fn gain(&self) -> FloatParameter {
+    pub fn gain(&self) -> FloatParameter<'_> {
         FloatParameter::new(self.param("Gain"), self.remote, -60.0..=12.0)
     }
 
     // TODO: zindex for bus
     /// Gain slider for a bus
-    pub fn gain_layer(&self, layer: impl Into<ZIndex>) -> FloatParameter {
+    pub fn gain_layer(&self, layer: impl Into<ZIndex>) -> FloatParameter<'_> {
         let layer = layer.into();
         let name = self.param(format!("GainLayer[{layer}]"));
         FloatParameter::new(name, self.remote, -60.0..=12.0)
@@ -100,18 +100,18 @@ impl<'a> Strip<'a> {
 
     // TODO: zindex for bus
     /// Pan in x direction
-    pub fn pan_x(&self) -> FloatParameter {
+    pub fn pan_x(&self) -> FloatParameter<'_> {
         FloatParameter::new(self.param("Pan_x"), self.remote, -0.5..=0.5)
     }
 
     /// Pan in y direction
-    pub fn pan_y(&self) -> FloatParameter {
+    pub fn pan_y(&self) -> FloatParameter<'_> {
         // FIXME: docs says for range: 0 to 1.0 (-0.5 to 0.5 for 5.1 pan pot)
         FloatParameter::new_unranged(self.param("Pan_y"), self.remote)
     }
 
     /// Color of physical strip in x direction
-    pub fn color_x(&self) -> Result<FloatParameter, InvalidTypeError> {
+    pub fn color_x(&self) -> Result<FloatParameter<'_>, InvalidTypeError> {
         if self.is_virtual() {
             Err(InvalidTypeError::ExpectedPhysical {
                 name: STRIP,
@@ -128,7 +128,7 @@ impl<'a> Strip<'a> {
     }
 
     /// Color of physical strip in y direction
-    pub fn color_y(&self) -> Result<FloatParameter, InvalidTypeError> {
+    pub fn color_y(&self) -> Result<FloatParameter<'_>, InvalidTypeError> {
         if self.is_virtual() {
             Err(InvalidTypeError::ExpectedPhysical {
                 name: STRIP,
@@ -145,7 +145,7 @@ impl<'a> Strip<'a> {
     }
 
     /// FX of physical strip in x direction
-    pub fn fx_x(&self) -> Result<FloatParameter, InvalidTypeError> {
+    pub fn fx_x(&self) -> Result<FloatParameter<'_>, InvalidTypeError> {
         if self.is_virtual() {
             Err(InvalidTypeError::ExpectedPhysical {
                 name: STRIP,
@@ -162,7 +162,7 @@ impl<'a> Strip<'a> {
     }
 
     /// FX of physical strip in y direction
-    pub fn fx_y(&self) -> Result<FloatParameter, InvalidTypeError> {
+    pub fn fx_y(&self) -> Result<FloatParameter<'_>, InvalidTypeError> {
         if self.is_virtual() {
             Err(InvalidTypeError::ExpectedPhysical {
                 name: STRIP,
@@ -179,26 +179,26 @@ impl<'a> Strip<'a> {
     }
 
     /// Audability
-    pub fn audability(&self) -> FloatParameter {
+    pub fn audability(&self) -> FloatParameter<'_> {
         FloatParameter::new(self.param("Audability"), self.remote, 0.0..=10.0)
     }
     // FIXME: Only available in virtual input aux
     /// Compression
     ///
     /// See also [Strip::comp_detailed] for detailed compressor settings
-    pub fn comp(&self) -> FloatParameter {
+    pub fn comp(&self) -> FloatParameter<'_> {
         FloatParameter::new(self.param("Comp"), self.remote, 0.0..=10.0)
     }
 
     /// Compressor detailed parameters/settings
     ///
     /// Only works on Voicemeeter Potato
-    pub fn comp_detailed(&self) -> Result<StripCompressor, ParameterError> {
+    pub fn comp_detailed(&self) -> Result<StripCompressor<'_>, ParameterError> {
         const VALID: &[VoicemeeterApplication] = &[
             VoicemeeterApplication::VoicemeeterPotato,
             VoicemeeterApplication::PotatoX64Bits,
         ];
-        if VALID.contains(&self.remote.program) {
+        if VALID.contains(&self.remote.program()) {
             if self.is_physical() {
                 Ok(StripCompressor::new(self.remote, self.strip_index))
             } else {
@@ -212,7 +212,7 @@ impl<'a> Strip<'a> {
         } else {
             Err(InvalidVoicemeeterVersion {
                 expected: VALID,
-                found: self.remote.program,
+                found: self.remote.program(),
                 parameter: self.param("Comp").to_string(),
             }
             .into())
@@ -222,20 +222,20 @@ impl<'a> Strip<'a> {
     /// Gate
     ///
     /// See also [Strip::gate_detailed] for detailed gate settings
-    pub fn gate(&self) -> FloatParameter {
+    pub fn gate(&self) -> FloatParameter<'_> {
         FloatParameter::new(self.param("Gate"), self.remote, 0.0..=10.0)
     }
 
     /// Gate detailed parameters/settings
     ///
     /// Only works on Voicemeeter Potato
-    pub fn gate_detailed(&self) -> Result<StripGate, ParameterError> {
+    pub fn gate_detailed(&self) -> Result<StripGate<'_>, ParameterError> {
         const VALID: &[VoicemeeterApplication] = &[
             VoicemeeterApplication::VoicemeeterPotato,
             VoicemeeterApplication::PotatoX64Bits,
         ];
 
-        if VALID.contains(&self.remote.program) {
+        if VALID.contains(&self.remote.program()) {
             if self.is_physical() {
                 Ok(StripGate::new(self.remote, self.strip_index))
             } else {
@@ -249,7 +249,7 @@ impl<'a> Strip<'a> {
         } else {
             Err(InvalidVoicemeeterVersion {
                 expected: VALID,
-                found: self.remote.program,
+                found: self.remote.program(),
                 parameter: self.param("Gate").to_string(),
             }
             .into())
@@ -257,18 +257,18 @@ impl<'a> Strip<'a> {
     }
 
     /// Denoiser Knob
-    pub fn denoiser(&self) -> Result<FloatParameter, ParameterError> {
+    pub fn denoiser(&self) -> Result<FloatParameter<'_>, ParameterError> {
         const VALID: &[VoicemeeterApplication] = &[
             VoicemeeterApplication::VoicemeeterPotato,
             VoicemeeterApplication::PotatoX64Bits,
         ];
 
-        if VALID.contains(&self.remote.program) {
+        if VALID.contains(&self.remote.program()) {
             if self.is_physical() {
                 Ok(FloatParameter::new(
                     self.param("Denoiser"),
                     self.remote,
-                    0.0..10.0,
+                    0.0..=10.0,
                 ))
             } else {
                 Err(InvalidTypeError::ExpectedPhysical {
@@ -281,7 +281,7 @@ impl<'a> Strip<'a> {
         } else {
             Err(InvalidVoicemeeterVersion {
                 expected: VALID,
-                found: self.remote.program,
+                found: self.remote.program(),
                 parameter: self.param("Gate").to_string(),
             }
             .into())
@@ -289,17 +289,17 @@ impl<'a> Strip<'a> {
     }
 
     /// Karaoke
-    pub fn karaoke(&self) -> IntParameter {
+    pub fn karaoke(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("Karaoke"), self.remote, 0..=4)
     }
 
     /// Limit
-    pub fn limit(&self) -> IntParameter {
+    pub fn limit(&self) -> IntParameter<'_> {
         IntParameter::new(self.param("Limit"), self.remote, -40..=12)
     }
 
     /// EQGain1 of virtual strip
-    pub fn eq_gain1(&self) -> Result<FloatParameter, InvalidTypeError> {
+    pub fn eq_gain1(&self) -> Result<FloatParameter<'_>, InvalidTypeError> {
         if self.is_physical() {
             Err(InvalidTypeError::ExpectedPhysical {
                 name: STRIP,
@@ -316,7 +316,7 @@ impl<'a> Strip<'a> {
     }
 
     /// EQGain2 of virtual strip
-    pub fn eq_gain2(&self) -> Result<FloatParameter, InvalidTypeError> {
+    pub fn eq_gain2(&self) -> Result<FloatParameter<'_>, InvalidTypeError> {
         if self.is_physical() {
             Err(InvalidTypeError::ExpectedPhysical {
                 name: STRIP,
@@ -333,7 +333,7 @@ impl<'a> Strip<'a> {
     }
 
     /// EQGain3 of virtual strip
-    pub fn eq_gain3(&self) -> Result<FloatParameter, InvalidTypeError> {
+    pub fn eq_gain3(&self) -> Result<FloatParameter<'_>, InvalidTypeError> {
         if self.is_physical() {
             Err(InvalidTypeError::ExpectedPhysical {
                 name: STRIP,
@@ -350,58 +350,58 @@ impl<'a> Strip<'a> {
     }
 
     /// Label
-    pub fn label(&self) -> StringParameter {
+    pub fn label(&self) -> StringParameter<'_> {
         StringParameter::new(self.param("Label"), self.remote)
     }
 
     /// Out BUS Assignation for A1
-    pub fn a1(&self) -> BoolParameter {
+    pub fn a1(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("A1"), self.remote)
     }
     /// Out BUS Assignation for A2
-    pub fn a2(&self) -> BoolParameter {
+    pub fn a2(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("A2"), self.remote)
     }
     /// Out BUS Assignation for A3
-    pub fn a3(&self) -> BoolParameter {
+    pub fn a3(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("A3"), self.remote)
     }
     /// Out BUS Assignation for A4
-    pub fn a4(&self) -> BoolParameter {
+    pub fn a4(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("A4"), self.remote)
     }
     /// Out BUS Assignation for A5
-    pub fn a5(&self) -> BoolParameter {
+    pub fn a5(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("A5"), self.remote)
     }
     /// Out BUS Assignation for B1
-    pub fn b1(&self) -> BoolParameter {
+    pub fn b1(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("B1"), self.remote)
     }
     /// Out BUS Assignation for B2
-    pub fn b2(&self) -> BoolParameter {
+    pub fn b2(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("B2"), self.remote)
     }
     /// Out BUS Assignation for B3
-    pub fn b3(&self) -> BoolParameter {
+    pub fn b3(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("B3"), self.remote)
     }
     /// EQ Button
-    pub fn eq_on(&self) -> BoolParameter {
+    pub fn eq_on(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("EQ.on"), self.remote)
     }
     /// EQ Memory Slot
-    pub fn eq_ab(&self) -> BoolParameter {
+    pub fn eq_ab(&self) -> BoolParameter<'_> {
         BoolParameter::new(self.param("EQ.AB"), self.remote)
     }
     /// EQ on channel
-    pub fn eq(&self, channel: usize) -> Result<EqChannelParameter, ParameterError> {
+    pub fn eq(&self, channel: usize) -> Result<EqChannelParameter<'_>, ParameterError> {
         const VALID: &[VoicemeeterApplication] = &[
             VoicemeeterApplication::VoicemeeterPotato,
             VoicemeeterApplication::PotatoX64Bits,
         ];
         let eq = EqChannelParameter::new_strip(self.remote, self.strip_index, channel);
-        if VALID.contains(&self.remote.program) {
+        if VALID.contains(&self.remote.program()) {
             if self.is_physical() {
                 Ok(eq)
             } else {
@@ -415,51 +415,90 @@ impl<'a> Strip<'a> {
         } else {
             Err(InvalidVoicemeeterVersion {
                 expected: VALID,
-                found: self.remote.program,
+                found: self.remote.program(),
                 parameter: eq.name().to_string(),
             }
             .into())
         }
     }
+    /// All cells of all EQ channels, see [`EqProfile`]
+    ///
+    /// Only available on physical strips in Voicemeeter Potato, like [`Strip::eq`].
+    pub fn eq_profile(&self) -> Result<EqProfileParameter<'a>, ParameterError> {
+        self.eq(0)?;
+        Ok(EqProfileParameter::new_strip(self.remote, self.strip_index))
+    }
     /// Fade to
-    pub fn fade_to(&self) -> TupleParameter<'_, i32, usize> {
+    pub fn fade_to(&self) -> TupleParameter<'_, i32, usize, true, false> {
         TupleParameter::new(self.param("FadeTo"), self.remote)
     }
     /// Fade by
-    pub fn fade_by(&self) -> TupleParameter<'_, i32, usize> {
+    pub fn fade_by(&self) -> TupleParameter<'_, i32, usize, true, false> {
         TupleParameter::new(self.param("FadeBy"), self.remote)
     }
     /// Send Level To Reverb
-    pub fn reverb(&self) -> FloatParameter {
-        FloatParameter::new(self.param("Reverb"), self.remote, 0.0..=10.0)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn reverb(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("Reverb");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Send Level To Delay
-    pub fn delay(&self) -> FloatParameter {
-        FloatParameter::new(self.param("Delay"), self.remote, 0.0..=10.0)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn delay(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("Delay");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Send Level To External Fx1
-    pub fn fx1(&self) -> FloatParameter {
-        FloatParameter::new(self.param("Fx1"), self.remote, 0.0..=10.0)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn fx1(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("Fx1");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Send Level To External Fx2
-    pub fn fx2(&self) -> FloatParameter {
-        FloatParameter::new(self.param("Fx2"), self.remote, 0.0..=10.0)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn fx2(&self) -> Result<FloatParameter<'_>, ParameterError> {
+        let param = self.param("Fx2");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(FloatParameter::new(param, self.remote, 0.0..=10.0))
     }
     /// Post Reverb button
-    pub fn post_reverb(&self) -> BoolParameter {
-        BoolParameter::new(self.param("PostReverb"), self.remote)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn post_reverb(&self) -> Result<BoolParameter<'_>, ParameterError> {
+        let param = self.param("PostReverb");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(BoolParameter::new(param, self.remote))
     }
     /// Post Delay button
-    pub fn post_delay(&self) -> BoolParameter {
-        BoolParameter::new(self.param("PostDelay"), self.remote)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn post_delay(&self) -> Result<BoolParameter<'_>, ParameterError> {
+        let param = self.param("PostDelay");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(BoolParameter::new(param, self.remote))
     }
     /// Post Fx1 button
-    pub fn post_fx1(&self) -> BoolParameter {
-        BoolParameter::new(self.param("PostFx1"), self.remote)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn post_fx1(&self) -> Result<BoolParameter<'_>, ParameterError> {
+        let param = self.param("PostFx1");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(BoolParameter::new(param, self.remote))
     }
     /// Post Fx2 button
-    pub fn post_fx2(&self) -> BoolParameter {
-        BoolParameter::new(self.param("PostFx2"), self.remote)
+    ///
+    /// Only works on Voicemeeter Potato
+    pub fn post_fx2(&self) -> Result<BoolParameter<'_>, ParameterError> {
+        let param = self.param("PostFx2");
+        check_version(self.remote, POTATO, &param)?;
+        Ok(BoolParameter::new(param, self.remote))
     }
 
     /// Application gain
@@ -574,46 +613,46 @@ impl<'a> StripCompressor<'a> {
     ///
     /// To control the gain before compression.
     pub fn gain_in(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("GainIn"), self.remote, -24.0..24.0)
+        FloatParameter::new(self.param("GainIn"), self.remote, -24.0..=24.0)
     }
     /// Ratio
     ///
     /// Gives the compression rate.
     pub fn ratio(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("Ratio"), self.remote, 1.0..8.0)
+        FloatParameter::new(self.param("Ratio"), self.remote, 1.0..=8.0)
     }
     /// Threshold
     ///
     /// Define a level to start the compression when
     /// the input signal goes over this threshold.
     pub fn threshold(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("Threshold"), self.remote, -40.0..-3.0)
+        FloatParameter::new(self.param("Threshold"), self.remote, -40.0..=-3.0)
     }
     /// Attack Time (ms)
     ///
     /// to control the compression behavior on
     /// sound attack (when the input signal starts to go over the threshold)
     pub fn attack(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("Attack"), self.remote, 0.0..200.0)
+        FloatParameter::new(self.param("Attack"), self.remote, 0.0..=200.0)
     }
     /// Release Time (ms)
     ///  to control the compression behavior when
     /// the signal goes down
     pub fn release(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("Release"), self.remote, 0.0..5000.0)
+        FloatParameter::new(self.param("Release"), self.remote, 0.0..=5000.0)
     }
     /// Knee.
     ///
     /// To control the compression transition softness on
     /// threshold point
     pub fn knee(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("Knee"), self.remote, 0.0..1.0)
+        FloatParameter::new(self.param("Knee"), self.remote, 0.0..=1.0)
     }
     /// Output Gain
     ///
     /// To control the gain after compression
     pub fn gain_out(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("GainOut"), self.remote, -24.0..24.0)
+        FloatParameter::new(self.param("GainOut"), self.remote, -24.0..=24.0)
     }
     /// Auto Make Up Option
     ///
@@ -650,7 +689,7 @@ impl<'a> StripGate<'a> {
     /// If input gain is below this level the gate is
     /// closing, above this level the gate is opening.
     pub fn threshold(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("Threshold"), self.remote, -60.0..-10.0)
+        FloatParameter::new(self.param("Threshold"), self.remote, -60.0..=-10.0)
     }
     /// Damping Max
     ///
@@ -658,7 +697,7 @@ impl<'a> StripGate<'a> {
     /// the gate is closing. Per default OFF = -inf, the gate
     /// completely remove the signal when closing.
     pub fn damping(&self) -> FloatParameter<'a, true, true> {
-        FloatParameter::new(self.param("Damping"), self.remote, -60.0..-10.0)
+        FloatParameter::new(self.param("Damping"), self.remote, -60.0..=-10.0)
     }
     /// Band Pass Sidechain (hz)
     ///
@@ -690,3 +729,25 @@ impl<'a> StripGate<'a> {
         FloatParameter::new(self.param("Release"), self.remote, 0.0..=5000.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn fx_sends() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, mut remote) = remote(VoicemeeterApplication::VoicemeeterPotato);
+        remote.parameters().strip(7)?.reverb()?.set(2.5)?;
+        assert_eq!(simulator.float("Strip[7].Reverb")?, 2.5);
+
+        simulator.set_program(VoicemeeterApplication::VoicemeeterBanana);
+        remote.update_program()?;
+        assert!(matches!(
+            remote.parameters().strip(0)?.delay(),
+            Err(ParameterError::Version(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//! Values of [`TupleParameter`](super::TupleParameter)s, like `("Discord", 0.5)`.
+//!
+//! Voicemeeter has no escapes in tuples, strings are written in double quotes and can not contain quotes or line breaks.
+//! Booleans are written as `1` and `0`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use voicemeeter::interface::parameters::tuple;
+//!
+//! let value = tuple::parse::<String, f32>(r#"("Google Chrome", -6.5)"#);
+//! assert_eq!(value, Some(("Google Chrome".to_owned(), -6.5)));
+//! assert_eq!(tuple::parse::<String, bool>(r#"("Discord", 1)"#), Some(("Discord".to_owned(), true)));
+//! ```
+use crate::types::ParameterNameRef;
+
+use super::{script, set_parameters::SetParameterError};
+
+/// A value in a tuple parameter.
+pub trait TupleElement: Sized {
+    /// Write the value like Voicemeeter expects it in a tuple of the parameter `name`.
+    fn write(&self, name: &ParameterNameRef) -> Result<String, SetParameterError>;
+
+    /// Parse a value written by [`write`](TupleElement::write).
+    fn parse(s: &str) -> Option<Self>;
+}
+
+impl TupleElement for String {
+    fn write(&self, name: &ParameterNameRef) -> Result<String, SetParameterError> {
+        script::quote(name, self)
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.strip_prefix('"')?.strip_suffix('"')?;
+        (!s.contains('"')).then(|| s.to_owned())
+    }
+}
+
+impl TupleElement for bool {
+    fn write(&self, _: &ParameterNameRef) -> Result<String, SetParameterError> {
+        Ok(u8::from(*self).to_string())
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Some(s.parse::<f32>().ok()? == 1.0)
+    }
+}
+
+macro_rules! number_element {
+    ($($ty:ty),*) => {$(
+        impl TupleElement for $ty {
+            fn write(&self, _: &ParameterNameRef) -> Result<String, SetParameterError> {
+                Ok(self.to_string())
+            }
+
+            fn parse(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+        }
+    )*};
+}
+
+number_element!(f32, i32, usize);
+
+/// Write a tuple like `("Discord", 0.5)` for the parameter `name`.
+pub fn write<A: TupleElement, B: TupleElement>(
+    name: &ParameterNameRef,
+    a: &A,
+    b: &B,
+) -> Result<String, SetParameterError> {
+    Ok(format!("({}, {})", a.write(name)?, b.write(name)?))
+}
+
+/// Parse a tuple like `("Discord", 0.5)`.
+pub fn parse<A: TupleElement, B: TupleElement>(s: &str) -> Option<(A, B)> {
+    let s = s.trim().strip_prefix('(')?.strip_suffix(')')?;
+    // strings can not contain quotes, so the separator is the first comma after an even number of quotes
+    let mut quoted = false;
+    let comma = s.find(|c| {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        c == ',' && !quoted
+    })?;
+    Some((
+        A::parse(s[..comma].trim())?,
+        B::parse(s[comma + 1..].trim())?,
+    ))
+}
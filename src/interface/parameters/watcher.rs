@@ -0,0 +1,529 @@
+//! Watching parameters for changes.
+//!
+//! A [`ParameterWatcher`] polls [`is_parameters_dirty`](VoicemeeterRemote::is_parameters_dirty) on its own thread,
+//! re-reads the watched parameters when they are dirty and sends a [`ParameterChange`] for every value that changed.
+//!
+//! While a watcher is running, it is the only thread allowed to call [`is_parameters_dirty`](VoicemeeterRemote::is_parameters_dirty),
+//! other threads get [`IsParametersDirtyError::PolledByWatcher`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use voicemeeter::VoicemeeterRemote;
+//!
+//! let remote = VoicemeeterRemote::new()?;
+//! let watcher = remote.watch_parameters()?;
+//! watcher.watch_strip(0)?;
+//! watcher.watch("Bus[0].Gain".into());
+//!
+//! for change in watcher.changes() {
+//!     println!("{}: {} -> {}", change.name, change.old, change.new);
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use crate::{
+    types::{ParameterName, ParameterNameRef},
+    VoicemeeterRemote,
+};
+
+use super::{
+    get_parameters::{GetParameterError, IsParametersDirtyError},
+    registry, BusIndex, ParameterError, StripIndex,
+};
+
+/// Interval between polls of the dirty flag.
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Parameters watched by [`ParameterWatcher::watch_strip`], if the running program supports them.
+static STRIP_PARAMETERS: &[&str] = &[
+    "Mono",
+    "Mute",
+    "Solo",
+    "MC",
+    "Gain",
+    "Pan_x",
+    "Pan_y",
+    "Color_x",
+    "Color_y",
+    "fx_x",
+    "fx_y",
+    "Audability",
+    "Comp",
+    "Gate",
+    "Denoiser",
+    "Karaoke",
+    "Limit",
+    "Label",
+    "A1",
+    "A2",
+    "A3",
+    "A4",
+    "A5",
+    "B1",
+    "B2",
+    "B3",
+    "EQGain1",
+    "EQGain2",
+    "EQGain3",
+    "EQ.on",
+    "EQ.AB",
+    "Reverb",
+    "Delay",
+    "Fx1",
+    "Fx2",
+    "PostReverb",
+    "PostDelay",
+    "PostFx1",
+    "PostFx2",
+];
+
+/// Parameters watched by [`ParameterWatcher::watch_bus`], if the running program supports them.
+static BUS_PARAMETERS: &[&str] = &[
+    "Label",
+    "Mono",
+    "Mute",
+    "Gain",
+    "Sel",
+    "Monitor",
+    "EQ.on",
+    "EQ.AB",
+    "ReturnReverb",
+    "ReturnDelay",
+    "ReturnFx1",
+    "ReturnFx2",
+    "mode.normal",
+    "mode.Amix",
+    "mode.Bmix",
+    "mode.Repeat",
+    "mode.Composite",
+    "mode.TVMix",
+    "mode.UpMix21",
+    "mode.UpMix41",
+    "mode.UpMix61",
+    "mode.CenterOnly",
+    "mode.LFEOnly",
+    "mode.RearOnly",
+];
+
+impl VoicemeeterRemote {
+    /// Start a [`ParameterWatcher`], polling every [`DEFAULT_WATCH_INTERVAL`].
+    ///
+    /// Only one watcher can run at a time.
+    pub fn watch_parameters(&self) -> Result<ParameterWatcher, WatchError> {
+        self.watch_parameters_every(DEFAULT_WATCH_INTERVAL)
+    }
+
+    /// Start a [`ParameterWatcher`], polling every `interval`.
+    ///
+    /// Only one watcher can run at a time.
+    pub fn watch_parameters_every(
+        &self,
+        interval: Duration,
+    ) -> Result<ParameterWatcher, WatchError> {
+        let mut poller = self.login_state.parameter_poller.lock().unwrap();
+        if poller.is_some() {
+            return Err(WatchError::AlreadyWatching);
+        }
+        let shared = Arc::new(Shared::default());
+        let (sender, receiver) = mpsc::channel();
+        let thread = std::thread::Builder::new()
+            .name("voicemeeter-parameter-watcher".to_owned())
+            .spawn({
+                let remote = self.clone();
+                let shared = shared.clone();
+                move || watch(remote, shared, sender, interval)
+            })
+            .map_err(WatchError::Spawn)?;
+        *poller = Some(thread.thread().id());
+        Ok(ParameterWatcher {
+            remote: self.clone(),
+            shared,
+            receiver,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// Value of a parameter.
+///
+/// With the `serde` feature, this is represented as a plain number or string.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum ParameterValue {
+    /// A float parameter, also used for boolean and integer parameters.
+    Float(f32),
+    /// A string parameter.
+    String(String),
+}
+
+impl ParameterValue {
+    /// The float value, if this is a float parameter.
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            ParameterValue::Float(f) => Some(*f),
+            ParameterValue::String(_) => None,
+        }
+    }
+
+    /// The boolean value, if this is a float parameter.
+    pub fn as_bool(&self) -> Option<bool> {
+        self.as_f32().map(|f| f == 1.0)
+    }
+
+    /// The string value, if this is a string parameter.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ParameterValue::Float(_) => None,
+            ParameterValue::String(s) => Some(s),
+        }
+    }
+}
+
+impl std::fmt::Display for ParameterValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterValue::Float(v) => v.fmt(f),
+            ParameterValue::String(s) => write!(f, "{s:?}"),
+        }
+    }
+}
+
+/// A change of a watched parameter.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterChange {
+    /// The parameter that changed.
+    pub name: ParameterName,
+    /// The previous value.
+    pub old: ParameterValue,
+    /// The new value.
+    pub new: ParameterValue,
+}
+
+/// The watched parameters and their last known values.
+#[derive(Default)]
+pub(crate) struct Watched {
+    /// Parameters to start watching.
+    pending: Mutex<Vec<ParameterName>>,
+    /// Last known values of the watched parameters.
+    values: Mutex<HashMap<ParameterName, ParameterValue>>,
+}
+
+impl Watched {
+    pub(crate) fn watch(&self, name: &ParameterNameRef) {
+        self.pending.lock().unwrap().push(name.to_owned());
+    }
+
+    pub(crate) fn watch_strip(
+        &self,
+        remote: &VoicemeeterRemote,
+        index: impl StripIndex,
+    ) -> Result<(), ParameterError> {
+        let strip = remote.parameters().strip(index)?;
+        self.watch_supported(
+            remote,
+            STRIP_PARAMETERS.iter().map(|p| strip.param(p).into_owned()),
+        );
+        Ok(())
+    }
+
+    pub(crate) fn watch_bus(
+        &self,
+        remote: &VoicemeeterRemote,
+        index: impl BusIndex,
+    ) -> Result<(), ParameterError> {
+        let bus = remote.parameters().bus(index)?;
+        self.watch_supported(
+            remote,
+            BUS_PARAMETERS.iter().map(|p| bus.param(p).into_owned()),
+        );
+        Ok(())
+    }
+
+    /// Watch the parameters in `names` that the running program supports, so they are not read and warned about on every poll.
+    fn watch_supported(
+        &self,
+        remote: &VoicemeeterRemote,
+        names: impl Iterator<Item = ParameterName>,
+    ) {
+        let program = remote.program();
+        self.pending.lock().unwrap().extend(names.filter(|name| {
+            registry::lookup(name.as_str()).map_or(true, |info| info.supports(program))
+        }));
+    }
+
+    pub(crate) fn unwatch(&self, name: &ParameterNameRef) {
+        self.pending.lock().unwrap().retain(|p| **p != *name);
+        self.values.lock().unwrap().remove(name);
+    }
+
+    pub(crate) fn value(&self, name: &ParameterNameRef) -> Option<ParameterValue> {
+        self.values.lock().unwrap().get(name).cloned()
+    }
+
+    pub(crate) fn values(&self) -> HashMap<ParameterName, ParameterValue> {
+        self.values.lock().unwrap().clone()
+    }
+
+    /// Read newly watched parameters, and if `dirty` re-read all watched parameters.
+    ///
+    /// `on_change` is called for every value that changed, stops early and returns `false` if it returns `false`.
+    pub(crate) fn update(
+        &self,
+        remote: &VoicemeeterRemote,
+        dirty: bool,
+        mut on_change: impl FnMut(ParameterChange) -> bool,
+    ) -> bool {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap());
+        for name in pending {
+            match read(remote, &name) {
+                Ok(value) => {
+                    self.values.lock().unwrap().insert(name, value);
+                }
+                Err(e) => tracing::warn!(%name, "not watching parameter: {e}"),
+            }
+        }
+
+        if dirty {
+            let names: Vec<_> = self.values.lock().unwrap().keys().cloned().collect();
+            for name in names {
+                let new = match read(remote, &name) {
+                    Ok(new) => new,
+                    Err(e) => {
+                        tracing::warn!(%name, "could not read parameter: {e}");
+                        continue;
+                    }
+                };
+                let old = {
+                    let mut values = self.values.lock().unwrap();
+                    // unwatched while reading
+                    let Some(value) = values.get_mut(&name) else {
+                        continue;
+                    };
+                    std::mem::replace(value, new.clone())
+                };
+                if old != new && !on_change(ParameterChange { name, old, new }) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    stop: AtomicBool,
+    watched: Watched,
+}
+
+/// Watches parameters on a background thread, see the [module documentation](self).
+///
+/// The thread is stopped when the watcher is dropped.
+pub struct ParameterWatcher {
+    remote: VoicemeeterRemote,
+    shared: Arc<Shared>,
+    receiver: mpsc::Receiver<ParameterChange>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for ParameterWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParameterWatcher")
+            .field("remote", &self.remote)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ParameterWatcher {
+    /// Watch a parameter.
+    ///
+    /// The parameter is read on the next poll, changes after that are reported.
+    /// Parameters that can not be read are not watched.
+    pub fn watch(&self, name: &ParameterNameRef) {
+        self.shared.watched.watch(name)
+    }
+
+    /// Watch the common parameters of a strip, like gain, mute, label and the bus assignments.
+    pub fn watch_strip(&self, index: impl StripIndex) -> Result<(), ParameterError> {
+        self.shared.watched.watch_strip(&self.remote, index)
+    }
+
+    /// Watch the common parameters of a bus, like gain, mute, label and the bus mode.
+    pub fn watch_bus(&self, index: impl BusIndex) -> Result<(), ParameterError> {
+        self.shared.watched.watch_bus(&self.remote, index)
+    }
+
+    /// Stop watching a parameter.
+    pub fn unwatch(&self, name: &ParameterNameRef) {
+        self.shared.watched.unwatch(name)
+    }
+
+    /// The last known value of a watched parameter.
+    pub fn value(&self, name: &ParameterNameRef) -> Option<ParameterValue> {
+        self.shared.watched.value(name)
+    }
+
+    /// The last known values of all watched parameters.
+    pub fn values(&self) -> HashMap<ParameterName, ParameterValue> {
+        self.shared.watched.values()
+    }
+
+    /// The receiving end of the changes.
+    pub fn receiver(&self) -> &mpsc::Receiver<ParameterChange> {
+        &self.receiver
+    }
+
+    /// Iterate over changes, blocking until the next one.
+    pub fn changes(&self) -> mpsc::Iter<'_, ParameterChange> {
+        self.receiver.iter()
+    }
+}
+
+impl Drop for ParameterWatcher {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        self.remote
+            .login_state
+            .parameter_poller
+            .lock()
+            .unwrap()
+            .take();
+    }
+}
+
+fn watch(
+    remote: VoicemeeterRemote,
+    shared: Arc<Shared>,
+    sender: mpsc::Sender<ParameterChange>,
+    interval: Duration,
+) {
+    while !shared.stop.load(Ordering::Relaxed) {
+        // the first dirty poll after a set makes voicemeeter process it, so poll before reading
+        let dirty = match remote.is_parameters_dirty() {
+            Ok(dirty) => dirty,
+            Err(IsParametersDirtyError::NoServer) => {
+                std::thread::sleep(interval);
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!("could not check if parameters are dirty: {e}");
+                false
+            }
+        };
+
+        if !shared
+            .watched
+            .update(&remote, dirty, |change| sender.send(change).is_ok())
+        {
+            return;
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// Read a parameter as a float, or as a string if it is not a float parameter.
+pub(crate) fn read(
+    remote: &VoicemeeterRemote,
+    name: &ParameterNameRef,
+) -> Result<ParameterValue, GetParameterError> {
+    match remote.get_parameter_float(name) {
+        Ok(f) => Ok(ParameterValue::Float(f)),
+        Err(GetParameterError::StructureMismatch(..)) => remote
+            .get_parameter_string(name)
+            .map(ParameterValue::String),
+        Err(e) => Err(e),
+    }
+}
+
+/// Errors that can happen when starting a [`ParameterWatcher`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum WatchError {
+    /// Another watcher is already running.
+    #[error("parameters are already watched")]
+    AlreadyWatching,
+    /// Could not spawn the thread.
+    #[error("could not spawn the watcher thread")]
+    Spawn(#[source] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::simulator::remote, types::VoicemeeterApplication};
+
+    use super::*;
+
+    #[test]
+    fn changes() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let watcher = remote.watch_parameters_every(Duration::from_millis(1))?;
+        assert!(matches!(
+            remote.watch_parameters(),
+            Err(WatchError::AlreadyWatching)
+        ));
+        assert!(matches!(
+            remote.is_parameters_dirty(),
+            Err(IsParametersDirtyError::PolledByWatcher)
+        ));
+        watcher.watch_strip(0)?;
+        watcher.watch("Bus[0].Gain".into());
+        watcher.watch("Bus[9].Gain".into());
+        while watcher.value("Bus[0].Gain".into()).is_none() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        simulator.gui_set_float("Strip[0].Mute", 1.0)?;
+        let change = watcher.receiver().recv_timeout(Duration::from_secs(5))?;
+        assert_eq!(change.name.as_str(), "Strip[0].Mute");
+        assert_eq!(change.old.as_bool(), Some(false));
+        assert_eq!(change.new.as_bool(), Some(true));
+
+        simulator.gui_set_string("Strip[0].Label", "Mic")?;
+        simulator.gui_set_float("Bus[0].Gain", -3.0)?;
+        let mut changes: Vec<_> = (0..2)
+            .map(|_| watcher.receiver().recv_timeout(Duration::from_secs(5)))
+            .collect::<Result<_, _>>()?;
+        changes.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(changes[0].name.as_str(), "Bus[0].Gain");
+        assert_eq!(changes[0].new, ParameterValue::Float(-3.0));
+        assert_eq!(changes[1].new.as_str(), Some("Mic"));
+        assert_eq!(
+            watcher.value("Strip[0].Label".into()),
+            Some(ParameterValue::String("Mic".to_owned()))
+        );
+        assert_eq!(watcher.value("Bus[9].Gain".into()), None);
+
+        drop(watcher);
+        remote.is_parameters_dirty()?;
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_parameters() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, remote) = remote(VoicemeeterApplication::Voicemeeter);
+        let watched = Watched::default();
+        watched.watch_strip(&remote, 0)?;
+        watched.watch_bus(&remote, 0)?;
+        let pending = watched.pending.lock().unwrap();
+        assert!(pending.iter().any(|p| p.as_str() == "Strip[0].Gain"));
+        assert!(!pending.iter().any(|p| p.as_str() == "Strip[0].Reverb"));
+        assert!(!pending.iter().any(|p| p.as_str() == "Strip[0].A3"));
+        assert!(!pending.iter().any(|p| p.as_str() == "Bus[0].ReturnFx1"));
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+//! The last values set for write-only parameters.
+//!
+//! Voicemeeter can not report the value of write-only parameters like `Strip[0].App[1].Gain`,
+//! so the values set through this crate are remembered instead.
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::{types::ParameterNameRef, VoicemeeterRemote};
+
+use super::{
+    registry,
+    script::syntax::{self, Operator, Value},
+    ParameterValue,
+};
+
+impl VoicemeeterRemote {
+    /// The last value set for the write-only parameter `param`, if it has been set through this crate since Voicemeeter was started.
+    ///
+    /// Values are remembered when set with [`set_parameter_float`](VoicemeeterRemote::set_parameter_float),
+    /// [`set_parameter_string`](VoicemeeterRemote::set_parameter_string) and [`set_parameters`](VoicemeeterRemote::set_parameters),
+    /// by all remotes sharing the same backend. Values set by Voicemeeter itself or other applications are not known.
+    ///
+    /// Returns `None` for parameters that can be read, use [`get_parameter_float`](VoicemeeterRemote::get_parameter_float)
+    /// or [`get_parameter_string`](VoicemeeterRemote::get_parameter_string) for those.
+    pub fn last_set_value(&self, param: &ParameterNameRef) -> Option<ParameterValue> {
+        self.login_state.written.get(param.as_str())
+    }
+}
+
+/// Values set for write-only parameters, by lowercase name.
+#[derive(Debug, Default)]
+pub(crate) struct WrittenValues(Mutex<HashMap<String, ParameterValue>>);
+
+impl WrittenValues {
+    pub(crate) fn get(&self, name: &str) -> Option<ParameterValue> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(&name.to_ascii_lowercase())
+            .cloned()
+    }
+
+    /// Remember `value` if `name` is a write-only parameter.
+    pub(crate) fn record(&self, name: &str, value: ParameterValue) {
+        if is_write_only(name) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(name.to_ascii_lowercase(), value);
+        }
+    }
+
+    /// Remember the values set by a script that was applied.
+    ///
+    /// Scripts that can not be [parsed](syntax::parse) are ignored.
+    pub(crate) fn record_script(&self, script: &str) {
+        let Ok(ast) = syntax::parse(script) else {
+            return;
+        };
+        let mut values = self.0.lock().unwrap();
+        for statement in ast.statements() {
+            if !is_write_only(&statement.name) {
+                continue;
+            }
+            let name = statement.name.to_ascii_lowercase();
+            let value = match (statement.operator, &statement.value) {
+                (Operator::Assign, Value::Number(n)) => Some(ParameterValue::Float(*n)),
+                (Operator::Assign, Value::String(s)) => Some(ParameterValue::String(s.clone())),
+                (Operator::Assign, value @ Value::Tuple(_)) => {
+                    Some(ParameterValue::String(value.to_string()))
+                }
+                (Operator::Add, Value::Number(n)) => values
+                    .get(&name)
+                    .and_then(|v| v.as_f32())
+                    .map(|f| ParameterValue::Float(f + n)),
+                (Operator::Subtract, Value::Number(n)) => values
+                    .get(&name)
+                    .and_then(|v| v.as_f32())
+                    .map(|f| ParameterValue::Float(f - n)),
+                _ => None,
+            };
+            match value {
+                Some(value) => values.insert(name, value),
+                // the new value is not known
+                None => values.remove(&name),
+            };
+        }
+    }
+
+    /// Forget all values, when Voicemeeter was restarted.
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
+fn is_write_only(name: &str) -> bool {
+    registry::lookup(name).is_some_and(|p| !p.read)
+}
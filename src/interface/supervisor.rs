@@ -0,0 +1,281 @@
+//! Supervising the connection to Voicemeeter.
+//!
+//! The program of a remote is only updated when calling [`VoicemeeterRemote::update_program`].
+//! A [`Supervisor`] polls the running Voicemeeter instance and keeps [`VoicemeeterRemote::program`] of its remote
+//! and all its clones up to date when Voicemeeter is closed, restarted or switched to another type.
+//!
+//! # Functions
+//!
+//! * [`supervisor`](VoicemeeterRemote::supervisor)
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::{ops::ControlFlow, time::Duration};
+//! use voicemeeter::VoicemeeterRemote;
+//!
+//! let remote = VoicemeeterRemote::new()?;
+//! remote
+//!     .supervisor()
+//!     .run(Duration::from_millis(500), |remote, event| {
+//!         println!("{event:?}, program is now {}", remote.program());
+//!         ControlFlow::Continue(())
+//!     })?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::{ops::ControlFlow, time::Duration};
+
+use crate::types::VoicemeeterApplication;
+
+use super::{
+    callback::register::AudioCallbackReregisterError,
+    general_information::{GetVoicemeeterInformationError, VoicemeeterVersion},
+    VoicemeeterRemote,
+};
+
+impl VoicemeeterRemote {
+    /// Create a [`Supervisor`] for the connection to Voicemeeter, using a clone of this remote.
+    pub fn supervisor(&self) -> Supervisor {
+        Supervisor {
+            remote: self.clone(),
+            connection: Connection::Unknown,
+        }
+    }
+}
+
+/// Polls Voicemeeter and reports changes in the connection, see the [module documentation](self).
+///
+/// On every change, the program of the [remote](Supervisor::remote) and the program used by audio callbacks is updated.
+/// When Voicemeeter restarts, a registered audio callback is registered and started again.
+#[derive(Debug)]
+pub struct Supervisor {
+    remote: VoicemeeterRemote,
+    connection: Connection,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Connection {
+    Unknown,
+    Disconnected,
+    Connected(VoicemeeterApplication, VoicemeeterVersion),
+}
+
+/// A change in the connection to Voicemeeter.
+///
+/// Restarts are only seen when the program or version changes between two polls, or when a poll finds Voicemeeter closed.
+/// A restart of the same program and version that happens entirely between two polls can not be told apart from
+/// Voicemeeter running on, so no event is reported and the audio callback is not registered again. Poll more often than
+/// Voicemeeter can restart to catch these.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ConnectionEvent {
+    /// Voicemeeter is running.
+    Connected {
+        /// The running program.
+        program: VoicemeeterApplication,
+        /// The version of the running program.
+        version: VoicemeeterVersion,
+    },
+    /// Voicemeeter is no longer running.
+    Disconnected,
+    /// Voicemeeter was switched to another program.
+    ProgramChanged {
+        /// The previous program.
+        old: VoicemeeterApplication,
+        /// The new program.
+        new: VoicemeeterApplication,
+    },
+    /// Voicemeeter was restarted with another version.
+    VersionChanged {
+        /// The previous version.
+        old: VoicemeeterVersion,
+        /// The new version.
+        new: VoicemeeterVersion,
+    },
+    /// The audio callback was registered again after a restart.
+    AudioCallbackReregistered,
+    /// The audio callback could not be registered again after a restart.
+    AudioCallbackLost(AudioCallbackReregisterError),
+}
+
+impl Supervisor {
+    /// The supervised remote.
+    pub fn remote(&self) -> &VoicemeeterRemote {
+        &self.remote
+    }
+
+    /// Returns `true` if Voicemeeter was running at the last poll.
+    pub fn is_connected(&self) -> bool {
+        matches!(self.connection, Connection::Connected(..))
+    }
+
+    /// Check the running Voicemeeter instance and return what changed since the last poll.
+    ///
+    /// The first poll reports [`ConnectionEvent::Connected`] if Voicemeeter is running.
+    pub fn poll(&mut self) -> Result<Vec<ConnectionEvent>, SupervisorError> {
+        let current = match (
+            self.remote.get_voicemeeter_type(),
+            self.remote.get_voicemeeter_version(),
+        ) {
+            (Ok(program), Ok(version)) => Connection::Connected(program, version),
+            (Err(GetVoicemeeterInformationError::NoServer), _)
+            | (_, Err(GetVoicemeeterInformationError::NoServer)) => Connection::Disconnected,
+            (Err(e), _) | (_, Err(e)) => return Err(e.into()),
+        };
+        if current == self.connection {
+            return Ok(vec![]);
+        }
+
+        let mut events = vec![];
+        let mut restarted = false;
+        match (&self.connection, &current) {
+            (Connection::Connected(..), Connection::Disconnected) => {
+                events.push(ConnectionEvent::Disconnected)
+            }
+            (
+                Connection::Connected(old_program, old_version),
+                Connection::Connected(program, version),
+            ) => {
+                if old_program != program {
+                    events.push(ConnectionEvent::ProgramChanged {
+                        old: *old_program,
+                        new: *program,
+                    });
+                }
+                if old_version != version {
+                    events.push(ConnectionEvent::VersionChanged {
+                        old: *old_version,
+                        new: *version,
+                    });
+                }
+                restarted = true;
+            }
+            (previous, Connection::Connected(program, version)) => {
+                events.push(ConnectionEvent::Connected {
+                    program: *program,
+                    version: *version,
+                });
+                restarted = matches!(previous, Connection::Disconnected);
+            }
+            (_, _) => {}
+        }
+        self.remote.set_program(match current {
+            Connection::Connected(program, _) => program,
+            _ => VoicemeeterApplication::None,
+        });
+        self.connection = current;
+
+        if restarted {
+            self.remote.login_state.written.clear();
+            match self.remote.reregister_audio_callback() {
+                Ok(true) => events.push(ConnectionEvent::AudioCallbackReregistered),
+                Ok(false) => {}
+                Err(e) => events.push(ConnectionEvent::AudioCallbackLost(e)),
+            }
+        }
+        Ok(events)
+    }
+
+    /// Poll every `interval` and call `on_event` for every event, until `on_event` returns [`ControlFlow::Break`].
+    pub fn run(
+        mut self,
+        interval: Duration,
+        mut on_event: impl FnMut(&VoicemeeterRemote, ConnectionEvent) -> ControlFlow<()>,
+    ) -> Result<(), SupervisorError> {
+        loop {
+            for event in self.poll()? {
+                if on_event(&self.remote, event).is_break() {
+                    return Ok(());
+                }
+            }
+            std::thread::sleep(interval);
+        }
+    }
+}
+
+/// Errors that can happen while supervising the connection.
+#[derive(Debug, thiserror::Error, Clone)]
+#[non_exhaustive]
+pub enum SupervisorError {
+    /// Could not get information about the running Voicemeeter instance.
+    #[error("could not get information about voicemeeter")]
+    InformationError(#[from] GetVoicemeeterInformationError),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::simulator::remote;
+
+    use super::*;
+
+    #[test]
+    fn restarts() -> Result<(), Box<dyn std::error::Error>> {
+        let (simulator, remote) = remote(VoicemeeterApplication::VoicemeeterBanana);
+        let mut supervisor = remote.supervisor();
+        assert!(matches!(
+            supervisor.poll()?[..],
+            [ConnectionEvent::Connected {
+                program: VoicemeeterApplication::VoicemeeterBanana,
+                ..
+            }]
+        ));
+        assert!(supervisor.poll()?.is_empty());
+
+        let guard = remote.audio_callback_register(
+            crate::AudioCallbackMode::MAIN,
+            "supervisor",
+            |_, _| 0,
+        )?;
+        remote.audio_callback_start()?;
+
+        simulator.set_program(VoicemeeterApplication::None);
+        assert!(matches!(
+            supervisor.poll()?[..],
+            [ConnectionEvent::Disconnected]
+        ));
+        assert!(!supervisor.is_connected());
+        assert_eq!(supervisor.remote().program(), VoicemeeterApplication::None);
+        assert_eq!(simulator.audio_callback(), None);
+
+        simulator.set_program(VoicemeeterApplication::VoicemeeterBanana);
+        assert!(matches!(
+            supervisor.poll()?[..],
+            [
+                ConnectionEvent::Connected { .. },
+                ConnectionEvent::AudioCallbackReregistered
+            ]
+        ));
+        assert_eq!(
+            simulator.audio_callback(),
+            Some(("supervisor".to_owned(), true))
+        );
+
+        simulator.set_program(VoicemeeterApplication::PotatoX64Bits);
+        assert!(matches!(
+            supervisor.poll()?[..],
+            [
+                ConnectionEvent::ProgramChanged {
+                    old: VoicemeeterApplication::VoicemeeterBanana,
+                    new: VoicemeeterApplication::PotatoX64Bits,
+                },
+                ConnectionEvent::VersionChanged { .. },
+                ConnectionEvent::AudioCallbackReregistered
+            ]
+        ));
+        assert_eq!(
+            supervisor.remote().program(),
+            VoicemeeterApplication::PotatoX64Bits
+        );
+        // the remote the supervisor was created from follows the new program
+        assert_eq!(remote.program(), VoicemeeterApplication::PotatoX64Bits);
+        assert_eq!(
+            format!("{remote:?}"),
+            format!("[{}]", VoicemeeterApplication::PotatoX64Bits)
+        );
+        remote.parameters().strip(7)?;
+        supervisor.remote().parameters().strip(7)?;
+
+        remote.audio_callback_unregister(guard)?;
+        Ok(())
+    }
+}
@@ -18,10 +18,23 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+pub mod backend;
 #[allow(missing_docs)]
 /// Raw FFI Bindings
 #[allow(rustdoc::broken_intra_doc_links)]
 pub mod bindings;
+/// C types used by the [`bindings`].
+///
+/// The remote API is only available on Windows, so these follow the Windows (LLP64) data model on every target.
+#[allow(non_camel_case_types)]
+pub mod ctypes {
+    pub use std::os::raw::{c_char, c_int, c_short, c_uchar, c_ushort, c_void};
+    /// `long` on Windows
+    pub type c_long = i32;
+    /// `unsigned long` on Windows
+    pub type c_ulong = u32;
+}
+pub mod discovery;
 #[cfg(feature = "interface")]
 pub mod interface;
 #[cfg(miri)]
@@ -32,10 +45,10 @@ pub mod types;
 #[cfg(feature = "interface")]
 pub use types::Device;
 
-use std::ffi::{OsStr, OsString};
+use std::ffi::OsStr;
 
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[doc(hidden)]
 pub static VOICEMEETER_REMOTE: std::sync::OnceLock<VoicemeeterRemoteRaw> =
@@ -51,21 +64,82 @@ pub use interface::VoicemeeterRemote;
 #[cfg(feature = "interface")]
 pub use interface::callback::{commands::CallbackCommand, data::DeviceBuffer};
 
-use winreg::enums::{KEY_READ, KEY_WOW64_32KEY};
-
-static INSTALLER_UNINST_KEY: &str = "VB:Voicemeeter {17359A74-1236-5467}";
-static UNINSTALLER_DIR: &str = "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall";
-static LIBRARY_NAME_64: &str = "VoicemeeterRemote64.dll";
+/// Environment variable that can be set to the path of the Voicemeeter remote DLL, overriding the [`discovery`].
+pub const VOICEMEETER_REMOTE_DLL_ENV: &str = "VOICEMEETER_REMOTE_DLL";
 
 #[doc(hidden)]
 /// Get a reference to voicemeeter remote
 pub fn get_voicemeeter_raw() -> Result<&'static VoicemeeterRemoteRaw, LoadError> {
+    load_voicemeeter_raw(None)
+}
+
+/// Path of the loaded [`VOICEMEETER_REMOTE`].
+static VOICEMEETER_REMOTE_PATH: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+
+/// Get a reference to voicemeeter remote, loading it if needed.
+///
+/// If `dll_path` is given, only that DLL is loaded. Otherwise the DLL is searched for at the path in
+/// [`VOICEMEETER_REMOTE_DLL_ENV`] and then with [`discovery::Discovery`].
+#[tracing::instrument]
+pub(crate) fn load_voicemeeter_raw(
+    dll_path: Option<&Path>,
+) -> Result<&'static VoicemeeterRemoteRaw, LoadError> {
     if let Some(remote) = VOICEMEETER_REMOTE.get() {
-        Ok(remote)
+        return match dll_path {
+            Some(path) if !is_loaded_path(path) => Err(LoadError::OtherLibraryLoaded {
+                path: path.to_owned(),
+                loaded: VOICEMEETER_REMOTE_PATH.get().cloned(),
+            }),
+            _ => Ok(remote),
+        };
+    }
+    let mut attempts = vec![];
+    let candidates: Vec<_> = if let Some(path) = dll_path {
+        vec![(LoadStrategy::Path(path.to_owned()), Ok(path.to_owned()))]
     } else {
-        let path = find_voicemeeter_remote_with_registry()?;
-        load_voicemeeter_from_path(&path)
+        let env = std::env::var_os(VOICEMEETER_REMOTE_DLL_ENV)
+            .filter(|p| !p.is_empty())
+            .map(|p| (LoadStrategy::Environment(p.clone().into()), Ok(p.into())));
+        let discovered = discovery::Discovery::new()
+            .candidates()
+            .into_iter()
+            .map(|c| (LoadStrategy::Discovery(c.source), c.result));
+        env.into_iter().chain(discovered).collect()
+    };
+    for (strategy, path) in candidates {
+        let path = match path {
+            Ok(path) => path,
+            Err(e) => {
+                attempts.push(LoadAttempt {
+                    strategy,
+                    error: e.into(),
+                });
+                continue;
+            }
+        };
+        match load_voicemeeter_from_path(path.as_os_str()) {
+            Ok(remote) => return Ok(remote),
+            Err(LoadError::LoadingError(e)) => {
+                tracing::warn!(?strategy, "could not load voicemeeter: {e}");
+                attempts.push(LoadAttempt {
+                    strategy,
+                    error: LoadAttemptError::LoadingError(path, e),
+                })
+            }
+            // loaded by another thread in the meantime
+            Err(LoadError::AlreadyLoaded) => return load_voicemeeter_raw(dll_path),
+            Err(e) => return Err(e),
+        }
     }
+    Err(LoadError::NotFound(attempts))
+}
+
+/// Returns `true` if `path` is the path the remote DLL was loaded from.
+fn is_loaded_path(path: &Path) -> bool {
+    let canonical = |p: &Path| std::fs::canonicalize(p).unwrap_or_else(|_| p.to_owned());
+    VOICEMEETER_REMOTE_PATH
+        .get()
+        .is_some_and(|loaded| canonical(loaded) == canonical(path))
 }
 
 /// Load voicemeeter
@@ -77,6 +151,7 @@ fn load_voicemeeter_from_path(path: &OsStr) -> Result<&'static VoicemeeterRemote
     VOICEMEETER_REMOTE
         .set(unsafe { VoicemeeterRemoteRaw::new(path)? })
         .map_err(|_| LoadError::AlreadyLoaded)?;
+    let _ = VOICEMEETER_REMOTE_PATH.set(path.into());
     Ok(VOICEMEETER_REMOTE.get().expect("lock was just set"))
 }
 
@@ -93,45 +168,105 @@ pub enum LoadError {
     /// Could not locate the dll
     #[error("library could not be located")]
     RemoteFileError(#[from] RemoteFileError),
+    /// None of the strategies to locate and load the DLL succeeded.
+    #[error("library could not be found or loaded, tried: {}", display_attempts(.0))]
+    NotFound(Vec<LoadAttempt>),
+    /// A DLL was requested from a path, but another DLL is already loaded.
+    ///
+    /// The remote DLL can only be loaded once per process.
+    #[error("could not load `{}`, {} is already loaded", .path.display(), .loaded.as_ref().map(|p| format!("`{}`", p.display())).unwrap_or_else(|| "another library".to_owned()))]
+    OtherLibraryLoaded {
+        /// The requested path.
+        path: PathBuf,
+        /// The path of the loaded DLL, if known.
+        loaded: Option<PathBuf>,
+    },
 }
 
-/// Get VoiceMeeterRemote via registry key
-#[tracing::instrument]
-pub(crate) fn find_voicemeeter_remote_with_registry() -> Result<OsString, RemoteFileError> {
-    tracing::debug!("finding voicemeeter dll");
-    let hklm = winreg::RegKey::predef(winreg::enums::HKEY_LOCAL_MACHINE);
-    let voicemeeter_uninst = if let Ok(reg) = hklm
-        .open_subkey(UNINSTALLER_DIR)
-        .and_then(|s| s.open_subkey(INSTALLER_UNINST_KEY))
-    {
-        // TODO: This will almost always fail, esp. on 64bit systems.
-        reg
-    } else {
-        hklm.open_subkey_with_flags(UNINSTALLER_DIR, KEY_READ | KEY_WOW64_32KEY)
-            .map_err(RegistryError::CouldNotFindUninstallReg)?
-            .open_subkey(INSTALLER_UNINST_KEY)
-            .map_err(RegistryError::CouldNotFindVM)?
-    };
-    let path: String = voicemeeter_uninst
-        .get_value("UninstallString")
-        .map_err(|_| RegistryError::CouldNotFindUninstallString)?;
-    let remote = Path::new(&path)
-        .parent()
-        .ok_or_else(|| RegistryError::UninstallStringInvalid(path.clone()))
-        .map(|p| p.join(LIBRARY_NAME_64))?;
-
-    if remote.exists() {
-        Ok(remote.into_os_string())
-    } else {
-        Err(RemoteFileError::NotFound(remote.display().to_string()))
+fn display_attempts(attempts: &[LoadAttempt]) -> String {
+    attempts
+        .iter()
+        .map(|a| a.to_string())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// A strategy used to find the Voicemeeter remote DLL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LoadStrategy {
+    /// Path given explicitly, see [`VoicemeeterRemoteBuilder::dll_path`](crate::interface::VoicemeeterRemoteBuilder::dll_path)
+    Path(PathBuf),
+    /// Path given in the [`VOICEMEETER_REMOTE_DLL_ENV`] environment variable.
+    Environment(PathBuf),
+    /// Path found with [`discovery::Discovery`].
+    Discovery(discovery::Source),
+}
+
+impl std::fmt::Display for LoadStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadStrategy::Path(p) => write!(f, "path {}", p.display()),
+            LoadStrategy::Environment(p) => {
+                write!(f, "{VOICEMEETER_REMOTE_DLL_ENV}={}", p.display())
+            }
+            LoadStrategy::Discovery(source) => write!(f, "discovery in {source}"),
+        }
     }
 }
 
+/// A failed attempt at loading the Voicemeeter remote DLL.
+#[derive(Debug, thiserror::Error)]
+#[error("{strategy}: {error}")]
+pub struct LoadAttempt {
+    /// The strategy used.
+    pub strategy: LoadStrategy,
+    /// Why the strategy failed.
+    #[source]
+    pub error: LoadAttemptError,
+}
+
+/// Reason a [`LoadAttempt`] failed.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum LoadAttemptError {
+    /// The DLL could not be located.
+    #[error(transparent)]
+    DiscoveryError(#[from] discovery::DiscoveryError),
+    /// The DLL was found but could not be loaded.
+    #[error("could not load `{}`: {}", .0.display(), .1)]
+    LoadingError(PathBuf, #[source] libloading::Error),
+}
+
+#[test]
+#[cfg(not(windows))]
+fn load_attempts() {
+    let Err(LoadError::NotFound(attempts)) =
+        load_voicemeeter_raw(Some(Path::new("./does-not-exist/VoicemeeterRemote64.dll")))
+    else {
+        panic!("expected the dll to not be found");
+    };
+    // an explicit path is the only attempt
+    assert_eq!(
+        attempts.iter().map(|a| &a.strategy).collect::<Vec<_>>(),
+        [&LoadStrategy::Path(
+            "./does-not-exist/VoicemeeterRemote64.dll".into()
+        )]
+    );
+
+    let Err(LoadError::NotFound(attempts)) = load_voicemeeter_raw(None) else {
+        panic!("expected the dll to not be found");
+    };
+    assert!(attempts.iter().any(|a| a.strategy
+        == LoadStrategy::Discovery(discovery::Source::Registry(discovery::RegistryView::Bits64))));
+}
+
 #[test]
 #[ignore]
-fn registry_check() -> Result<(), RemoteFileError> {
-    dbg!(find_voicemeeter_remote_with_registry()?);
-    Ok(())
+fn registry_check() {
+    for candidate in discovery::Discovery::new().candidates() {
+        println!("{candidate}");
+    }
 }
 
 /// Error while trying to get Voicemeeter location
@@ -162,14 +297,7 @@ pub enum RegistryError {
     /// Given uninstall exe is not a valid path
     #[error("given uninstall exe is not a valid path: {:?}", 0)]
     UninstallStringInvalid(String),
-}
-
-/// Get a pointer to a `T` if option is [`Some`](Option::Some) or a null ptr if it's [`None`](Option::None)
-#[cfg(feature = "interface")]
-pub(crate) fn opt_or_null<T>(mut option: Option<&mut T>) -> *mut T {
-    if let Some(p) = option.take() {
-        std::ptr::addr_of_mut!(*p)
-    } else {
-        std::ptr::null_mut()
-    }
+    /// The registry is only available on windows
+    #[error("the registry is only available on windows")]
+    Unsupported,
 }
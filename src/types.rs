@@ -2,6 +2,8 @@
 
 /// A Zero Indexed Index
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct ZIndex(pub(crate) i32);
 
@@ -30,6 +32,8 @@ impl From<u32> for ZIndex {
 
 /// A macro button. Zero indexed
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct LogicalButton(pub ZIndex);
 
@@ -61,17 +65,44 @@ impl From<u32> for LogicalButton {
 #[aliri_braid::braid()]
 pub struct ParameterName;
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for ParameterName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ParameterNameRef {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ParameterName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(ParameterName::new)
+    }
+}
+
 /// Voicemeeter application type.
+///
+/// With the `serde` feature, this is represented as `"Voicemeeter"`, `"Banana"`, `"Potato"`, `"PotatoX64"`, `"Other"` or `"None"`.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub enum VoicemeeterApplication {
     /// Standard "base" voicemeeter.
     Voicemeeter = 1,
     /// Voicemeeter Banana.
+    #[cfg_attr(feature = "serde", serde(rename = "Banana"))]
     VoicemeeterBanana = 2,
     /// Voicemeeter Potato.
+    #[cfg_attr(feature = "serde", serde(rename = "Potato"))]
     VoicemeeterPotato = 3,
     /// Voicemeeter Potato x64.
+    #[cfg_attr(feature = "serde", serde(rename = "PotatoX64"))]
     PotatoX64Bits = 6,
     /// Unknown voicemeeter type
     Other,
@@ -133,16 +164,23 @@ impl From<i32> for VoicemeeterApplication {
 }
 
 /// Level type, used for [`VoicemeeterRemote::get_level`](super::VoicemeeterRemote::get_level)
+///
+/// With the `serde` feature, this is represented as `"PreFaderInput"`, `"PostFaderInput"`, `"PostMuteInput"` or `"Output"`.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub enum LevelType {
     /// Pre fader input levels.
+    #[cfg_attr(feature = "serde", serde(rename = "PreFaderInput"))]
     PreFaderInputLevels = 0,
     /// Pre fader output levels.
+    #[cfg_attr(feature = "serde", serde(rename = "PostFaderInput"))]
     PostFaderInputLevels = 1,
     /// Post mute input levels.
+    #[cfg_attr(feature = "serde", serde(rename = "PostMuteInput"))]
     PostMuteInputLevels = 2,
     /// Output levels
+    #[cfg_attr(feature = "serde", serde(rename = "Output"))]
     OutputLevels = 3,
     #[doc(hidden)]
     Other,
@@ -161,7 +199,10 @@ impl From<i32> for LevelType {
 }
 
 /// A device.
+///
+/// With the `serde` feature, outputs are represented by their bus name, e.g. `"A1"` or `"B2"`, and other devices by their variant name.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Device {
     /// Input Strip 1. Available on all Voicemeeter versions.
     Strip1,
@@ -174,20 +215,28 @@ pub enum Device {
     /// Input Strip 5. Available on Voicemeeter Potato.
     Strip5,
     /// Output A1. Available on all Voicemeeter versions.
+    #[cfg_attr(feature = "serde", serde(rename = "A1"))]
     OutputA1,
     /// Output A2. Available on all Voicemeeter versions.
+    #[cfg_attr(feature = "serde", serde(rename = "A2"))]
     OutputA2,
     /// Output A3. Available on Voicemeeter Banana and Potato.
+    #[cfg_attr(feature = "serde", serde(rename = "A3"))]
     OutputA3,
     /// Output A4. Available on Voicemeeter Potato.
+    #[cfg_attr(feature = "serde", serde(rename = "A4"))]
     OutputA4,
     /// Output A5. Available on Voicemeeter Potato.
+    #[cfg_attr(feature = "serde", serde(rename = "A5"))]
     OutputA5,
     /// Virtual Output B1. Available on all Voicemeeter versions.
+    #[cfg_attr(feature = "serde", serde(rename = "B1"))]
     VirtualOutputB1,
     /// Virtual Output B2. Available on Voicemeeter Banana and Potato.
+    #[cfg_attr(feature = "serde", serde(rename = "B2"))]
     VirtualOutputB2,
     /// Virtual Output B3. Available on Voicemeeter Potato.
+    #[cfg_attr(feature = "serde", serde(rename = "B3"))]
     VirtualOutputB3,
     /// Virtual Input. Available on all Voicemeeter versions.
     VirtualInput,
@@ -199,6 +248,7 @@ pub enum Device {
 
 /// Index in the buffers for a [devices'](Device) channel.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChannelIndex {
     /// Start index.
     pub start: usize,
@@ -440,9 +490,13 @@ impl Device {
 }
 
 /// Bus mode
+///
+/// With the `serde` feature, this is represented by its [`Display`](std::fmt::Display) name, e.g. `"normal"` or `"TVMix"`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BusMode {
     /// Bus mode normal
+    #[cfg_attr(feature = "serde", serde(rename = "normal"))]
     Normal,
     /// Bus mode Amix
     Amix,
@@ -453,6 +507,7 @@ pub enum BusMode {
     /// Bus mode Composite
     Composite,
     /// Bus mode TVMix
+    #[cfg_attr(feature = "serde", serde(rename = "TVMix"))]
     TvMix,
     /// Bus mode UpMix21
     UpMix21,
@@ -463,6 +518,7 @@ pub enum BusMode {
     /// Bus mode CenterOnly
     CenterOnly,
     /// Bus mode LFEOnly
+    #[cfg_attr(feature = "serde", serde(rename = "LFEOnly"))]
     LfeOnly,
     /// Bus mode RearOnly
     RearOnly,
@@ -486,3 +542,50 @@ impl std::fmt::Display for BusMode {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_representations() -> Result<(), serde_json::Error> {
+        use crate::interface::{device::DeviceType, general_information::VoicemeeterVersion};
+        use serde_json::json;
+
+        assert_eq!(serde_json::to_value(Device::OutputA1)?, "A1");
+        assert_eq!(serde_json::to_value(Device::VirtualOutputB3)?, "B3");
+        assert_eq!(serde_json::to_value(Device::Strip2)?, "Strip2");
+        assert_eq!(
+            serde_json::to_value(VoicemeeterApplication::VoicemeeterPotato)?,
+            "Potato"
+        );
+        assert_eq!(serde_json::to_value(LevelType::OutputLevels)?, "Output");
+        assert_eq!(serde_json::to_value(BusMode::TvMix)?, "TVMix");
+        assert_eq!(serde_json::to_value(LogicalButton::from(3))?, 3);
+        assert_eq!(
+            serde_json::to_value(VoicemeeterVersion(3, 1, 1, 4))?,
+            "3.1.1.4"
+        );
+        assert_eq!(serde_json::to_value(DeviceType::Wdm)?, "WDM");
+        assert_eq!(serde_json::to_value(DeviceType::from(42))?, 42);
+
+        assert_eq!(
+            serde_json::from_value::<Device>(json!("B2"))?,
+            Device::VirtualOutputB2
+        );
+        assert_eq!(
+            serde_json::from_value::<VoicemeeterVersion>(json!("2.0.6.8"))?,
+            VoicemeeterVersion(2, 0, 6, 8)
+        );
+        assert!(serde_json::from_value::<VoicemeeterVersion>(json!("2.0.6")).is_err());
+        assert!(matches!(
+            serde_json::from_value::<DeviceType>(json!("ASIO"))?,
+            DeviceType::Asio
+        ));
+        assert_eq!(
+            serde_json::from_value::<ParameterName>(json!("Strip[0].Mute"))?,
+            ParameterName::from("Strip[0].Mute")
+        );
+        Ok(())
+    }
+}